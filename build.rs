@@ -0,0 +1,56 @@
+//! Generates the locale registry from the `.ftl` files in `locales/`.
+//!
+//! Every `locales/<language-id>.ftl` becomes an entry of `LOCALES` in `$OUT_DIR/locales.rs`, so
+//! adding a language only takes a new translation file.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+/// Message holding a language's own name, shown in the language drop-down
+const NAME_MESSAGE: &str = "language-name";
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let locales_dir = Path::new(&manifest_dir).join("locales");
+    println!("cargo:rerun-if-changed={}", locales_dir.display());
+
+    let mut locales: Vec<(String, String, String)> = fs::read_dir(&locales_dir)
+        .expect("locales/ directory is readable")
+        .map(|entry| entry.expect("locales/ entry is readable").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+        .map(|path| {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let id = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .expect("locale file names are UTF-8")
+                .to_string();
+            let source = fs::read_to_string(&path).expect("locale file is readable");
+            let name = language_name(&source)
+                .unwrap_or_else(|| panic!("{} has no `{NAME_MESSAGE}` message", path.display()));
+            (id, name, path.display().to_string())
+        })
+        .collect();
+    locales.sort();
+
+    let mut registry = String::from(
+        "/// Bundled locales as (language identifier, native language name, Fluent source)\n\
+         pub static LOCALES: &[(&str, &str, &str)] = &[\n",
+    );
+    for (id, name, path) in &locales {
+        writeln!(registry, "    ({id:?}, {name:?}, include_str!({path:?})),").unwrap();
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("locales.rs"), registry).expect("OUT_DIR is writable");
+}
+
+/// Value of the single-line `language-name = ...` message
+fn language_name(source: &str) -> Option<String> {
+    source.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == NAME_MESSAGE).then(|| value.trim().to_string())
+    })
+}
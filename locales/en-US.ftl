@@ -7,32 +7,200 @@ error-failed-load = Failed to load models
 button-retry = 🔄 Retry
 loading-models = Loading models...
 footer-text = Built with Dioxus 🦀 | Data from Polza AI API
+page-models = Models
+page-providers = Providers
+page-arena = Arena ({ $count })
+page-navigation-label = Page
+offline-indicator = ⚠ Offline
+offline-indicator-hint = No connection: showing the saved catalog, prices may be out of date
+cache-error-quota = The catalog or its history could not be saved for offline use: the browser's storage for this site is full
+cache-error-failed = The catalog or its history could not be saved for offline use: { $reason }
+cache-warning-dismiss = Dismiss
+static-page-title = { $name } · { app-title }
+static-model-description = Prices, context length and supported parameters of { $name } on Polza AI
 
 # Filter Controls
 filter-label = Filter models:
 filter-placeholder = Type to filter models...
+filter-clear = Clear filter
 input-modalities-label = Input Modalities:
 output-modalities-label = Output Modalities:
+created-filter-label = Created:
+recency-last-days = Last {$days} days
+recency-since-last-visit = Since my last visit
+new-badge-window-label = "NEW" badge:
+provider-filter-label = Provider:
+provider-filter-clear = Clear the provider filter
+
+# What's New
+whats-new-title = { $count ->
+    [one] 🆕 What's new ({ $count } change)
+   *[other] 🆕 What's new ({ $count } changes)
+}
+whats-new-period = Between {$since} and {$until}
+whats-new-no-changes = No changes between the last two snapshots
+whats-new-added = Added ({$count})
+whats-new-removed = Removed ({$count})
+whats-new-price-changes = Price changes ({$count})
+price-field-prompt = Prompt / { price-unit-long }:
+price-field-completion = Completion / { price-unit-long }:
+price-field-internal-reasoning = Internal Reasoning / { price-unit-long }:
+price-field-input-cache-read = Input Cache Read / { price-unit-long }:
+price-field-input-cache-write = Input Cache Write / { price-unit-long }:
+price-field-image = Image:
+price-field-request = Request:
+price-field-web-search = Web Search:
+
+# Filter Presets
+preset-save-current = + Save filters as preset
+preset-name-placeholder = Preset name
+preset-save = Save
+preset-cancel = Cancel
+preset-rename = Rename
+preset-delete = Delete
+preset-name-taken = A preset with this name already exists
 
 # Sort Controls
+sort-by = Sort by
+sort-then-by = then by
+sort-add-key = + Add sort key
+sort-remove-key = Remove this sort key
 sort-name = Name
+sort-provider = Provider
 sort-created = Created
 sort-prompt-price = Prompt Price
 sort-completion-price = Completion Price
+sort-internal-reasoning-price = Reasoning Price
+sort-input-cache-read-price = Cache Read Price
+sort-input-cache-write-price = Cache Write Price
+sort-image-price = Image Price
+sort-request-price = Request Price
+sort-web-search-price = Web Search Price
+sort-context-length = Context Length
+sort-max-completion-tokens = Max Completion Tokens
 sort-ascending = ↑ Ascending
 sort-descending = ↓ Descending
 
+# View Controls
+view-cards = Cards
+view-table = Table
+view-chart = Chart
+view-mode-label = View mode
+columns-button = ☰ Columns
+
+# Scatter Chart
+chart-x-axis = X axis:
+chart-y-axis = Y axis:
+chart-axis-prompt-price = Prompt / { price-unit-long }
+chart-axis-completion-price = Completion / { price-unit-long }
+chart-axis-context-length = Context length
+chart-axis-created = Created
+chart-log-scale-note = Prices use a logarithmic scale
+chart-no-points = No models to plot
+chart-hidden-models = { $count ->
+    [one] { $count } model not shown (free or missing on a logarithmic axis)
+   *[other] { $count } models not shown (free or missing on a logarithmic axis)
+}
+
+# Providers Overview
+providers-found = { $count ->
+    [one] { $count } provider
+   *[other] { $count } providers
+}
+providers-column-provider = Provider
+providers-column-models = Models
+providers-column-prompt = Prompt / { price-unit-short } (min · median · max)
+providers-column-completion = Completion / { price-unit-short } (min · median · max)
+providers-column-modalities = Modalities
+providers-column-newest = Newest model
+providers-column-moderated = Moderated
+providers-row-hint = Show this provider's models
+providers-min = Minimum
+providers-median = Median
+providers-max = Maximum
+
+# Model Table
+column-name = Name
+column-provider = Provider
+column-prompt-price = Prompt / { price-unit-short }
+column-completion-price = Completion / { price-unit-short }
+column-internal-reasoning-price = Reasoning / { price-unit-short }
+column-input-cache-read-price = Cache Read / { price-unit-short }
+column-input-cache-write-price = Cache Write / { price-unit-short }
+column-image-price = Image
+column-request-price = Request
+column-web-search-price = Web Search
+column-context-length = Context
+column-max-completion-tokens = Max Completion
+column-modalities = Modalities
+column-created = Created
+column-prompt-cost = Prompt Cost
+table-sort-hint = Click to sort, Shift+click to add as a secondary sort key
+
+# Favorites
+favorite-add = Add to favorites
+favorite-remove = Remove from favorites
+favorites-filter-label = Favorites:
+favorites-only = ★ Favorites only ({$count})
+favorites-panel-title = ★ Favorites ({$count}) — import / export
+favorites-json-placeholder = Paste a JSON array of model slugs here, or press Export
+favorites-export = Export
+favorites-import = Import
+favorites-imported = { $count ->
+    [one] Imported { $count } new favorite
+   *[other] Imported { $count } new favorites
+}
+favorites-import-failed = Could not import favorites: {$error}
+
+# Token Counter
+token-counter-title = Prompt cost estimate
+token-counter-placeholder = Paste a document or prompt to count its tokens and see what it costs with each model
+token-counter-characters = Characters: { $count }
+token-counter-exact = exact
+token-counter-estimate = estimate
+token-counter-hint = The prompt cost is shown on every model in the list. Only OpenAI vocabularies are bundled; other families are estimated.
+token-counter-hint-estimated = The prompt cost is shown on every model in the list. Token counts are estimated from the text for every tokenizer family.
+token-count = { $tokens } tokens
+token-count-estimate = ≈{ $tokens } tokens
+tokenizer-gpt = GPT (o200k_base)
+tokenizer-cl100k = OpenAI (cl100k_base)
+tokenizer-claude = Claude
+tokenizer-gemini = Gemini
+tokenizer-llama = Llama
+tokenizer-mistral = Mistral
+tokenizer-qwen = Qwen
+tokenizer-deepseek = DeepSeek
+tokenizer-grok = Grok
+tokenizer-other = Other tokenizers
+
 # Model Card
 label-provider = Provider
 label-created = Created:
-label-prompt-price = Prompt / 1M tokens:
-label-completion-price = Completion / 1M tokens:
+label-prompt-price = Prompt / { price-unit-long }:
+label-completion-price = Completion / { price-unit-long }:
+label-prompt-cost = Prompt cost:
 label-canonical-slug = Canonical Slug
 button-copy = Copy
+button-close = Close
+button-copy-failed = Copy failed
+copy-menu-label = More copy formats
+copy-format-slug = Slug
+copy-format-request-json = Request JSON
+copy-format-curl = curl command
+copy-format-python = Python (OpenAI SDK)
+copy-format-typescript = TypeScript (OpenAI SDK)
+copy-format-pricing-json = Pricing JSON (in the selected unit)
+copy-announcement = { $format } copied to the clipboard
+copy-failed = Copy failed: { $error }
 button-copied = ✓ Copied
+badge-new = NEW
+badge-new-title = Added {$date}
 
 # Model List
-models-found = Found {$count} model(s)
+models-found = { $count ->
+    [one] Found { $count } model
+   *[other] Found { $count } models
+}
 models-matching = matching "{$filter}"
 no-models-filter = 😔 No models match your filter
 no-models-available = No models available
@@ -41,9 +209,16 @@ no-models-available = No models available
 section-basic-info = Basic Information
 section-provider-config = Provider Configuration
 section-pricing = Pricing
+section-price-history = Price History
 section-architecture = Architecture
 section-parameters = Supported Parameters
 
+# Price History
+price-history-prompt = Prompt / { price-unit-long }
+price-history-completion = Completion / { price-unit-long }
+price-history-empty = No price history recorded yet
+price-history-single = Only one price recorded so far; the chart will grow with each refresh
+
 # Modal - Labels
 modal-label-provider = Provider:
 modal-label-created = Created:
@@ -51,11 +226,11 @@ modal-label-canonical-slug = Canonical Slug:
 modal-label-context-length = Context Length:
 modal-label-max-completion = Max Completion Tokens:
 modal-label-moderated = Moderated:
-modal-label-prompt-1m = Prompt / 1M tokens:
-modal-label-completion-1m = Completion / 1M tokens:
-modal-label-internal-reasoning = Internal Reasoning / 1M tokens:
-modal-label-input-cache-read = Input Cache Read / 1M tokens:
-modal-label-input-cache-write = Input Cache Write / 1M tokens:
+modal-label-prompt-price = Prompt / { price-unit-long }:
+modal-label-completion-price = Completion / { price-unit-long }:
+modal-label-internal-reasoning = Internal Reasoning / { price-unit-long }:
+modal-label-input-cache-read = Input Cache Read / { price-unit-long }:
+modal-label-input-cache-write = Input Cache Write / { price-unit-long }:
 modal-label-image = Image (for one image):
 modal-label-request = Request (for each request):
 modal-label-web-search = Web Search (for web search):
@@ -67,6 +242,174 @@ unit-tokens = tokens
 value-no-limit = No limit
 no-parameters = No parameters specified
 
+# Modalities
+modality-text = Text
+modality-image = Image
+modality-file = File
+modality-audio = Audio
+modality-embeddings = Embeddings
+modality-video = Video
+
+# Request Parameters
+parameter-temperature = Temperature
+parameter-temperature-description = Randomness of the output: lower is more focused and deterministic, higher is more varied
+parameter-top-p = Top P
+parameter-top-p-description = Nucleus sampling: only the most likely tokens whose probabilities add up to this value are considered
+parameter-top-k = Top K
+parameter-top-k-description = Only the K most likely tokens are considered at each step
+parameter-min-p = Min P
+parameter-min-p-description = Minimum probability of a token, relative to the most likely one, to be considered
+parameter-top-a = Top A
+parameter-top-a-description = Dynamic cut-off of unlikely tokens based on the probability of the most likely one
+parameter-frequency-penalty = Frequency penalty
+parameter-frequency-penalty-description = Penalizes tokens by how often they already appeared, reducing repetition
+parameter-presence-penalty = Presence penalty
+parameter-presence-penalty-description = Penalizes tokens that already appeared at all, encouraging new topics
+parameter-repetition-penalty = Repetition penalty
+parameter-repetition-penalty-description = Scales down the probability of tokens from the prompt and the answer so far
+parameter-max-tokens = Max tokens
+parameter-max-tokens-description = Upper limit on the number of tokens generated in the answer
+parameter-seed = Seed
+parameter-seed-description = Fixed seed for repeatable sampling, where the provider supports it
+parameter-stop = Stop sequences
+parameter-stop-description = Generation stops when any of these strings is produced
+parameter-tools = Tools
+parameter-tools-description = Functions the model may call (tool calling)
+parameter-tool-choice = Tool choice
+parameter-tool-choice-description = Whether the model must, may or must not call a tool
+parameter-response-format = Response format
+parameter-response-format-description = Forces the answer to be JSON, optionally matching a schema
+parameter-structured-outputs = Structured outputs
+parameter-structured-outputs-description = Answers are guaranteed to follow the supplied JSON schema
+parameter-logit-bias = Logit bias
+parameter-logit-bias-description = Raises or lowers the likelihood of specific tokens
+parameter-logprobs = Log probabilities
+parameter-logprobs-description = Returns the log probability of each generated token
+parameter-top-logprobs = Top log probabilities
+parameter-top-logprobs-description = Number of most likely alternatives returned for each generated token
+parameter-reasoning = Reasoning
+parameter-reasoning-description = Controls the model's internal reasoning (effort or token budget)
+parameter-include-reasoning = Include reasoning
+parameter-include-reasoning-description = Returns the model's reasoning along with the answer
+parameter-dimensions = Dimensions
+parameter-dimensions-description = Length of the returned embedding vectors
+parameter-encoding-format = Encoding format
+parameter-encoding-format-description = Embeddings as a list of floats or as a base64 string
+
+# Playground
+modal-tab-details = Details
+modal-tab-try-it = Try it
+playground-api-key = API key
+playground-api-key-placeholder = Polza AI API key
+playground-api-key-hint = The key is kept for this session only, unless remembered, and is sent only to the API below.
+playground-remember-key = Remember the key on this device (stored unencrypted)
+playground-advanced = Advanced
+playground-base-url = API base URL
+playground-prompt = Prompt
+playground-prompt-placeholder = Ask the model something...
+playground-parameter-default = default
+parameter-error-not-a-number = Not a number
+parameter-error-out-of-range = Out of range
+playground-send = Send
+playground-stop = Stop
+playground-running = Waiting for the answer...
+playground-cancelled = Request cancelled.
+playground-error-no-key = Enter an API key first.
+playground-error = Request failed: { $error }
+playground-usage = { $prompt } prompt + { $completion } completion tokens · { $cost } · { $seconds } s
+playground-no-usage = Finished in { $seconds } s (the server did not report token usage)
+
+# Arena
+arena-selected = Compared models ({ $count }/{ $max }):
+arena-remove = Remove { $name } from the comparison
+arena-clear = Clear
+arena-select-hint = Pick { $min } to { $max } models in the list or table with the ⚖ button to compare their answers.
+arena-toggle-add = Add to the arena comparison
+arena-toggle-remove = Remove from the arena comparison
+arena-toggle-full = The arena compares at most { $max } models
+arena-run = Run on all models
+arena-streaming = Streaming...
+arena-latency = Total time: { $seconds } s
+arena-first-token = First token: { $seconds } s
+arena-tokens = { $prompt } prompt + { $completion } completion tokens
+arena-cost = Cost: { $cost }
+arena-no-usage = The server did not report token usage
+arena-export = Export session as JSON
+arena-export-copy = Copy JSON
+arena-export-copied = Session JSON copied to the clipboard
+
+# Keyboard Shortcuts
+shortcuts-hint = Press ? for keyboard shortcuts
+shortcuts-title = Keyboard shortcuts
+shortcut-focus-filter = Focus the filter
+shortcut-select-next = Select the next model
+shortcut-select-previous = Select the previous model
+shortcut-open-selected = Open the selected model
+shortcut-copy-slug = Copy the slug of the selected model
+shortcut-toggle-favorite = Add or remove the selected model from favorites
+shortcut-previous-model = Previous model (details open)
+shortcut-next-model = Next model (details open)
+shortcut-show-help = Show or hide this list
+shortcut-close = Close the dialog
+
 # Language Switcher
 language-name = English
-language-code = EN
+language-label = Language
+
+# Theme Switcher
+theme-label = Color theme
+theme-auto = 🌓 Auto
+theme-light = ☀️ Light
+theme-dark = 🌙 Dark
+theme-high-contrast = ◐ High contrast
+
+# Currency
+currency-label = Display currency
+currency-rub = ₽ Russian ruble
+currency-usd = $ US dollar
+currency-eur = € Euro
+currency-cny = ¥ Chinese yuan
+currency-rate-label = Rubles per 1 { $currency }
+currency-rate-bundled = reference rate as of { $date }
+currency-rate-custom = your rate, entered { $date }
+currency-rate-reset = Use reference rate
+
+# Relative Dates
+relative-just-now = just now
+relative-minutes-ago = { $count ->
+    [one] { $count } minute ago
+   *[other] { $count } minutes ago
+}
+relative-hours-ago = { $count ->
+    [one] { $count } hour ago
+   *[other] { $count } hours ago
+}
+relative-days-ago = { $count ->
+    [one] { $count } day ago
+   *[other] { $count } days ago
+}
+relative-months-ago = { $count ->
+    [one] { $count } month ago
+   *[other] { $count } months ago
+}
+relative-years-ago = { $count ->
+    [one] { $count } year ago
+   *[other] { $count } years ago
+}
+
+# Price Units
+# Token prices are labelled with the chosen unit; `$unit` is token, thousand, million or billion
+price-unit-label = Price unit
+price-unit-long = { $unit ->
+    [token] token
+    [thousand] 1K tokens
+    [billion] 1B tokens
+   *[million] 1M tokens
+}
+price-unit-short = { $unit ->
+    [token] token
+    [thousand] 1K
+    [billion] 1B
+   *[million] 1M
+}
+price-unit-option = per { price-unit-long }
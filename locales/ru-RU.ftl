@@ -7,32 +7,189 @@ error-failed-load = Не удалось загрузить модели
 button-retry = 🔄 Повторить
 loading-models = Загрузка моделей...
 footer-text = Создано с Dioxus 🦀 | Данные из Polza AI API
+page-models = Модели
+page-providers = Провайдеры
+page-arena = Арена ({ $count })
+page-navigation-label = Страница
+offline-indicator = ⚠ Нет сети
+offline-indicator-hint = Нет подключения: показан сохранённый каталог, цены могут быть устаревшими
+cache-error-quota = Не удалось сохранить каталог или его историю для работы без сети: хранилище браузера для этого сайта заполнено
+cache-error-failed = Не удалось сохранить каталог или его историю для работы без сети: { $reason }
+cache-warning-dismiss = Закрыть
+static-page-title = { $name } · { app-title }
+static-model-description = Цены, длина контекста и поддерживаемые параметры { $name } в Polza AI
 
 # Filter Controls
 filter-label = Фильтр моделей:
 filter-placeholder = Введите текст для фильтрации...
+filter-clear = Очистить фильтр
 input-modalities-label = Входные модальности:
 output-modalities-label = Выходные модальности:
+created-filter-label = Добавлены:
+recency-last-days = За последние {$days} дн.
+recency-since-last-visit = С моего прошлого визита
+new-badge-window-label = Значок «NEW»:
+provider-filter-label = Провайдер:
+provider-filter-clear = Сбросить фильтр по провайдеру
+
+# What's New
+whats-new-title = 🆕 Что нового (изменений: {$count})
+whats-new-period = С {$since} по {$until}
+whats-new-no-changes = Между двумя последними снимками изменений нет
+whats-new-added = Добавлены ({$count})
+whats-new-removed = Удалены ({$count})
+whats-new-price-changes = Изменения цен ({$count})
+price-field-prompt = Промпт / { price-unit-long }:
+price-field-completion = Завершение / { price-unit-long }:
+price-field-internal-reasoning = Внутренние размышления / { price-unit-long }:
+price-field-input-cache-read = Чтение кеша ввода / { price-unit-long }:
+price-field-input-cache-write = Запись кеша ввода / { price-unit-long }:
+price-field-image = Изображение:
+price-field-request = Запрос:
+price-field-web-search = Веб-поиск:
+
+# Filter Presets
+preset-save-current = + Сохранить фильтры как пресет
+preset-name-placeholder = Название пресета
+preset-save = Сохранить
+preset-cancel = Отмена
+preset-rename = Переименовать
+preset-delete = Удалить
+preset-name-taken = Пресет с таким названием уже есть
 
 # Sort Controls
+sort-by = Сортировать по
+sort-then-by = затем по
+sort-add-key = + Добавить ключ сортировки
+sort-remove-key = Убрать этот ключ сортировки
 sort-name = Название
+sort-provider = Провайдер
 sort-created = Дата создания
 sort-prompt-price = Цена промпта
 sort-completion-price = Цена генерации
+sort-internal-reasoning-price = Цена размышлений
+sort-input-cache-read-price = Цена чтения кеша
+sort-input-cache-write-price = Цена записи кеша
+sort-image-price = Цена изображения
+sort-request-price = Цена запроса
+sort-web-search-price = Цена веб-поиска
+sort-context-length = Длина контекста
+sort-max-completion-tokens = Макс. токенов завершения
 sort-ascending = ↑ По возрастанию
 sort-descending = ↓ По убыванию
 
+# View Controls
+view-cards = Карточки
+view-table = Таблица
+view-chart = График
+view-mode-label = Режим просмотра
+columns-button = ☰ Столбцы
+
+# Scatter Chart
+chart-x-axis = Ось X:
+chart-y-axis = Ось Y:
+chart-axis-prompt-price = Промпт / { price-unit-long }
+chart-axis-completion-price = Завершение / { price-unit-long }
+chart-axis-context-length = Длина контекста
+chart-axis-created = Дата создания
+chart-log-scale-note = Цены отложены в логарифмическом масштабе
+chart-no-points = Нет моделей для отображения
+chart-hidden-models = Не показано моделей: {$count} (бесплатные или без значения на логарифмической оси)
+
+# Providers Overview
+providers-found = Провайдеров: {$count}
+providers-column-provider = Провайдер
+providers-column-models = Модели
+providers-column-prompt = Промпт / { price-unit-short } (мин · медиана · макс)
+providers-column-completion = Завершение / { price-unit-short } (мин · медиана · макс)
+providers-column-modalities = Модальности
+providers-column-newest = Новейшая модель
+providers-column-moderated = Модерируемые
+providers-row-hint = Показать модели этого провайдера
+providers-min = Минимум
+providers-median = Медиана
+providers-max = Максимум
+
+# Model Table
+column-name = Название
+column-provider = Провайдер
+column-prompt-price = Промпт / { price-unit-short }
+column-completion-price = Завершение / { price-unit-short }
+column-internal-reasoning-price = Размышления / { price-unit-short }
+column-input-cache-read-price = Чтение кеша / { price-unit-short }
+column-input-cache-write-price = Запись кеша / { price-unit-short }
+column-image-price = Изображение
+column-request-price = Запрос
+column-web-search-price = Веб-поиск
+column-context-length = Контекст
+column-max-completion-tokens = Макс. завершение
+column-modalities = Модальности
+column-created = Создано
+column-prompt-cost = Стоимость промпта
+table-sort-hint = Нажмите для сортировки, Shift+клик — добавить дополнительный ключ
+
+# Favorites
+favorite-add = Добавить в избранное
+favorite-remove = Убрать из избранного
+favorites-filter-label = Избранное:
+favorites-only = ★ Только избранное ({$count})
+favorites-panel-title = ★ Избранное ({$count}) — импорт / экспорт
+favorites-json-placeholder = Вставьте JSON-массив идентификаторов моделей или нажмите «Экспорт»
+favorites-export = Экспорт
+favorites-import = Импорт
+favorites-imported = Импортировано новых избранных моделей: {$count}
+favorites-import-failed = Не удалось импортировать избранное: {$error}
+
+# Token Counter
+token-counter-title = Оценка стоимости промпта
+token-counter-placeholder = Вставьте документ или промпт, чтобы посчитать токены и узнать стоимость для каждой модели
+token-counter-characters = Символов: { $count }
+token-counter-exact = точно
+token-counter-estimate = оценка
+token-counter-hint = Стоимость промпта показана у каждой модели в списке. Встроены только словари OpenAI, для остальных семейств токены оцениваются.
+token-counter-hint-estimated = Стоимость промпта показана у каждой модели в списке. Число токенов оценивается по тексту для всех семейств токенизаторов.
+token-count = Токенов: { $tokens }
+token-count-estimate = Токенов: ≈{ $tokens }
+tokenizer-gpt = GPT (o200k_base)
+tokenizer-cl100k = OpenAI (cl100k_base)
+tokenizer-claude = Claude
+tokenizer-gemini = Gemini
+tokenizer-llama = Llama
+tokenizer-mistral = Mistral
+tokenizer-qwen = Qwen
+tokenizer-deepseek = DeepSeek
+tokenizer-grok = Grok
+tokenizer-other = Другие токенизаторы
+
 # Model Card
 label-provider = Провайдер
 label-created = Создано:
-label-prompt-price = Промпт / 1M токенов:
-label-completion-price = Завершение / 1M токенов:
+label-prompt-price = Промпт / { price-unit-long }:
+label-completion-price = Завершение / { price-unit-long }:
+label-prompt-cost = Стоимость промпта:
 label-canonical-slug = Канонический идентификатор
 button-copy = Копировать
+button-close = Закрыть
+button-copy-failed = Ошибка
+copy-menu-label = Другие форматы копирования
+copy-format-slug = Slug
+copy-format-request-json = JSON запроса
+copy-format-curl = Команда curl
+copy-format-python = Python (OpenAI SDK)
+copy-format-typescript = TypeScript (OpenAI SDK)
+copy-format-pricing-json = Цены в JSON (в выбранных единицах)
+copy-announcement = { $format }: скопировано в буфер обмена
+copy-failed = Не удалось скопировать: { $error }
 button-copied = ✓ Скопировано
+badge-new = NEW
+badge-new-title = Добавлена {$date}
 
 # Model List
-models-found = Найдено {$count} модель/моделей
+models-found = { $count ->
+    [one] Найдена { $count } модель
+    [few] Найдено { $count } модели
+   *[many] Найдено { $count } моделей
+}
 models-matching = соответствует "{$filter}"
 no-models-filter = 😔 Нет моделей, соответствующих фильтру
 no-models-available = Нет доступных моделей
@@ -41,9 +198,16 @@ no-models-available = Нет доступных моделей
 section-basic-info = Основная информация
 section-provider-config = Конфигурация провайдера
 section-pricing = Цены
+section-price-history = История цен
 section-architecture = Архитектура
 section-parameters = Поддерживаемые параметры
 
+# Price History
+price-history-prompt = Промпт / { price-unit-long }
+price-history-completion = Завершение / { price-unit-long }
+price-history-empty = История цен пока не записана
+price-history-single = Пока записана только одна цена; график будет пополняться при каждом обновлении
+
 # Modal - Labels
 modal-label-provider = Провайдер:
 modal-label-created = Создано:
@@ -51,11 +215,11 @@ modal-label-canonical-slug = Канонический идентификатор
 modal-label-context-length = Длина контекста:
 modal-label-max-completion = Максимум токенов завершения:
 modal-label-moderated = Модерация:
-modal-label-prompt-1m = Промпт / 1M токенов:
-modal-label-completion-1m = Завершение / 1M токенов:
-modal-label-internal-reasoning = Внутренние размышления / 1M токенов:
-modal-label-input-cache-read = Чтение кеша ввода / 1M токенов:
-modal-label-input-cache-write = Запись кеша ввода / 1M токенов:
+modal-label-prompt-price = Промпт / { price-unit-long }:
+modal-label-completion-price = Завершение / { price-unit-long }:
+modal-label-internal-reasoning = Внутренние размышления / { price-unit-long }:
+modal-label-input-cache-read = Чтение кеша ввода / { price-unit-long }:
+modal-label-input-cache-write = Запись кеша ввода / { price-unit-long }:
 modal-label-image = Изображение (за одно изображение):
 modal-label-request = Запрос (за каждый запрос):
 modal-label-web-search = Веб-поиск (за веб-поиск):
@@ -67,6 +231,179 @@ unit-tokens = токенов
 value-no-limit = Без ограничений
 no-parameters = Параметры не указаны
 
+# Modalities
+modality-text = Текст
+modality-image = Изображение
+modality-file = Файл
+modality-audio = Аудио
+modality-embeddings = Эмбеддинги
+modality-video = Видео
+
+# Request Parameters
+parameter-temperature = Температура
+parameter-temperature-description = Случайность ответа: ниже — точнее и предсказуемее, выше — разнообразнее
+parameter-top-p = Top P
+parameter-top-p-description = Ядерная выборка: рассматриваются только самые вероятные токены, чья суммарная вероятность не превышает это значение
+parameter-top-k = Top K
+parameter-top-k-description = На каждом шаге рассматриваются только K самых вероятных токенов
+parameter-min-p = Min P
+parameter-min-p-description = Минимальная вероятность токена относительно самого вероятного, чтобы он рассматривался
+parameter-top-a = Top A
+parameter-top-a-description = Динамическое отсечение маловероятных токенов в зависимости от вероятности самого вероятного
+parameter-frequency-penalty = Штраф за частоту
+parameter-frequency-penalty-description = Штрафует токены тем сильнее, чем чаще они уже встречались, уменьшая повторы
+parameter-presence-penalty = Штраф за присутствие
+parameter-presence-penalty-description = Штрафует уже встречавшиеся токены, подталкивая к новым темам
+parameter-repetition-penalty = Штраф за повторение
+parameter-repetition-penalty-description = Снижает вероятность токенов из промпта и уже написанной части ответа
+parameter-max-tokens = Максимум токенов
+parameter-max-tokens-description = Верхний предел числа токенов в ответе
+parameter-seed = Сид
+parameter-seed-description = Фиксированное зерно для воспроизводимой генерации, если провайдер его поддерживает
+parameter-stop = Стоп-последовательности
+parameter-stop-description = Генерация останавливается, как только появляется одна из этих строк
+parameter-tools = Инструменты
+parameter-tools-description = Функции, которые модель может вызывать
+parameter-tool-choice = Выбор инструмента
+parameter-tool-choice-description = Должна ли модель вызвать инструмент, может ли или не должна
+parameter-response-format = Формат ответа
+parameter-response-format-description = Заставляет модель отвечать в JSON, при необходимости по схеме
+parameter-structured-outputs = Структурированный вывод
+parameter-structured-outputs-description = Ответ гарантированно соответствует переданной JSON-схеме
+parameter-logit-bias = Смещение логитов
+parameter-logit-bias-description = Повышает или понижает вероятность отдельных токенов
+parameter-logprobs = Логарифмы вероятностей
+parameter-logprobs-description = Возвращает логарифм вероятности каждого сгенерированного токена
+parameter-top-logprobs = Топ логарифмов вероятностей
+parameter-top-logprobs-description = Сколько самых вероятных альтернатив вернуть для каждого токена
+parameter-reasoning = Размышления
+parameter-reasoning-description = Управляет внутренними размышлениями модели (усилие или бюджет токенов)
+parameter-include-reasoning = Включать размышления
+parameter-include-reasoning-description = Возвращает размышления модели вместе с ответом
+parameter-dimensions = Размерность
+parameter-dimensions-description = Длина возвращаемых векторов эмбеддингов
+parameter-encoding-format = Формат кодирования
+parameter-encoding-format-description = Эмбеддинги списком чисел или строкой base64
+
+# Playground
+modal-tab-details = Подробности
+modal-tab-try-it = Попробовать
+playground-api-key = API-ключ
+playground-api-key-placeholder = API-ключ Polza AI
+playground-api-key-hint = Ключ хранится только до конца сеанса, если его не запомнить, и отправляется только на указанный ниже API.
+playground-remember-key = Запомнить ключ на этом устройстве (хранится в открытом виде)
+playground-advanced = Дополнительно
+playground-base-url = Базовый URL API
+playground-prompt = Промпт
+playground-prompt-placeholder = Спросите модель о чём-нибудь...
+playground-parameter-default = по умолчанию
+parameter-error-not-a-number = Не число
+parameter-error-out-of-range = Вне допустимого диапазона
+playground-send = Отправить
+playground-stop = Остановить
+playground-running = Ожидание ответа...
+playground-cancelled = Запрос отменён.
+playground-error-no-key = Сначала введите API-ключ.
+playground-error = Ошибка запроса: { $error }
+playground-usage = Токенов: { $prompt } промпт + { $completion } завершение · { $cost } · { $seconds } с
+playground-no-usage = Готово за { $seconds } с (сервер не сообщил расход токенов)
+
+# Arena
+arena-selected = Сравниваемые модели ({ $count }/{ $max }):
+arena-remove = Убрать { $name } из сравнения
+arena-clear = Очистить
+arena-select-hint = Выберите от { $min } до { $max } моделей в списке или таблице кнопкой ⚖, чтобы сравнить их ответы.
+arena-toggle-add = Добавить в сравнение на арене
+arena-toggle-remove = Убрать из сравнения на арене
+arena-toggle-full = На арене можно сравнить не более { $max } моделей
+arena-run = Запустить на всех моделях
+arena-streaming = Получение ответа...
+arena-latency = Общее время: { $seconds } с
+arena-first-token = Первый токен: { $seconds } с
+arena-tokens = Токенов: { $prompt } промпт + { $completion } завершение
+arena-cost = Стоимость: { $cost }
+arena-no-usage = Сервер не сообщил расход токенов
+arena-export = Экспортировать сессию в JSON
+arena-export-copy = Копировать JSON
+arena-export-copied = JSON сессии скопирован в буфер обмена
+
+# Keyboard Shortcuts
+shortcuts-hint = Нажмите ? для списка горячих клавиш
+shortcuts-title = Горячие клавиши
+shortcut-focus-filter = Перейти к фильтру
+shortcut-select-next = Выбрать следующую модель
+shortcut-select-previous = Выбрать предыдущую модель
+shortcut-open-selected = Открыть выбранную модель
+shortcut-copy-slug = Скопировать slug выбранной модели
+shortcut-toggle-favorite = Добавить выбранную модель в избранное или убрать из него
+shortcut-previous-model = Предыдущая модель (в окне подробностей)
+shortcut-next-model = Следующая модель (в окне подробностей)
+shortcut-show-help = Показать или скрыть этот список
+shortcut-close = Закрыть окно
+
 # Language Switcher
 language-name = Русский
-language-code = РУ
+language-label = Язык
+
+# Theme Switcher
+theme-label = Цветовая тема
+theme-auto = 🌓 Авто
+theme-light = ☀️ Светлая
+theme-dark = 🌙 Тёмная
+theme-high-contrast = ◐ Высокий контраст
+
+# Currency
+currency-label = Валюта отображения
+currency-rub = ₽ Российский рубль
+currency-usd = $ Доллар США
+currency-eur = € Евро
+currency-cny = ¥ Китайский юань
+currency-rate-label = Рублей за 1 { $currency }
+currency-rate-bundled = справочный курс на { $date }
+currency-rate-custom = ваш курс, введён { $date }
+currency-rate-reset = Вернуть справочный курс
+
+# Relative Dates
+relative-just-now = только что
+relative-minutes-ago = { $count ->
+    [one] { $count } минуту назад
+    [few] { $count } минуты назад
+   *[many] { $count } минут назад
+}
+relative-hours-ago = { $count ->
+    [one] { $count } час назад
+    [few] { $count } часа назад
+   *[many] { $count } часов назад
+}
+relative-days-ago = { $count ->
+    [one] { $count } день назад
+    [few] { $count } дня назад
+   *[many] { $count } дней назад
+}
+relative-months-ago = { $count ->
+    [one] { $count } месяц назад
+    [few] { $count } месяца назад
+   *[many] { $count } месяцев назад
+}
+relative-years-ago = { $count ->
+    [one] { $count } год назад
+    [few] { $count } года назад
+   *[many] { $count } лет назад
+}
+
+# Price Units
+# Token prices are labelled with the chosen unit; `$unit` is token, thousand, million or billion
+price-unit-label = Единица цены
+price-unit-long = { $unit ->
+    [token] токен
+    [thousand] 1K токенов
+    [billion] 1B токенов
+   *[million] 1M токенов
+}
+price-unit-short = { $unit ->
+    [token] токен
+    [thousand] 1K
+    [billion] 1B
+   *[million] 1M
+}
+price-unit-option = за { price-unit-long }
@@ -0,0 +1,77 @@
+// Service worker keeping the app usable offline.
+//
+// Pages (the app shell with the pre-rendered catalog or model) are fetched network-first so
+// deployments show up on the next load;
+// the wasm bundle, scripts and assets have content-hashed names and are served from the cache,
+// refreshed in the background. The model catalog itself lives in IndexedDB, and requests to the
+// API (another origin) are never intercepted.
+
+const CACHE_NAME = 'polza-models-v1';
+const SHELL = ['./', './manifest.webmanifest', './icon.svg'];
+
+// Same-origin scripts, styles and wasm referenced by the app shell
+const linkedAssets = (html) =>
+    [...html.matchAll(/(?:src|href)="([^"]+\.(?:js|wasm|css))"/g)]
+        .map((match) => new URL(match[1], self.registration.scope))
+        .filter((url) => url.origin === self.location.origin)
+        .map((url) => url.href);
+
+self.addEventListener('install', (event) => {
+    event.waitUntil((async () => {
+        const cache = await caches.open(CACHE_NAME);
+        await cache.addAll(SHELL);
+        const shell = await cache.match('./');
+        if (shell) {
+            await cache.addAll(linkedAssets(await shell.text()));
+        }
+        await self.skipWaiting();
+    })());
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil((async () => {
+        const names = await caches.keys();
+        await Promise.all(names.filter((name) => name !== CACHE_NAME).map((name) => caches.delete(name)));
+        await self.clients.claim();
+    })());
+});
+
+self.addEventListener('fetch', (event) => {
+    const request = event.request;
+    if (request.method !== 'GET' || new URL(request.url).origin !== self.location.origin) {
+        return;
+    }
+
+    if (request.mode === 'navigate') {
+        event.respondWith((async () => {
+            const cache = await caches.open(CACHE_NAME);
+            try {
+                const response = await fetch(request);
+                if (response.ok) {
+                    // Pre-rendered model pages are kept next to the catalog page, not in its place
+                    await cache.put(request, response.clone());
+                }
+                return response;
+            } catch (error) {
+                return (await cache.match(request)) || (await cache.match('./')) || Response.error();
+            }
+        })());
+        return;
+    }
+
+    event.respondWith((async () => {
+        const cache = await caches.open(CACHE_NAME);
+        const cached = await cache.match(request);
+        const refreshed = fetch(request).then((response) => {
+            if (response.ok) {
+                cache.put(request, response.clone());
+            }
+            return response;
+        });
+        if (cached) {
+            event.waitUntil(refreshed.catch(() => undefined));
+            return cached;
+        }
+        return refreshed;
+    })());
+});
@@ -0,0 +1,515 @@
+use std::fmt;
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::BilledTokens;
+
+use super::client::API_BASE_URL;
+
+/// Connection settings for chat completions, entered by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiSettings {
+    pub api_key: String,
+    /// OpenAI-compatible base URL (can point at a local mock server)
+    pub base_url: String,
+    /// Keep the settings, key included, in storage across sessions (opt-in)
+    #[serde(default)]
+    pub remember_key: bool,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            base_url: API_BASE_URL.to_string(),
+            remember_key: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: "user".to_string(),
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StreamOptions {
+    pub include_usage: bool,
+}
+
+/// Streaming chat completion request
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    pub stream: bool,
+    pub stream_options: StreamOptions,
+    /// Sampling parameters (temperature, max_tokens, ...) sent as top-level fields
+    #[serde(flatten)]
+    pub parameters: Map<String, Value>,
+}
+
+impl ChatRequest {
+    /// Single-prompt request that streams the answer and reports token usage at the end
+    pub fn new(
+        model: impl Into<String>,
+        prompt: impl Into<String>,
+        parameters: Map<String, Value>,
+    ) -> Self {
+        Self {
+            model: model.into(),
+            messages: vec![ChatMessage::user(prompt)],
+            stream: true,
+            stream_options: StreamOptions {
+                include_usage: true,
+            },
+            parameters,
+        }
+    }
+}
+
+/// Tokens billed for a completion
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    #[serde(default)]
+    pub total_tokens: u64,
+    /// Cached part of the prompt, if the server reports it
+    #[serde(default)]
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+    /// Reasoning part of the completion, if the server reports it
+    #[serde(default)]
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptTokensDetails {
+    #[serde(default)]
+    pub cached_tokens: u64,
+    #[serde(default)]
+    pub cache_write_tokens: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionTokensDetails {
+    #[serde(default)]
+    pub reasoning_tokens: u64,
+}
+
+impl Usage {
+    /// Counts as billed by [`Pricing::usage_cost`](crate::models::Pricing::usage_cost)
+    pub fn billed(&self) -> BilledTokens {
+        let prompt = self.prompt_tokens_details.unwrap_or_default();
+        let completion = self.completion_tokens_details.unwrap_or_default();
+        BilledTokens {
+            prompt: self.prompt_tokens,
+            completion: self.completion_tokens,
+            reasoning: completion.reasoning_tokens,
+            cache_read: prompt.cached_tokens,
+            cache_write: prompt.cache_write_tokens,
+        }
+    }
+}
+
+/// What is known once a streamed completion has finished
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChatOutcome {
+    /// Reported by the final chunk; `None` if the server did not send it
+    pub usage: Option<Usage>,
+    pub finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatError {
+    MissingApiKey,
+    /// The request could not be sent or the connection broke
+    Http(String),
+    /// The server answered with an error status
+    Status {
+        status: u16,
+        message: String,
+    },
+    /// The stream contained an error or could not be parsed
+    Stream(String),
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::MissingApiKey => write!(f, "no API key"),
+            ChatError::Http(message) | ChatError::Stream(message) => write!(f, "{message}"),
+            ChatError::Status { status, message } if message.is_empty() => {
+                write!(f, "HTTP {status}")
+            }
+            ChatError::Status { status, message } => write!(f, "HTTP {status}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ChatError {}
+
+impl From<reqwest::Error> for ChatError {
+    fn from(err: reqwest::Error) -> Self {
+        ChatError::Http(err.to_string())
+    }
+}
+
+/// Send a chat completion and stream the answer, calling `on_delta` for every piece of text
+pub async fn stream_chat_completion(
+    settings: &ApiSettings,
+    request: &ChatRequest,
+    mut on_delta: impl FnMut(&str),
+) -> Result<ChatOutcome, ChatError> {
+    let api_key = settings.api_key.trim();
+    if api_key.is_empty() {
+        return Err(ChatError::MissingApiKey);
+    }
+
+    let url = format!(
+        "{}/chat/completions",
+        settings.base_url.trim().trim_end_matches('/')
+    );
+    let response = reqwest::Client::new()
+        .post(url)
+        .bearer_auth(api_key)
+        .json(request)
+        .send()
+        .await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(ChatError::Status {
+            status: status.as_u16(),
+            message: error_message(&body),
+        });
+    }
+
+    let mut decoder = SseDecoder::default();
+    let mut outcome = ChatOutcome::default();
+    let mut body = response.bytes_stream();
+    while let Some(bytes) = body.next().await {
+        for data in decoder.push(&bytes?) {
+            if data == "[DONE]" {
+                return Ok(outcome);
+            }
+            let chunk: StreamChunk =
+                serde_json::from_str(&data).map_err(|err| ChatError::Stream(err.to_string()))?;
+            if let Some(error) = chunk.error {
+                return Err(ChatError::Stream(error.message));
+            }
+            for choice in chunk.choices {
+                if let Some(content) = choice.delta.content.filter(|content| !content.is_empty()) {
+                    on_delta(&content);
+                }
+                if choice.finish_reason.is_some() {
+                    outcome.finish_reason = choice.finish_reason;
+                }
+            }
+            if chunk.usage.is_some() {
+                outcome.usage = chunk.usage;
+            }
+        }
+    }
+    Ok(outcome)
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamChunk {
+    #[serde(default)]
+    choices: Vec<StreamChoice>,
+    usage: Option<Usage>,
+    error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamChoice {
+    #[serde(default)]
+    delta: Delta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Delta {
+    content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+/// Message of an OpenAI-style `{"error": {"message": ...}}` body, or the body itself
+fn error_message(body: &str) -> String {
+    #[derive(Deserialize)]
+    struct ErrorResponse {
+        error: ErrorBody,
+    }
+
+    serde_json::from_str::<ErrorResponse>(body)
+        .map(|response| response.error.message)
+        .unwrap_or_else(|_| body.trim().to_string())
+}
+
+/// Incremental decoder for a `text/event-stream` body
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    /// Bytes of the line that is still incomplete
+    pending: Vec<u8>,
+    /// `data:` lines of the event that is still incomplete
+    data: Vec<String>,
+}
+
+impl SseDecoder {
+    /// Feed the next received bytes, returning the data of every event they complete.
+    ///
+    /// Chunks may split lines (and UTF-8 sequences) anywhere; comment lines
+    /// (`: keep-alive`) and fields other than `data` are ignored.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(bytes);
+
+        let mut events = Vec::new();
+        while let Some(end) = self.pending.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(self.data.join("\n"));
+                    self.data.clear();
+                }
+            } else if let Some(value) = line.strip_prefix("data:") {
+                self.data
+                    .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_sse_decoder_handles_split_chunks() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(b": keep-alive\n\nda").is_empty());
+        assert!(decoder.push(b"ta: {\"a\":1}\r").is_empty());
+        assert_eq!(
+            decoder.push(b"\n\r\ndata: [DONE]\n\n"),
+            ["{\"a\":1}", "[DONE]"]
+        );
+
+        // A multi-byte character split between two chunks
+        let text = "data: привет\n\n".as_bytes();
+        assert!(decoder.push(&text[..8]).is_empty());
+        assert_eq!(decoder.push(&text[8..]), ["привет"]);
+    }
+
+    #[test]
+    fn test_request_body() {
+        let mut parameters = Map::new();
+        parameters.insert("temperature".to_string(), Value::from(0.5));
+        let request = ChatRequest::new("openai/gpt-5-image-mini", "Hi", parameters);
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["model"], "openai/gpt-5-image-mini");
+        assert_eq!(body["messages"][0]["content"], "Hi");
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["stream_options"]["include_usage"], true);
+        assert_eq!(body["temperature"], 0.5);
+    }
+
+    #[test]
+    fn test_stored_settings_without_the_flag_are_not_remembered() {
+        // Settings saved before "remember key" existed
+        let settings: ApiSettings =
+            serde_json::from_str(r#"{"api_key":"secret","base_url":"http://localhost"}"#).unwrap();
+        assert!(!settings.remember_key);
+        assert!(!ApiSettings::default().remember_key);
+    }
+
+    #[test]
+    fn test_usage_details() {
+        let usage: Usage = serde_json::from_str(
+            r#"{"prompt_tokens":1000,"completion_tokens":200,"total_tokens":1200,
+                "prompt_tokens_details":{"cached_tokens":600},
+                "completion_tokens_details":{"reasoning_tokens":150}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            usage.billed(),
+            BilledTokens {
+                prompt: 1000,
+                completion: 200,
+                reasoning: 150,
+                cache_read: 600,
+                cache_write: 0,
+            }
+        );
+
+        let plain: Usage =
+            serde_json::from_str(r#"{"prompt_tokens":12,"completion_tokens":3}"#).unwrap();
+        assert_eq!(plain.billed().reasoning, 0);
+        assert_eq!(plain.billed().cache_read, 0);
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            error_message(r#"{"error": {"message": "Invalid API key", "code": 401}}"#),
+            "Invalid API key"
+        );
+        assert_eq!(error_message(" Bad gateway \n"), "Bad gateway");
+    }
+
+    /// Serve one HTTP response on a local port, sending the raw request back through the channel
+    fn mock_server(response: String) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            sender.send(request).unwrap();
+
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        (format!("http://{address}"), receiver)
+    }
+
+    fn settings(base_url: String) -> ApiSettings {
+        ApiSettings {
+            api_key: "test-key".to_string(),
+            base_url,
+            remember_key: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_against_mock_server() {
+        let events = [
+            r#"{"choices":[{"delta":{"role":"assistant","content":""}}]}"#,
+            r#"{"choices":[{"delta":{"content":"Hello"}}]}"#,
+            r#"{"choices":[{"delta":{"content":", world"},"finish_reason":"stop"}]}"#,
+            r#"{"choices":[],"usage":{"prompt_tokens":12,"completion_tokens":3,"total_tokens":15}}"#,
+            "[DONE]",
+        ];
+        let body: String = events
+            .iter()
+            .map(|event| format!("data: {event}\n\n"))
+            .collect();
+        let (base_url, request) = mock_server(format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n{body}"
+        ));
+
+        let mut text = String::new();
+        let outcome = stream_chat_completion(
+            &settings(base_url),
+            &ChatRequest::new("nvidia/nemotron-nano-12b-v2-vl", "Hi", Map::new()),
+            |delta| text.push_str(delta),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(text, "Hello, world");
+        assert_eq!(outcome.finish_reason.as_deref(), Some("stop"));
+        assert_eq!(
+            outcome.usage,
+            Some(Usage {
+                prompt_tokens: 12,
+                completion_tokens: 3,
+                total_tokens: 15,
+                ..Usage::default()
+            })
+        );
+
+        let request = request.recv().unwrap();
+        assert!(
+            request.starts_with("POST /chat/completions HTTP/1.1\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.contains("authorization: Bearer test-key\r\n"),
+            "{request}"
+        );
+        assert!(
+            request.contains(r#""model":"nvidia/nemotron-nano-12b-v2-vl""#),
+            "{request}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_status_against_mock_server() {
+        let body = r#"{"error":{"message":"Insufficient balance"}}"#;
+        let (base_url, _request) = mock_server(format!(
+            "HTTP/1.1 402 Payment Required\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ));
+
+        let result = stream_chat_completion(
+            &settings(base_url),
+            &ChatRequest::new("nvidia/nemotron-nano-12b-v2-vl", "Hi", Map::new()),
+            |_| {},
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            Err(ChatError::Status {
+                status: 402,
+                message: "Insufficient balance".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_api_key() {
+        let result = stream_chat_completion(
+            &ApiSettings::default(),
+            &ChatRequest::new("nvidia/nemotron-nano-12b-v2-vl", "Hi", Map::new()),
+            |_| {},
+        )
+        .await;
+        assert_eq!(result, Err(ChatError::MissingApiKey));
+    }
+}
@@ -1,19 +1,26 @@
-use crate::cache::{load_from_cache, save_to_cache};
+use crate::cache::{load_from_cache, load_offline_copy, save_to_cache, CacheError};
+use crate::history::{record_prices, record_snapshot};
 use crate::models::ApiResponse;
 use crate::utils::tokenize;
 
 #[cfg(target_arch = "wasm32")]
 use gloo_console::log;
 
-const API_ENDPOINT: &str = "https://api.polza.ai/api/v1/models";
+/// Base URL of the OpenAI-compatible Polza AI API
+pub const API_BASE_URL: &str = "https://api.polza.ai/api/v1";
 
-/// Fetch models from the API (or load from cache)
-pub async fn fetch_models() -> Result<ApiResponse, reqwest::Error> {
+/// Fetch models from the API (or load from cache).
+///
+/// `on_cache_error` is told when the fetched catalog, its snapshot or its prices could not be
+/// saved, e.g. because the browser's storage quota is used up.
+pub async fn fetch_models(
+    mut on_cache_error: impl FnMut(CacheError),
+) -> Result<ApiResponse, reqwest::Error> {
     #[cfg(target_arch = "wasm32")]
     log!("[API] 🔄 fetch_models() called");
 
     // Try to load from cache first
-    if let Some(cached_models) = load_from_cache() {
+    if let Some(cached_models) = load_from_cache().await {
         #[cfg(target_arch = "wasm32")]
         log!("[API] ✓ Returning cached data");
         return Ok(ApiResponse {
@@ -22,13 +29,25 @@ pub async fn fetch_models() -> Result<ApiResponse, reqwest::Error> {
     }
 
     // Cache miss - fetch from API
+    let endpoint = format!("{API_BASE_URL}/models");
     #[cfg(target_arch = "wasm32")]
-    log!(format!("[API] 🌐 Fetching from API: {}", API_ENDPOINT));
+    log!(format!("[API] 🌐 Fetching from API: {}", endpoint));
 
-    let response = reqwest::get(API_ENDPOINT)
-        .await?
-        .json::<ApiResponse>()
-        .await?;
+    let response = match fetch_catalog(&endpoint).await {
+        Ok(response) => response,
+        Err(err) => {
+            // Offline or the API is down: fall back to the saved catalog, however old
+            if let Some(saved_models) = load_offline_copy().await {
+                #[cfg(target_arch = "wasm32")]
+                log!(format!(
+                    "[API] ✗ Fetch failed ({}), returning saved data",
+                    err
+                ));
+                return Ok(ApiResponse { data: saved_models });
+            }
+            return Err(err);
+        }
+    };
 
     #[cfg(target_arch = "wasm32")]
     log!(format!(
@@ -36,6 +55,30 @@ pub async fn fetch_models() -> Result<ApiResponse, reqwest::Error> {
         response.data.len()
     ));
 
+    let filtered_response = prepare_catalog(response);
+
+    // Keep a dated snapshot for the change log and extend the price history
+    let recorded = [
+        record_snapshot(&filtered_response.data).await,
+        record_prices(&filtered_response.data).await,
+    ];
+
+    // Save to cache
+    let saved = save_to_cache(&filtered_response.data).await;
+    for err in recorded.into_iter().chain([saved]).filter_map(Result::err) {
+        on_cache_error(err);
+    }
+
+    Ok(filtered_response)
+}
+
+/// Fetch the catalog as the API returns it; see [`prepare_catalog`]
+pub async fn fetch_catalog(endpoint: &str) -> Result<ApiResponse, reqwest::Error> {
+    reqwest::get(endpoint).await?.json::<ApiResponse>().await
+}
+
+/// Drop the models without pricing, sort the rest by name and tokenize them for the filter
+pub fn prepare_catalog(response: ApiResponse) -> ApiResponse {
     let mut filtered_response = response;
     let original_count = filtered_response.data.len();
     filtered_response.data.retain(|x| !x.pricing.is_empty());
@@ -65,8 +108,5 @@ pub async fn fetch_models() -> Result<ApiResponse, reqwest::Error> {
         model.architecture.input_modalities.sort();
     });
 
-    // Save to cache
-    save_to_cache(&filtered_response.data);
-
-    Ok(filtered_response)
+    filtered_response
 }
@@ -1,3 +1,8 @@
+mod chat;
 mod client;
 
-pub use client::fetch_models;
+pub use chat::{
+    stream_chat_completion, ApiSettings, ChatError, ChatMessage, ChatOutcome, ChatRequest,
+    CompletionTokensDetails, PromptTokensDetails, SseDecoder, Usage,
+};
+pub use client::{fetch_catalog, fetch_models, prepare_catalog, API_BASE_URL};
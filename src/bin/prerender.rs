@@ -0,0 +1,149 @@
+//! Pre-render the app into the web bundle built by `dx bundle`: the catalog, one page per model and
+//! the fallback page for every other URL.
+//!
+//! ```text
+//! cargo run --release --features prerender --bin prerender -- <public dir> [--snapshot <models.json>]
+//! ```
+//!
+//! The catalog is fetched from the API, or read from a saved `/models` response with `--snapshot`.
+//! Each page embeds what it shows, so the app built with the `hydrate` feature takes it over.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::use_init_i18n;
+use time::OffsetDateTime;
+
+use polza_models::api::{fetch_catalog, prepare_catalog, API_BASE_URL};
+use polza_models::components::PageHead;
+use polza_models::i18n::{i18n_config, DEFAULT_LANGUAGE};
+use polza_models::models::{ApiResponse, Model};
+use polza_models::prerender::{
+    hydration_data, inject, model_page_path, root_prefix, PrerenderedPage, StaticPage,
+};
+use polza_models::App;
+
+const USAGE: &str = "usage: prerender <public dir> [--snapshot <models.json>]";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("prerender: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let public_dir = args.next().ok_or(USAGE)?;
+    let public_dir = Path::new(&public_dir);
+    let response = match (args.next().as_deref(), args.next()) {
+        (None, _) => fetch_catalog(&format!("{API_BASE_URL}/models")).await?,
+        (Some("--snapshot"), Some(path)) => {
+            serde_json::from_str::<ApiResponse>(&fs::read_to_string(path)?)?
+        }
+        _ => return Err(USAGE.into()),
+    };
+    let catalog = prepare_catalog(response);
+    let rendered_at = OffsetDateTime::now_utc();
+
+    // The shell is read once: every page is rendered into the pristine copy
+    let index_path = public_dir.join("index.html");
+    let shell = fs::read_to_string(&index_path)?;
+
+    // Served by GitHub Pages for every URL without a page; the app loads the catalog itself
+    let fallback = PrerenderedPage {
+        catalog: None,
+        model: None,
+        rendered_at,
+    };
+    fs::write(
+        public_dir.join("404.html"),
+        render_page(&shell, fallback, None)?,
+    )?;
+
+    let mut written = 0;
+    for model in &catalog.data {
+        let Some(path) = model_page_path(&model.id) else {
+            eprintln!(
+                "prerender: skipping {:?}, its id is not a usable path",
+                model.id
+            );
+            continue;
+        };
+        // The model's details over the loading app, which loads the catalog itself
+        let page = PrerenderedPage {
+            catalog: None,
+            model: Some(model.clone()),
+            rendered_at,
+        };
+        let html = render_page(&shell, page, Some(&root_prefix(&model.id)))?;
+        let path = public_dir.join(path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, html)?;
+        written += 1;
+    }
+
+    let page = PrerenderedPage {
+        catalog: Some(catalog),
+        model: None,
+        rendered_at,
+    };
+    fs::write(&index_path, render_page(&shell, page, None)?)?;
+
+    println!(
+        "Pre-rendered the catalog, {written} model pages and 404.html into {}",
+        public_dir.display()
+    );
+    Ok(())
+}
+
+/// The app showing `page`, put into the app shell with the data to hydrate it
+fn render_page(
+    shell: &str,
+    page: PrerenderedPage,
+    base: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let head = render_head(page.model.clone());
+    let data = hydration_data(&page);
+    let body = render_app(page);
+    let page = StaticPage {
+        head: &head,
+        body: &body,
+        data: &data,
+        base,
+    };
+    Ok(inject(shell, page)?)
+}
+
+/// `<title>` and meta tags of the catalog, or of the page of `model`, in the default language
+fn render_head(model: Option<Model>) -> String {
+    fn root(model: Option<Model>) -> Element {
+        use_init_i18n(|| i18n_config(DEFAULT_LANGUAGE));
+        rsx! { PageHead { model: model } }
+    }
+
+    let mut dom = VirtualDom::new_with_props(root, model);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+/// The app as it renders `page`, with the markers it hydrates from
+fn render_app(page: PrerenderedPage) -> String {
+    fn root(page: PrerenderedPage) -> Element {
+        use_context_provider(|| page);
+        rsx! { App {} }
+    }
+
+    let mut dom = VirtualDom::new_with_props(root, page);
+    dom.rebuild_in_place();
+    dioxus_ssr::pre_render(&dom)
+}
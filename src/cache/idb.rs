@@ -0,0 +1,209 @@
+//! Async wrappers over the callback-based IndexedDB API.
+
+use js_sys::{Array, Promise, JSON};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    IdbDatabase, IdbObjectStoreParameters, IdbRequest, IdbRequestReadyState, IdbTransaction,
+    IdbTransactionMode, IdbVersionChangeEvent,
+};
+
+use super::CacheError;
+
+/// Database holding the cached catalog and its history
+const DATABASE_NAME: &str = "polza-models";
+
+/// Version of the database layout; opening an older database runs [`upgrade`]
+const DATABASE_VERSION: u32 = 3;
+
+/// Object store of cached values, keyed by name
+pub const CACHE_STORE: &str = "cache";
+
+/// Object store of catalog models, one record per model keyed by its `id`
+pub const MODELS_STORE: &str = "models";
+
+/// Indices of [`MODELS_STORE`] on the `provider` and `created` fields of the records
+pub const PROVIDER_INDEX: &str = "provider";
+pub const CREATED_INDEX: &str = "created";
+
+/// Object store of catalog snapshots, keyed by `taken_at`
+pub const SNAPSHOTS_STORE: &str = "snapshots";
+
+/// Object store of price points, one record per model and time keyed by `[id, at]`
+pub const PRICES_STORE: &str = "prices";
+
+/// Index of [`PRICES_STORE`] on the model `id`
+pub const PRICE_MODEL_INDEX: &str = "id";
+
+/// Open the database, creating or upgrading its object stores as needed
+pub async fn open() -> Result<IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .ok_or("no window")?
+        .indexed_db()?
+        .ok_or("IndexedDB is unavailable")?;
+    let request = factory.open_with_u32(DATABASE_NAME, DATABASE_VERSION)?;
+
+    let upgrading = request.clone();
+    let on_upgrade =
+        Closure::<dyn FnMut(IdbVersionChangeEvent)>::new(move |event: IdbVersionChangeEvent| {
+            let database = upgrading.result().and_then(|result| result.dyn_into());
+            let result = database.and_then(|database| {
+                let transaction = upgrading.transaction().ok_or("no upgrade transaction")?;
+                upgrade(&database, &transaction, event.old_version())
+            });
+            if let Err(err) = result {
+                gloo_console::log!(format!(
+                    "[Cache] ✗ Failed to upgrade the database: {}",
+                    describe(&err)
+                ));
+            }
+        });
+    request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+    let opened = wait(&request).await;
+    request.set_onupgradeneeded(None);
+
+    let database: IdbDatabase = opened?.dyn_into()?;
+    // Let a newer version of the app, open in another tab, upgrade the database
+    database.set_onversionchange(Some(&js_sys::Function::new_no_args("this.close();")));
+    Ok(database)
+}
+
+/// Bring the object stores of an older version of the database up to date
+fn upgrade(
+    database: &IdbDatabase,
+    transaction: &IdbTransaction,
+    old_version: f64,
+) -> Result<(), JsValue> {
+    if old_version < 1.0 {
+        database.create_object_store(CACHE_STORE)?;
+    }
+    if old_version < 2.0 {
+        // Version 1 kept the whole catalog as one JSON string in the cache store
+        transaction.object_store(CACHE_STORE)?.clear()?;
+
+        let parameters = IdbObjectStoreParameters::new();
+        parameters.set_key_path(&"id".into());
+        let models =
+            database.create_object_store_with_optional_parameters(MODELS_STORE, &parameters)?;
+        models.create_index_with_str(PROVIDER_INDEX, "provider")?;
+        models.create_index_with_str(CREATED_INDEX, "created")?;
+    }
+    if old_version < 3.0 {
+        // Version 2 left the snapshots and the price history in LocalStorage
+        let parameters = IdbObjectStoreParameters::new();
+        parameters.set_key_path(&"taken_at".into());
+        database.create_object_store_with_optional_parameters(SNAPSHOTS_STORE, &parameters)?;
+
+        let parameters = IdbObjectStoreParameters::new();
+        parameters.set_key_path(&Array::of2(&"id".into(), &"at".into()));
+        let prices =
+            database.create_object_store_with_optional_parameters(PRICES_STORE, &parameters)?;
+        prices.create_index_with_str(PRICE_MODEL_INDEX, "id")?;
+    }
+    Ok(())
+}
+
+/// Wait for a request to finish and return its result
+pub async fn wait(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    // A finished request fires no more events
+    if request.ready_state() == IdbRequestReadyState::Pending {
+        let promise = Promise::new(&mut |resolve, reject| {
+            request.set_onsuccess(Some(&resolve));
+            request.set_onerror(Some(&reject));
+        });
+        // A failure is read from the request below
+        let _ = JsFuture::from(promise).await;
+        request.set_onsuccess(None);
+        request.set_onerror(None);
+    }
+
+    match request.error() {
+        Ok(Some(error)) => Err(error.into()),
+        _ => request.result(),
+    }
+}
+
+/// Wait for a read-write transaction to be committed.
+///
+/// A failed write (e.g. with `QuotaExceededError`) aborts the transaction; its error is returned.
+pub async fn committed(transaction: &IdbTransaction) -> Result<(), JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        transaction.set_oncomplete(Some(&resolve));
+        transaction.set_onerror(Some(&reject));
+        transaction.set_onabort(Some(&reject));
+    });
+    let outcome = JsFuture::from(promise).await;
+    transaction.set_oncomplete(None);
+    transaction.set_onerror(None);
+    transaction.set_onabort(None);
+
+    outcome.map(|_| ()).map_err(|_| {
+        transaction
+            .error()
+            .map_or_else(|| "transaction aborted".into(), JsValue::from)
+    })
+}
+
+/// Add or replace `records` in one object store, in a single transaction
+pub async fn put_all(store_name: &str, records: &[JsValue]) -> Result<(), JsValue> {
+    let database = open().await?;
+    let transaction =
+        database.transaction_with_str_and_mode(store_name, IdbTransactionMode::Readwrite)?;
+    let queued = (|| {
+        let store = transaction.object_store(store_name)?;
+        for record in records {
+            store.put(record)?;
+        }
+        Ok::<_, JsValue>(())
+    })();
+    if let Err(err) = queued {
+        // Nothing is written unless every record was accepted
+        let _ = transaction.abort();
+        return Err(err);
+    }
+    committed(&transaction).await
+}
+
+/// A value as a JavaScript object, going through its JSON form
+pub fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    let json = serde_json::to_string(value).map_err(|err| JsValue::from(err.to_string()))?;
+    JSON::parse(&json)
+}
+
+/// A value read back from a JavaScript object; `None` if missing or unreadable
+pub fn from_js<T: DeserializeOwned>(value: &JsValue) -> Option<T> {
+    if value.is_undefined() {
+        return None;
+    }
+    let json = String::from(JSON::stringify(value).ok()?);
+    serde_json::from_str(&json).ok()
+}
+
+/// The [`CacheError`] to report for a failed write
+pub fn cache_error(error: &JsValue) -> CacheError {
+    match error_name(error).as_deref() {
+        Some("QuotaExceededError") => CacheError::QuotaExceeded,
+        _ => CacheError::Failed(describe(error)),
+    }
+}
+
+/// Name of a `DOMException` or `Error`, e.g. `QuotaExceededError`
+pub fn error_name(error: &JsValue) -> Option<String> {
+    js_sys::Reflect::get(error, &"name".into())
+        .ok()?
+        .as_string()
+}
+
+/// Human-readable description of an IndexedDB error, e.g. `QuotaExceededError: …`
+pub fn describe(error: &JsValue) -> String {
+    let message = js_sys::Reflect::get(error, &"message".into())
+        .ok()
+        .and_then(|message| message.as_string());
+    match (error_name(error), message) {
+        (Some(name), Some(message)) => format!("{name}: {message}"),
+        (Some(text), None) | (None, Some(text)) => text,
+        (None, None) => error.as_string().unwrap_or_else(|| format!("{error:?}")),
+    }
+}
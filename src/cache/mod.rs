@@ -1,9 +1,33 @@
+use std::fmt;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) mod idb;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 #[cfg(target_arch = "wasm32")]
-pub use wasm::{clear_cache, load_from_cache, save_to_cache};
+pub use wasm::{expire_cache, load_from_cache, load_offline_copy, save_to_cache};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod native;
 #[cfg(not(target_arch = "wasm32"))]
-pub use native::{clear_cache, load_from_cache, save_to_cache};
+pub use native::{expire_cache, load_from_cache, load_offline_copy, save_to_cache};
+
+/// Why the catalog or its history could not be saved for offline use
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheError {
+    /// The browser's storage quota for the site is used up
+    QuotaExceeded,
+    /// The storage refused the write for another reason
+    Failed(String),
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::QuotaExceeded => write!(f, "storage quota exceeded"),
+            CacheError::Failed(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
@@ -1,16 +1,23 @@
+use super::CacheError;
 use crate::models::Model;
 
 /// No-op implementation for non-web platforms
-pub fn load_from_cache() -> Option<Vec<Model>> {
+pub async fn load_from_cache() -> Option<Vec<Model>> {
     None
 }
 
 /// No-op implementation for non-web platforms
-pub fn save_to_cache(_models: &[Model]) {
+pub async fn load_offline_copy() -> Option<Vec<Model>> {
+    None
+}
+
+/// No-op implementation for non-web platforms
+pub async fn save_to_cache(_models: &[Model]) -> Result<(), CacheError> {
     // No-op on non-web platforms
+    Ok(())
 }
 
 /// No-op implementation for non-web platforms
-pub fn clear_cache() {
+pub async fn expire_cache() {
     // No-op on non-web platforms
 }
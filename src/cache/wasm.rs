@@ -1,24 +1,44 @@
 use crate::models::Model;
 use gloo_console::log;
+use gloo_storage::errors::StorageError;
 use gloo_storage::{LocalStorage, Storage};
-use js_sys::Date;
+use js_sys::{Array, Date, Reflect};
 use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::IdbTransactionMode;
 
-const CACHE_KEY: &str = "polza_models_cache";
+use super::idb::{self, from_js, to_js, CACHE_STORE, MODELS_STORE};
+use super::CacheError;
+
+/// LocalStorage key of the catalog cached by versions before the IndexedDB cache
+const LEGACY_CACHE_KEY: &str = "polza_models_cache";
+/// Key of the catalog metadata in the cache store
+const CATALOG_KEY: &str = "catalog";
 const CACHE_DURATION_MS: f64 = 60.0 * 60.0 * 1000.0; // 1 hour in milliseconds
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct CachedModels {
-    data: Vec<Model>,
+/// When the cached catalog was saved and how many models it has
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct CatalogMeta {
     timestamp: f64, // Milliseconds since Unix epoch
+    count: usize,
+}
+
+/// The catalog as the LocalStorage cache kept it
+#[derive(Debug, Clone, Deserialize)]
+struct LegacyCachedModels {
+    data: Vec<Model>,
+    timestamp: f64,
 }
 
-pub fn load_from_cache() -> Option<Vec<Model>> {
-    let cached: Result<CachedModels, _> = LocalStorage::get(CACHE_KEY);
+struct CachedModels {
+    data: Vec<Model>,
+    meta: CatalogMeta,
+}
 
-    if let Ok(cached) = cached {
+pub async fn load_from_cache() -> Option<Vec<Model>> {
+    if let Some(cached) = read_cached().await {
         let now = Date::now();
-        let age = now - cached.timestamp;
+        let age = now - cached.meta.timestamp;
         let age_minutes = age / 60000.0;
 
         if age < CACHE_DURATION_MS {
@@ -41,17 +61,185 @@ pub fn load_from_cache() -> Option<Vec<Model>> {
     None
 }
 
-pub fn save_to_cache(models: &[Model]) {
-    let cached = CachedModels {
-        data: models.to_vec(),
+/// The cached catalog however old it is, for when the API cannot be reached
+pub async fn load_offline_copy() -> Option<Vec<Model>> {
+    let cached = read_cached().await?;
+    log!(format!(
+        "[Cache] ✓ Using the saved catalog offline - {} models",
+        cached.data.len()
+    ));
+    Some(cached.data)
+}
+
+pub async fn save_to_cache(models: &[Model]) -> Result<(), CacheError> {
+    let meta = CatalogMeta {
         timestamp: Date::now(),
+        count: models.len(),
     };
 
-    let _ = LocalStorage::set(CACHE_KEY, cached);
+    write_catalog(models, meta).await?;
     log!(format!("[Cache] ✓ Saved {} models to cache", models.len()));
+    Ok(())
+}
+
+/// Mark the cache as expired, keeping the data as the offline copy
+pub async fn expire_cache() {
+    if let Some(meta) = read_meta().await {
+        let expired = CatalogMeta {
+            timestamp: 0.0,
+            ..meta
+        };
+        let write = async {
+            let database = idb::open().await?;
+            let transaction = database
+                .transaction_with_str_and_mode(CACHE_STORE, IdbTransactionMode::Readwrite)?;
+            transaction
+                .object_store(CACHE_STORE)?
+                .put_with_key(&to_js(&expired)?, &CATALOG_KEY.into())?;
+            idb::committed(&transaction).await
+        };
+        if let Err(err) = write.await {
+            log!(format!(
+                "[Cache] ✗ Failed to expire cache: {}",
+                idb::describe(&err)
+            ));
+        }
+    }
+    log!("[Cache] 🗑️  Cache expired - next fetch will reload from API");
+}
+
+async fn read_meta() -> Option<CatalogMeta> {
+    let read = async {
+        let database = idb::open().await?;
+        let store = database
+            .transaction_with_str(CACHE_STORE)?
+            .object_store(CACHE_STORE)?;
+        idb::wait(&store.get(&CATALOG_KEY.into())?).await
+    };
+
+    match read.await {
+        Ok(meta) => from_js(&meta),
+        Err(err) => {
+            log!(format!(
+                "[Cache] ✗ Failed to read cache: {}",
+                idb::describe(&err)
+            ));
+            None
+        }
+    }
+}
+
+async fn read_cached() -> Option<CachedModels> {
+    migrate_local_storage().await;
+
+    let read = async {
+        let database = idb::open().await?;
+        let transaction = database.transaction_with_str_sequence(&Array::of2(
+            &CACHE_STORE.into(),
+            &MODELS_STORE.into(),
+        ))?;
+        let meta = transaction
+            .object_store(CACHE_STORE)?
+            .get(&CATALOG_KEY.into())?;
+        let records = transaction.object_store(MODELS_STORE)?.get_all()?;
+        Ok::<_, JsValue>((idb::wait(&meta).await?, idb::wait(&records).await?))
+    };
+
+    let (meta, records) = match read.await {
+        Ok(read) => read,
+        Err(err) => {
+            log!(format!(
+                "[Cache] ✗ Failed to read cache: {}",
+                idb::describe(&err)
+            ));
+            return None;
+        }
+    };
+    let meta: CatalogMeta = from_js(&meta)?;
+    let data: Vec<Model> = Array::from(&records)
+        .iter()
+        .filter_map(|record| from_js(&record))
+        .collect();
+
+    // A catalog with unreadable records is treated as missing
+    (data.len() == meta.count).then_some(CachedModels { data, meta })
+}
+
+/// Replace the cached catalog in a single transaction
+async fn write_catalog(models: &[Model], meta: CatalogMeta) -> Result<(), CacheError> {
+    let write = async {
+        let records = models
+            .iter()
+            .map(model_record)
+            .collect::<Result<Vec<_>, _>>()?;
+        let meta = to_js(&meta)?;
+
+        let database = idb::open().await?;
+        let transaction = database.transaction_with_str_sequence_and_mode(
+            &Array::of2(&CACHE_STORE.into(), &MODELS_STORE.into()),
+            IdbTransactionMode::Readwrite,
+        )?;
+        let queued = (|| {
+            let store = transaction.object_store(MODELS_STORE)?;
+            // Models dropped from the catalog must not linger
+            store.clear()?;
+            for record in &records {
+                store.put(record)?;
+            }
+            transaction
+                .object_store(CACHE_STORE)?
+                .put_with_key(&meta, &CATALOG_KEY.into())?;
+            Ok::<_, JsValue>(())
+        })();
+        if let Err(err) = queued {
+            // Nothing is written unless every record was accepted
+            let _ = transaction.abort();
+            return Err(err);
+        }
+        idb::committed(&transaction).await
+    };
+
+    write.await.map_err(|err| {
+        log!(format!(
+            "[Cache] ✗ Failed to save cache: {}",
+            idb::describe(&err)
+        ));
+        idb::cache_error(&err)
+    })
+}
+
+/// Move the catalog cached in LocalStorage by earlier versions into IndexedDB, once
+async fn migrate_local_storage() {
+    match LocalStorage::get::<LegacyCachedModels>(LEGACY_CACHE_KEY) {
+        Ok(legacy) => {
+            let meta = CatalogMeta {
+                timestamp: legacy.timestamp,
+                count: legacy.data.len(),
+            };
+            if write_catalog(&legacy.data, meta).await.is_ok() {
+                log!(format!(
+                    "[Cache] ✓ Migrated {} models from localStorage",
+                    legacy.data.len()
+                ));
+            }
+        }
+        Err(StorageError::KeyNotFound(_)) => return,
+        Err(err) => log!(format!(
+            "[Cache] ✗ Discarding unreadable localStorage cache: {}",
+            err
+        )),
+    }
+    // Dropped even if the copy failed: the next fetch saves a fresh catalog
+    LocalStorage::delete(LEGACY_CACHE_KEY);
 }
 
-pub fn clear_cache() {
-    LocalStorage::delete(CACHE_KEY);
-    log!("[Cache] 🗑️  Cache cleared - next fetch will reload from API");
+/// A model as an IndexedDB record, with the `provider` field the provider index is built on
+fn model_record(model: &Model) -> Result<JsValue, JsValue> {
+    let record = to_js(model)?;
+    Reflect::set(
+        &record,
+        &"provider".into(),
+        &model.provider().unwrap_or_default().into(),
+    )?;
+    Ok(record)
 }
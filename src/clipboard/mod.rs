@@ -0,0 +1,31 @@
+use std::fmt;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(target_arch = "wasm32")]
+pub use wasm::write_text;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::write_text;
+
+/// Why text could not be put on the clipboard
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipboardError {
+    /// The platform offers no clipboard access
+    Unsupported,
+    /// The clipboard refused the write (permission denied, no display, ...)
+    Failed(String),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClipboardError::Unsupported => write!(f, "clipboard is not available"),
+            ClipboardError::Failed(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
@@ -0,0 +1,30 @@
+use super::ClipboardError;
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+thread_local! {
+    /// Kept alive for the whole session: on X11 and Wayland the copied text is only
+    /// served while the clipboard handle that set it exists.
+    static CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Write text to the system clipboard
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub async fn write_text(text: String) -> Result<(), ClipboardError> {
+    CLIPBOARD.with_borrow_mut(|clipboard| {
+        let clipboard = match clipboard {
+            Some(clipboard) => clipboard,
+            None => clipboard.insert(
+                arboard::Clipboard::new().map_err(|err| ClipboardError::Failed(err.to_string()))?,
+            ),
+        };
+        clipboard
+            .set_text(text)
+            .map_err(|err| ClipboardError::Failed(err.to_string()))
+    })
+}
+
+/// Mobile targets have no clipboard backend yet
+#[cfg(any(target_os = "android", target_os = "ios"))]
+pub async fn write_text(_text: String) -> Result<(), ClipboardError> {
+    Err(ClipboardError::Unsupported)
+}
@@ -0,0 +1,23 @@
+use gloo_console::log;
+use wasm_bindgen_futures::JsFuture;
+
+use super::ClipboardError;
+
+/// Write text through the asynchronous Clipboard API, waiting for the browser to accept it
+pub async fn write_text(text: String) -> Result<(), ClipboardError> {
+    let window = web_sys::window().ok_or(ClipboardError::Unsupported)?;
+    let promise = window.navigator().clipboard().write_text(&text);
+
+    JsFuture::from(promise).await.map(|_| ()).map_err(|err| {
+        let reason = err
+            .as_string()
+            .or_else(|| {
+                js_sys::Reflect::get(&err, &"message".into())
+                    .ok()?
+                    .as_string()
+            })
+            .unwrap_or_else(|| format!("{err:?}"));
+        log!(format!("[Clipboard] ✗ Copy failed: {}", reason));
+        ClipboardError::Failed(reason)
+    })
+}
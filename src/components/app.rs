@@ -7,290 +7,835 @@ use std::collections::{BTreeSet, HashSet};
 use gloo_console::log;
 
 use crate::api::fetch_models;
-use crate::cache::clear_cache;
-use crate::i18n::init_i18n;
-use crate::models::{Modality, Model, SortDirection, SortField};
-use crate::utils::{has_all_modalities, matches_any_token_sequence, tokenize};
-
-use super::filters::FilterControls;
+use crate::cache::{expire_cache, CacheError};
+use crate::history::latest_changes;
+use crate::i18n::{detect_language, i18n_config, init_i18n, DEFAULT_LANGUAGE};
+use crate::models::{
+    ArenaSelection, ChartAxis, CurrencySettings, Favorites, FilterPreset, Modality, Model, Page,
+    PriceUnit, RecencyWindow, SortDirection, SortField, SortKey, TableColumn, Theme, ViewMode,
+};
+use crate::prerender::{is_hydrating, linked_model_id, use_prerendered_page};
+use crate::storage::{self, use_persistent};
+use crate::utils::{
+    has_all_modalities, matches_any_token_sequence, sort_models, step_selection, tokenize,
+    Shortcut, SnippetFormat, TokenCounts,
+};
+
+use super::arena::Arena;
+use super::connection::{use_online_status, use_service_worker, CacheWarning, OfflineIndicator};
+use super::copy_menu::{copy_to_clipboard, CopyFeedback};
+use super::currency::{CurrencySwitcher, PriceUnitSelect};
+use super::favorites::FavoritesPanel;
+use super::filters::{FilterControls, FILTER_INPUT_ID};
+use super::language_switcher::LanguageSwitcher;
 use super::modal::ModelModal;
-use super::model_list::ModelList;
+use super::model_list::{ModelList, ResultsSummary};
+use super::model_table::ModelTable;
+use super::playground::use_session_api_settings;
+use super::presets::PresetChips;
+use super::providers::ProviderOverview;
+use super::scatter_chart::ScatterChart;
+use super::shortcuts_help::ShortcutsHelp;
 use super::sort_controls::SortControls;
 use super::styles::GlobalStyles;
+use super::theme_switcher::ThemeSwitcher;
+use super::token_counter::TokenCounter;
+use super::view_controls::ViewControls;
+use super::whats_new::WhatsNew;
+
+/// Storage key for persisting the selected view mode
+const VIEW_MODE_STORAGE_KEY: &str = "polza-models-view-mode";
+
+/// Storage key for persisting the visible table columns
+const TABLE_COLUMNS_STORAGE_KEY: &str = "polza-models-table-columns";
+
+/// Storage keys for persisting the scatter chart axes
+const CHART_X_AXIS_STORAGE_KEY: &str = "polza-models-chart-x-axis";
+const CHART_Y_AXIS_STORAGE_KEY: &str = "polza-models-chart-y-axis";
+
+/// Storage key for persisting the color theme
+const THEME_STORAGE_KEY: &str = "polza-models-theme";
+
+/// Storage key for persisting the display currency and exchange rates
+const CURRENCY_STORAGE_KEY: &str = "polza-models-currency";
+
+/// Storage key for persisting the price unit (per token, 1K, 1M or 1B tokens)
+const PRICE_UNIT_STORAGE_KEY: &str = "polza-models-price-unit";
+
+/// Storage key for persisting favorite model slugs
+const FAVORITES_STORAGE_KEY: &str = "polza-models-favorites";
+
+/// Storage key for persisting saved filter presets
+const PRESETS_STORAGE_KEY: &str = "polza-models-filter-presets";
+
+/// Storage key for persisting the "NEW" badge window
+const NEW_BADGE_WINDOW_STORAGE_KEY: &str = "polza-models-new-badge-window";
+
+/// Storage key for the time of the previous visit (unix timestamp)
+const LAST_VISIT_STORAGE_KEY: &str = "polza-models-last-visit";
+
+/// Document-level listener forwarding shortcut keys to the app.
+///
+/// Keys typed into form fields or pressed with Ctrl/Alt/Meta are left alone, as is Enter on
+/// buttons and links so they keep their native behaviour.
+const SHORTCUT_LISTENER_JS: &str = r#"
+document.addEventListener('keydown', (event) => {
+    if (event.ctrlKey || event.metaKey || event.altKey) return;
+    const target = event.target;
+    const tag = target && target.tagName;
+    if (tag === 'INPUT' || tag === 'TEXTAREA' || tag === 'SELECT' || (target && target.isContentEditable)) return;
+    if (event.key === 'Enter' && (tag === 'BUTTON' || tag === 'A' || tag === 'SUMMARY')) return;
+    if (event.key === 'Enter' && target.dataset && target.dataset.openOnEnter) {
+        event.preventDefault();
+        target.click();
+        return;
+    }
+    const keys = ['/', 'ArrowDown', 'ArrowUp', 'j', 'k', 'Enter', 'c', 'f', '[', ']', '?', 'Escape'];
+    if (!keys.includes(event.key)) return;
+    event.preventDefault();
+    dioxus.send(event.key);
+});
+"#;
 
 #[component]
 pub fn App() -> Element {
-    // Initialize i18n
-    let i18n = use_init_i18n(init_i18n);
+    // The page pre-rendered at build time, while the app hydrates it
+    let prerendered = use_prerendered_page();
+    let hydrating = use_hook(is_hydrating);
+
+    // Initialize i18n; a pre-rendered page stays in the default language until it is live
+    let mut i18n = use_init_i18n(move || {
+        if hydrating {
+            i18n_config(DEFAULT_LANGUAGE)
+        } else {
+            init_i18n()
+        }
+    });
 
     // State for the filter input
     let filter_text = use_signal(String::new);
 
+    // State for the color theme (persisted)
+    let theme = use_persistent(THEME_STORAGE_KEY, Theme::default);
+
+    // State for the display currency (persisted), read by every price through `use_money`
+    let currency = use_persistent(CURRENCY_STORAGE_KEY, CurrencySettings::default);
+    use_context_provider(|| currency);
+
+    // State for the price unit (persisted), also read through `use_money`
+    let price_unit = use_persistent(PRICE_UNIT_STORAGE_KEY, PriceUnit::default);
+    use_context_provider(|| price_unit);
+
+    // State for the current page (model list or providers overview)
+    let mut page = use_signal(Page::default);
+
+    // State for the provider filter (applied from the providers overview)
+    let mut selected_provider = use_signal(|| None::<String>);
+
     // State for modality filters
     let selected_input_modalities = use_signal(HashSet::<Modality>::new);
     let selected_output_modalities = use_signal(HashSet::<Modality>::new);
 
-    // State for sorting
-    let sort_field = use_signal(|| SortField::PromptPrice);
-    let sort_direction = use_signal(|| SortDirection::Descending);
-
-    // State for the selected model (for modal display)
-    let mut selected_model = use_signal(|| None::<Model>);
-
-    // State for copy button feedback (main list) - tracks which slug was copied
-    let copied_slug = use_signal(|| None::<String>);
+    // State for favorites (persisted) and the "favorites only" filter
+    let mut favorites = use_persistent(FAVORITES_STORAGE_KEY, Favorites::default);
+    let favorites_only = use_signal(|| false);
+
+    // Time of this visit, provided for relative dates; a pre-rendered page keeps the time of the
+    // build until it is live, so it hydrates with the dates it was rendered with
+    let mut now = use_signal(|| {
+        prerendered
+            .as_ref()
+            .map_or_else(time::OffsetDateTime::now_utc, |page| page.rendered_at)
+    });
+    use_context_provider(|| now);
+
+    // Once a pre-rendered page is live, switch it to the visitor's language and clock
+    use_effect(move || {
+        if hydrating {
+            now.set(time::OffsetDateTime::now_utc());
+            i18n.set_language(detect_language());
+        }
+    });
+
+    // Time of the previous visit, loaded when the current one is recorded (once the page is live)
+    let mut last_visit = use_signal(|| None::<time::OffsetDateTime>);
+    use_effect(move || {
+        let previous = storage::load::<i64>(LAST_VISIT_STORAGE_KEY)
+            .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok());
+        let visit = time::OffsetDateTime::now_utc();
+        storage::save(LAST_VISIT_STORAGE_KEY, &visit.unix_timestamp());
+        last_visit.set(previous);
+    });
+
+    // State for the created-date filter and the "NEW" badge window (persisted)
+    let date_range = use_signal(|| None::<RecencyWindow>);
+    let new_badge_window = use_persistent(NEW_BADGE_WINDOW_STORAGE_KEY, RecencyWindow::default);
+
+    // State for sorting (primary key first)
+    let sort_keys = use_signal(|| {
+        vec![SortKey::new(
+            SortField::PromptPrice,
+            SortDirection::Descending,
+        )]
+    });
+
+    // State for saved filter presets
+    let presets = use_persistent(PRESETS_STORAGE_KEY, Vec::<FilterPreset>::new);
+
+    // State for the presentation (cards or table) and the visible table columns
+    let view_mode = use_persistent(VIEW_MODE_STORAGE_KEY, ViewMode::default);
+    let visible_columns = use_persistent(TABLE_COLUMNS_STORAGE_KEY, TableColumn::defaults);
+
+    // State for the scatter chart axes
+    let chart_x_axis = use_persistent(CHART_X_AXIS_STORAGE_KEY, || ChartAxis::ContextLength);
+    let chart_y_axis = use_persistent(CHART_Y_AXIS_STORAGE_KEY, || ChartAxis::PromptPrice);
+
+    // State for the models picked for the side-by-side arena (model ids)
+    let arena_selection = use_signal(ArenaSelection::default);
+
+    // API key and base URL shared by the playground and the arena, kept for the session only
+    // unless the user asks to remember the key
+    let api_settings = use_session_api_settings();
+    use_context_provider(|| api_settings);
+
+    // State for the selected model (for modal display), open on the page of a model
+    let mut selected_model =
+        use_signal(|| prerendered.as_ref().and_then(|page| page.model.clone()));
+
+    // State for copy feedback - tracks the model and format of the last copy
+    let copy_feedback = use_signal(|| None::<CopyFeedback>);
+
+    // State for the keyboard selection in the model list (model id) and the shortcut cheat sheet
+    let mut keyboard_selection = use_signal(|| None::<String>);
+    let mut show_shortcuts = use_signal(|| false);
 
     // State for refresh button loading indicator
     let mut is_refreshing = use_signal(|| false);
 
-    // Fetch models from the API (or load from cache)
-    let mut models_resource = use_resource(|| async move { fetch_models().await });
-
-    rsx! {
-        GlobalStyles {}
+    // Offline support: the service worker caches the app, the header shows when the network is gone
+    use_service_worker();
+    let online = use_online_status();
 
-        div {
-            class: "container",
-            style: "max-width: 800px; margin: 0 auto; padding: 20px; font-family: system-ui, -apple-system, sans-serif;",
+    // Why the last fetched catalog could not be saved for offline use, until dismissed
+    let mut cache_error = use_signal(|| None::<CacheError>);
 
-            // Header
-            div {
-                style: "display: flex; align-items: center; justify-content: space-between; margin-bottom: 10px;",
-                h1 {
-                    style: "color: #2c3e50; margin: 0;",
-                    { t!("app-title") }
+    // Fetch models from the API (or load from cache)
+    let mut models_resource = use_resource(move || async move {
+        fetch_models(move |error| cache_error.set(Some(error))).await
+    });
+
+    // Retry a failed load as soon as the connection comes back
+    use_effect(move || {
+        if *online.read() && matches!(*models_resource.peek(), Some(Err(_))) {
+            models_resource.restart();
+        }
+    });
+
+    // Opened on the page of a model the build did not render (`models/<id>/`, served by
+    // `404.html`), open that model once the catalog has loaded
+    let mut linked_model =
+        use_signal(|| linked_model_id().filter(|_| selected_model.peek().is_none()));
+    use_effect(move || {
+        let Some(Ok(response)) = &*models_resource.read() else {
+            return;
+        };
+        if let Some(id) = linked_model.take() {
+            if let Some(model) = response.data.iter().find(|model| model.id == id) {
+                selected_model.set(Some(model.clone()));
+            }
+        }
+    });
+
+    // Changes between the two latest catalog snapshots (recomputed after every fetch)
+    let catalog_changes = use_resource(move || async move {
+        let _ = models_resource.read();
+        latest_changes().await
+    });
+
+    // Models passing all filters, in display order (shared by the views and the keyboard shortcuts)
+    let prerendered_catalog = prerendered.clone();
+    let visible_models = use_memo(move || {
+        let resource = models_resource.read();
+        let catalog = match &*resource {
+            Some(result) => result.as_ref().ok(),
+            None => prerendered_catalog
+                .as_ref()
+                .and_then(|page| page.catalog.as_ref()),
+        };
+        let Some(response) = catalog else {
+            return Vec::new();
+        };
+
+        let filter_tokens = tokenize(&filter_text.read());
+        let input_modalities = selected_input_modalities.read();
+        let output_modalities = selected_output_modalities.read();
+        let current_sort_keys = sort_keys.read();
+        let current_favorites = favorites.read();
+        let only_favorites = *favorites_only.read();
+        let created_range = *date_range.read();
+        let provider_filter = selected_provider.read();
+        let now = *now.read();
+        let last_visit = *last_visit.read();
+
+        let mut filtered_models: Vec<&Model> = response
+            .data
+            .iter()
+            .filter(|model| {
+                // Text filter: All filter tokens must match (AND logic)
+                let text_matches = filter_tokens.iter().all(|filter_token| {
+                    matches_any_token_sequence(filter_token, &model.name_tokens)
+                });
+
+                // Input modality filter: Model must have all selected input modalities
+                let input_matches =
+                    has_all_modalities(&model.architecture.input_modalities, &input_modalities);
+
+                // Output modality filter: Model must have all selected output modalities
+                let output_matches =
+                    has_all_modalities(&model.architecture.output_modalities, &output_modalities);
+
+                // Favorites filter: Model must be a favorite when enabled
+                let favorite_matches =
+                    !only_favorites || current_favorites.contains(&model.canonical_slug);
+
+                // Created-date filter: Model must fall inside the selected window
+                let date_matches = created_range
+                    .is_none_or(|window| window.contains(model.created, now, last_visit));
+
+                // Provider filter: Model must come from the selected provider
+                let provider_matches = provider_filter
+                    .as_deref()
+                    .is_none_or(|provider| model.provider() == Some(provider));
+
+                // All filters must pass (AND logic)
+                text_matches
+                    && input_matches
+                    && output_matches
+                    && favorite_matches
+                    && date_matches
+                    && provider_matches
+            })
+            .collect();
+
+        // Sort filtered results
+        sort_models(&mut filtered_models, &current_sort_keys);
+
+        // Pin favorites to the top (stable, so the chosen order is kept within each group)
+        filtered_models.sort_by_key(|model| !current_favorites.contains(&model.canonical_slug));
+
+        filtered_models.into_iter().cloned().collect::<Vec<Model>>()
+    });
+
+    // Text pasted into the token counter and its token count per tokenizer family of the visible models
+    let prompt_sample = use_signal(String::new);
+    let token_counts = use_memo(move || {
+        let text = prompt_sample.read();
+        if text.trim().is_empty() {
+            return None;
+        }
+        let models = visible_models.read();
+        Some(TokenCounts::for_families(
+            &text,
+            models
+                .iter()
+                .map(|model| model.architecture.tokenizer_family()),
+        ))
+    });
+
+    // Keyboard shortcuts
+    use_future(move || async move {
+        let mut listener = document::eval(SHORTCUT_LISTENER_JS);
+        while let Ok(key) = listener.recv::<String>().await {
+            let Some(shortcut) = Shortcut::from_key(&key) else {
+                continue;
+            };
+
+            let models = visible_models.peek().clone();
+            let position_of = |id: Option<String>| {
+                id.and_then(|id| models.iter().position(|model| model.id == id))
+            };
+            let open_model = selected_model.peek().clone();
+            // The keyboard selection is only shown in the card list and the table, so it is
+            // neither moved nor acted on in the chart or on the other pages
+            let selection_shown =
+                *page.peek() == Page::Models && *view_mode.peek() != ViewMode::Chart;
+            let selected_index =
+                position_of(keyboard_selection.peek().clone()).filter(|_| selection_shown);
+
+            // Copy and favorite act on the open model, or else on the keyboard selection
+            let target = open_model
+                .clone()
+                .or_else(|| selected_index.map(|index| models[index].clone()));
+
+            match shortcut {
+                Shortcut::FocusFilter => {
+                    document::eval(&format!(
+                        "document.getElementById('{FILTER_INPUT_ID}')?.focus();"
+                    ));
                 }
+                Shortcut::SelectNext | Shortcut::SelectPrevious
+                    if open_model.is_none() && selection_shown =>
                 {
-                    // Auto-clear is_refreshing when data loads
-                    use_effect(move || {
-                        // Use .value() to create a reactive subscription
-                        let resource_state = models_resource.value();
-                        if resource_state.read().is_some() && *is_refreshing.peek() {
-                            #[cfg(target_arch = "wasm32")]
-                            log!("[UI] ✓ Data loaded - clearing refresh state");
-                            is_refreshing.set(false);
-                        }
-                    });
-
-                    let is_loading = *is_refreshing.read();
-                    let button_style = if is_loading {
-                        "padding: 8px 16px; font-size: 13px; cursor: not-allowed; opacity: 0.6;"
+                    let delta = if shortcut == Shortcut::SelectNext {
+                        1
                     } else {
-                        "padding: 8px 16px; font-size: 13px;"
+                        -1
                     };
-
-                    rsx! {
-                        button {
-                            class: "retry-button",
-                            style: "{button_style}",
-                            disabled: is_loading,
-                            onclick: move |_| {
-                                #[cfg(target_arch = "wasm32")]
-                                log!("[UI] 🔄 Refresh button clicked!");
-                                is_refreshing.set(true);
-                                #[cfg(target_arch = "wasm32")]
-                                log!("[UI] ⏳ Loading state: LOADING");
-                                clear_cache();
-                                models_resource.restart();
-                            },
-                            if is_loading {
-                                { t!("button-refreshing") }
-                            } else {
-                                { t!("button-refresh") }
-                            }
+                    if let Some(index) = step_selection(selected_index, models.len(), delta) {
+                        keyboard_selection.set(Some(models[index].id.clone()));
+                        document::eval(
+                            "requestAnimationFrame(() => document.querySelector('.model-item.selected, .model-table tr.selected')?.scrollIntoView({ block: 'nearest' }));",
+                        );
+                    }
+                }
+                Shortcut::OpenSelected if open_model.is_none() => {
+                    if let Some(index) = selected_index {
+                        selected_model.set(Some(models[index].clone()));
+                    }
+                }
+                Shortcut::CopySlug => {
+                    if let Some(model) = target {
+                        copy_to_clipboard(
+                            &model,
+                            SnippetFormat::Slug,
+                            *price_unit.read(),
+                            copy_feedback,
+                        );
+                    }
+                }
+                Shortcut::ToggleFavorite => {
+                    if let Some(model) = target {
+                        favorites.write().toggle(&model.canonical_slug);
+                    }
+                }
+                Shortcut::PreviousModel | Shortcut::NextModel => {
+                    if let Some(open) = open_model {
+                        let delta = if shortcut == Shortcut::NextModel {
+                            1
+                        } else {
+                            -1
+                        };
+                        let current = position_of(Some(open.id));
+                        if let Some(index) = step_selection(current, models.len(), delta) {
+                            keyboard_selection.set(Some(models[index].id.clone()));
+                            selected_model.set(Some(models[index].clone()));
                         }
                     }
                 }
+                Shortcut::ShowHelp => show_shortcuts.toggle(),
+                Shortcut::Close => {
+                    if *show_shortcuts.peek() {
+                        show_shortcuts.set(false);
+                    } else {
+                        selected_model.set(None);
+                    }
+                }
+                _ => {}
             }
+        }
+    });
 
-            p {
-                style: "color: #7f8c8d; margin-bottom: 30px;",
-                { t!("app-subtitle") }
-            }
+    // The live catalog, or the pre-rendered page's until it has loaded
+    let models_state = models_resource.read_unchecked();
+    let catalog = models_state
+        .as_ref()
+        .map(Result::as_ref)
+        .or_else(|| prerendered.as_ref()?.catalog.as_ref().map(Ok));
+
+    rsx! {
+        // Installable web app: manifest and service worker are served from public/
+        document::Link { rel: "manifest", href: "manifest.webmanifest" }
+        document::Meta { name: "theme-color", content: "#3498db" }
+        GlobalStyles {}
+
+        div {
+            class: "theme-root",
+            "data-theme": theme.read().attribute(),
 
-            // Content area - shows loading, error, or results
             div {
-                style: "background: #f8f9fa; border-radius: 8px; padding: 20px; min-height: 200px;",
-
-                match &*models_resource.read_unchecked() {
-                    Some(Ok(response)) => {
-                        // Compute available input and output modalities from the dataset
-                        let all_input_modalities: Vec<Modality> = response.data.iter()
-                            .flat_map(|m| m.architecture.input_modalities.iter())
-                            .copied()
-                            .collect::<BTreeSet<_>>()
-                            .into_iter()
-                            .collect();
-
-                        let all_output_modalities: Vec<Modality> = response.data.iter()
-                            .flat_map(|m| m.architecture.output_modalities.iter())
-                            .copied()
-                            .collect::<BTreeSet<_>>()
-                            .into_iter()
-                            .collect();
-
-                        let filter = filter_text.read();
-                        let filter_tokens = tokenize(&filter);
-                        let input_modalities = selected_input_modalities.read();
-                        let output_modalities = selected_output_modalities.read();
-                        let current_sort_field = sort_field.read();
-                        let current_sort_direction = sort_direction.read();
-
-                        let mut filtered_models: Vec<_> = response.data.iter()
-                            .filter(|model| {
-                                // Text filter: All filter tokens must match (AND logic)
-                                let text_matches = filter_tokens.iter().all(|filter_token| {
-                                    matches_any_token_sequence(filter_token, &model.name_tokens)
-                                });
-
-                                // Input modality filter: Model must have all selected input modalities
-                                let input_matches = has_all_modalities(
-                                    &model.architecture.input_modalities,
-                                    &input_modalities
-                                );
-
-                                // Output modality filter: Model must have all selected output modalities
-                                let output_matches = has_all_modalities(
-                                    &model.architecture.output_modalities,
-                                    &output_modalities
-                                );
-
-                                // All filters must pass (AND logic)
-                                text_matches && input_matches && output_matches
-                            })
-                            .collect();
-
-                        // Sort filtered results
-                        filtered_models.sort_by(|a, b| {
-                            let comparison = match *current_sort_field {
-                                SortField::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                                SortField::Created => a.created.cmp(&b.created),
-                                SortField::PromptPrice => a.pricing.prompt.cmp(&b.pricing.prompt),
-                                SortField::CompletionPrice => a.pricing.completion.cmp(&b.pricing.completion),
-                            };
-
-                            match *current_sort_direction {
-                                SortDirection::Ascending => comparison,
-                                SortDirection::Descending => comparison.reverse(),
+                class: "container",
+                style: if *view_mode.read() != ViewMode::Cards || *page.read() != Page::Models {
+                    "max-width: 1400px; margin: 0 auto; padding: 20px; font-family: system-ui, -apple-system, sans-serif;"
+                } else {
+                    "max-width: 800px; margin: 0 auto; padding: 20px; font-family: system-ui, -apple-system, sans-serif;"
+                },
+
+                // Header
+                div {
+                    style: "display: flex; align-items: center; justify-content: space-between; margin-bottom: 10px;",
+                    div {
+                        style: "display: flex; align-items: center; gap: 12px;",
+                        h1 {
+                            style: "color: var(--color-text); margin: 0;",
+                            { t!("app-title") }
+                        }
+                        OfflineIndicator { online: online }
+                    }
+                    {
+                        // Auto-clear is_refreshing when data loads
+                        use_effect(move || {
+                            // Use .value() to create a reactive subscription
+                            let resource_state = models_resource.value();
+                            if resource_state.read().is_some() && *is_refreshing.peek() {
+                                #[cfg(target_arch = "wasm32")]
+                                log!("[UI] ✓ Data loaded - clearing refresh state");
+                                is_refreshing.set(false);
                             }
                         });
 
+                        let is_loading = *is_refreshing.read();
+                        let button_style = if is_loading {
+                            "padding: 8px 16px; font-size: 13px; cursor: not-allowed; opacity: 0.6;"
+                        } else {
+                            "padding: 8px 16px; font-size: 13px;"
+                        };
+
                         rsx! {
-                            div {
-                                // Filter controls
-                                FilterControls {
-                                    filter_text: filter_text,
-                                    selected_input_modalities: selected_input_modalities,
-                                    selected_output_modalities: selected_output_modalities,
-                                    all_input_modalities: all_input_modalities,
-                                    all_output_modalities: all_output_modalities
+                            button {
+                                class: "retry-button",
+                                style: "{button_style}",
+                                disabled: is_loading,
+                                onclick: move |_| {
+                                    #[cfg(target_arch = "wasm32")]
+                                    log!("[UI] 🔄 Refresh button clicked!");
+                                    is_refreshing.set(true);
+                                    #[cfg(target_arch = "wasm32")]
+                                    log!("[UI] ⏳ Loading state: LOADING");
+                                    // The expired data stays as the offline copy if the fetch fails
+                                    spawn(async move {
+                                        expire_cache().await;
+                                        models_resource.restart();
+                                    });
+                                },
+                                if is_loading {
+                                    { t!("button-refreshing") }
+                                } else {
+                                    { t!("button-refresh") }
                                 }
+                            }
+                        }
+                    }
+                }
 
-                                // Sort controls
-                                SortControls {
-                                    sort_field: sort_field,
-                                    sort_direction: sort_direction
-                                }
+                p {
+                    style: "color: var(--color-text-muted); margin-bottom: 30px;",
+                    { t!("app-subtitle") }
+                }
+
+                CacheWarning { error: cache_error }
 
-                                // Model list
-                                ModelList {
-                                    models: filtered_models.into_iter().cloned().collect(),
-                                    filter: filter.clone(),
-                                    on_select: move |model: Model| {
-                                        selected_model.set(Some(model));
-                                    },
-                                    copied_slug: copied_slug
+                // Page navigation
+                div {
+                    class: "sort-field-group page-tabs",
+                    role: "group",
+                    aria_label: t!("page-navigation-label"),
+                    button {
+                        class: if *page.read() == Page::Models { "sort-field-button active" } else { "sort-field-button" },
+                        aria_pressed: "{*page.read() == Page::Models}",
+                        onclick: move |_| page.set(Page::Models),
+                        { t!("page-models") }
+                    }
+                    button {
+                        class: if *page.read() == Page::Providers { "sort-field-button active" } else { "sort-field-button" },
+                        aria_pressed: "{*page.read() == Page::Providers}",
+                        onclick: move |_| page.set(Page::Providers),
+                        { t!("page-providers") }
+                    }
+                    button {
+                        class: if *page.read() == Page::Arena { "sort-field-button active" } else { "sort-field-button" },
+                        aria_pressed: "{*page.read() == Page::Arena}",
+                        onclick: move |_| page.set(Page::Arena),
+                        { t!("page-arena", count: arena_selection.read().len()) }
+                    }
+                }
+
+                // Content area - shows loading, error, or results
+                div {
+                    style: "background: var(--color-surface-alt); border-radius: 8px; padding: 20px; min-height: 200px;",
+
+                    match catalog {
+                        Some(Ok(response)) if *page.read() == Page::Providers => rsx! {
+                            ProviderOverview {
+                                models: response.data.clone(),
+                                on_select_provider: move |provider: String| {
+                                    selected_provider.set(Some(provider));
+                                    page.set(Page::Models);
+                                },
+                                on_select_model: move |model: Model| {
+                                    selected_model.set(Some(model));
                                 }
                             }
-                        }
-                    },
-                    Some(Err(err)) => rsx! {
-                        div {
-                            style: "text-align: center; padding: 40px;",
-                            div {
-                                style: "font-size: 48px; margin-bottom: 16px;",
-                                "⚠️"
-                            }
-                            div {
-                                style: "color: #e74c3c; font-weight: 600; margin-bottom: 8px;",
-                                { t!("error-failed-load") }
+                        },
+                        Some(Ok(response)) if *page.read() == Page::Arena => {
+                            // Selected models in the order they were picked
+                            let models: Vec<Model> = arena_selection
+                                .read()
+                                .ids()
+                                .iter()
+                                .filter_map(|id| response.data.iter().find(|model| &model.id == id))
+                                .cloned()
+                                .collect();
+                            rsx! {
+                                Arena { models: models, selection: arena_selection }
                             }
-                            div {
-                                style: "color: #7f8c8d; font-size: 14px; margin-bottom: 20px;",
-                                "{err}"
-                            }
-                            button {
-                                class: "retry-button",
-                                onclick: move |_| models_resource.restart(),
-                                { t!("button-retry") }
+                        },
+                        Some(Ok(response)) => {
+                            // Compute available input and output modalities from the dataset
+                            let all_input_modalities: Vec<Modality> = response.data.iter()
+                                .flat_map(|m| m.architecture.input_modalities.iter())
+                                .copied()
+                                .collect::<BTreeSet<_>>()
+                                .into_iter()
+                                .collect();
+
+                            let all_output_modalities: Vec<Modality> = response.data.iter()
+                                .flat_map(|m| m.architecture.output_modalities.iter())
+                                .copied()
+                                .collect::<BTreeSet<_>>()
+                                .into_iter()
+                                .collect();
+
+                            let filter = filter_text.read();
+                            let current_favorites = favorites.read();
+                            let filtered_models = visible_models.read();
+
+                            rsx! {
+                                div {
+                                    // Change log since the previous catalog snapshot
+                                    if let Some(changes) = catalog_changes.read().clone().flatten() {
+                                        WhatsNew {
+                                            changes: changes,
+                                            on_select: move |model: Model| {
+                                                selected_model.set(Some(model));
+                                            }
+                                        }
+                                    }
+
+                                    // Saved filter presets
+                                    PresetChips {
+                                        presets: presets,
+                                        filter_text: filter_text,
+                                        selected_input_modalities: selected_input_modalities,
+                                        selected_output_modalities: selected_output_modalities,
+                                        sort_keys: sort_keys
+                                    }
+
+                                    // Filter controls
+                                    FilterControls {
+                                        filter_text: filter_text,
+                                        selected_input_modalities: selected_input_modalities,
+                                        selected_output_modalities: selected_output_modalities,
+                                        all_input_modalities: all_input_modalities,
+                                        all_output_modalities: all_output_modalities,
+                                        favorites_only: favorites_only,
+                                        favorites_count: current_favorites.len(),
+                                        date_range: date_range,
+                                        new_badge_window: new_badge_window,
+                                        has_last_visit: last_visit.read().is_some(),
+                                        selected_provider: selected_provider
+                                    }
+
+                                    // Favorites import/export
+                                    FavoritesPanel { favorites: favorites }
+
+                                    // Prompt token counts and costs
+                                    TokenCounter { text: prompt_sample, counts: token_counts() }
+
+                                    // View mode and column picker
+                                    ViewControls {
+                                        view_mode: view_mode,
+                                        visible_columns: visible_columns
+                                    }
+
+                                    if *view_mode.read() == ViewMode::Chart {
+                                        // Scatter plot (click a point for details)
+                                        ResultsSummary { count: filtered_models.len(), filter: filter.clone() }
+                                        ScatterChart {
+                                            models: filtered_models.clone(),
+                                            x_axis: chart_x_axis,
+                                            y_axis: chart_y_axis,
+                                            on_select: move |model: Model| {
+                                                selected_model.set(Some(model));
+                                            }
+                                        }
+                                    } else if *view_mode.read() == ViewMode::Table {
+                                        // Compact table (sorted via its column headers)
+                                        ModelTable {
+                                            models: filtered_models.clone(),
+                                            filter: filter.clone(),
+                                            columns: visible_columns.read().clone(),
+                                            sort_keys: sort_keys,
+                                            favorites: favorites,
+                                            arena_selection: arena_selection,
+                                            token_counts: token_counts(),
+                                            keyboard_selection: keyboard_selection.read().clone(),
+                                            on_select: move |model: Model| {
+                                                selected_model.set(Some(model));
+                                            }
+                                        }
+                                    } else {
+                                        // Sort controls
+                                        SortControls {
+                                            sort_keys: sort_keys
+                                        }
+
+                                        // Model list
+                                        ModelList {
+                                            models: filtered_models.clone(),
+                                            filter: filter.clone(),
+                                            on_select: move |model: Model| {
+                                                selected_model.set(Some(model));
+                                            },
+                                            copy_feedback: copy_feedback,
+                                            favorites: favorites,
+                                            arena_selection: arena_selection,
+                                            new_since: new_badge_window.read().cutoff(*now.read(), *last_visit.read()),
+                                            keyboard_selection: keyboard_selection.read().clone(),
+                                            token_counts: token_counts()
+                                        }
+                                    }
+                                }
                             }
-                        }
-                    },
-                    None => rsx! {
-                        div {
-                            style: "text-align: center; padding: 40px;",
+                        },
+                        Some(Err(err)) => rsx! {
                             div {
-                                style: "font-size: 48px; margin-bottom: 16px; animation: spin 1s linear infinite;",
-                                "⏳"
+                                style: "text-align: center; padding: 40px;",
+                                div {
+                                    style: "font-size: 48px; margin-bottom: 16px;",
+                                    "⚠️"
+                                }
+                                div {
+                                    style: "color: var(--color-danger); font-weight: 600; margin-bottom: 8px;",
+                                    { t!("error-failed-load") }
+                                }
+                                div {
+                                    style: "color: var(--color-text-muted); font-size: 14px; margin-bottom: 20px;",
+                                    "{err}"
+                                }
+                                button {
+                                    class: "retry-button",
+                                    onclick: move |_| models_resource.restart(),
+                                    { t!("button-retry") }
+                                }
                             }
+                        },
+                        None => rsx! {
                             div {
-                                style: "color: #7f8c8d;",
-                                { t!("loading-models") }
+                                style: "text-align: center; padding: 40px;",
+                                div {
+                                    style: "font-size: 48px; margin-bottom: 16px; animation: spin 1s linear infinite;",
+                                    "⏳"
+                                }
+                                div {
+                                    style: "color: var(--color-text-muted);",
+                                    { t!("loading-models") }
+                                }
                             }
                         }
                     }
                 }
-            }
 
-            // Modal
-            if let Some(model) = selected_model.read().as_ref() {
-                ModelModal {
-                    model: model.clone(),
-                    on_close: move |_| selected_model.set(None)
+                // Keyboard shortcut cheat sheet
+                if *show_shortcuts.read() {
+                    ShortcutsHelp { on_close: move |_| show_shortcuts.set(false) }
                 }
-            }
 
-            // Footer
-            div {
-                style: "margin-top: 30px; text-align: center; color: #95a5a6; font-size: 13px;",
+                // Modal
+                if let Some(model) = selected_model.read().as_ref() {
+                    ModelModal {
+                        model: model.clone(),
+                        favorites: favorites,
+                        copy_feedback: copy_feedback,
+                        on_close: move |_| selected_model.set(None)
+                    }
+                }
+
+                // Copy feedback for screen readers
                 div {
-                    style: "margin-bottom: 10px;",
-                    { t!("footer-text") }
+                    class: "visually-hidden",
+                    role: "status",
+                    aria_live: "polite",
+                    match copy_feedback.read().as_ref().map(|feedback| (feedback.format, &feedback.result)) {
+                        Some((format, Ok(()))) => rsx! {
+                            { t!("copy-announcement", format: t!(format.label_key())) }
+                        },
+                        Some((_, Err(err))) => rsx! {
+                            { t!("copy-failed", error: err.to_string()) }
+                        },
+                        None => rsx! {},
+                    }
                 }
-                // Language switcher
-                {
-                    let mut i18n_copy = i18n.to_owned();
-                    rsx! {
-                        button {
-                            class: "language-switcher",
-                            style: "padding: 6px 12px; font-size: 12px; background: #3498db; color: white; border: none; border-radius: 4px; cursor: pointer; transition: background 0.2s;",
-                            onclick: move |_| {
-                                use unic_langid::langid;
-                                use crate::i18n::save_language_preference;
-
-                                let current_lang = i18n_copy.language().clone();
-                                let new_lang = if current_lang == langid!("en-US") {
-                                    langid!("ru-RU")
-                                } else {
-                                    langid!("en-US")
-                                };
 
-                                #[cfg(target_arch = "wasm32")]
-                                gloo_console::log!(
-                                    "[i18n] Switching language from",
-                                    current_lang.to_string(),
-                                    "to",
-                                    new_lang.to_string()
-                                );
-
-                                save_language_preference(&new_lang);
-                                i18n_copy.set_language(new_lang);
-                            },
-                            { t!("language-code") }
-                        }
+                // Footer
+                div {
+                    style: "margin-top: 30px; text-align: center; color: var(--color-text-faint); font-size: 13px;",
+                    div {
+                        style: "margin-bottom: 10px;",
+                        { t!("footer-text") }
                     }
+                    div {
+                        style: "margin-bottom: 10px;",
+                        { t!("shortcuts-hint") }
+                    }
+                    // Language switcher
+                    LanguageSwitcher {}
+
+                    // Theme switcher
+                    ThemeSwitcher { theme: theme }
+
+                    // Display currency
+                    CurrencySwitcher { settings: currency }
+
+                    // Price unit
+                    PriceUnitSelect { unit: price_unit }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+    use crate::prerender::PrerenderedPage;
+
+    fn snapshot() -> ApiResponse {
+        serde_json::from_str(include_str!("../models.json")).unwrap()
+    }
+
+    #[test]
+    fn test_prerendered_catalog_links_every_model() {
+        let html = render_to_html(|| {
+            use_context_provider(|| PrerenderedPage {
+                catalog: Some(snapshot()),
+                model: None,
+                rendered_at: time::OffsetDateTime::now_utc(),
+            });
+            rsx! { App {} }
+        });
+        for model in &snapshot().data {
+            let link = format!(r#"href="models/{}/""#, model.id);
+            assert!(html.contains(&link), "missing {link}");
+        }
+        assert!(!html.contains(r#"role="dialog""#), "{html}");
+    }
+
+    #[test]
+    fn test_prerendered_model_page_opens_the_model() {
+        let html = render_to_html(|| {
+            use_context_provider(|| PrerenderedPage {
+                catalog: None,
+                model: snapshot().data.into_iter().next(),
+                rendered_at: time::OffsetDateTime::now_utc(),
+            });
+            rsx! { App {} }
+        });
+        let model = snapshot().data.remove(0);
+        assert!(html.contains(r#"role="dialog""#), "{html}");
+        assert!(html.contains(&model.canonical_slug), "{html}");
+        // The catalog behind the dialog is left to the app to load
+        assert!(html.contains("Loading models..."), "{html}");
+    }
+}
@@ -0,0 +1,390 @@
+use dioxus::core::Task;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use rust_decimal::Decimal;
+use serde_json::Map;
+use time::OffsetDateTime;
+use web_time::Instant;
+
+use crate::api::{stream_chat_completion, ChatRequest};
+use crate::clipboard;
+use crate::models::{ArenaResponse, ArenaSelection, ArenaSession, ArenaStatus, Model};
+
+use super::currency::use_money;
+use super::playground::{use_api_settings, ApiSettingsFields};
+
+/// Send one prompt to the selected models at once and show the answers side by side
+#[component]
+pub fn Arena(models: Vec<Model>, selection: Signal<ArenaSelection>) -> Element {
+    let settings = use_api_settings();
+    let mut prompt = use_signal(String::new);
+    let mut session = use_signal(|| None::<ArenaSession>);
+    let mut tasks = use_signal(Vec::<Task>::new);
+    let mut export_json = use_signal(|| None::<String>);
+    let mut export_status = use_signal(|| None::<Result<String, String>>);
+
+    let is_running = session
+        .read()
+        .as_ref()
+        .is_some_and(|session| session.responses.iter().any(|r| r.status.is_pending()));
+    let missing_key = settings.read().api_key.trim().is_empty();
+    let can_run = !is_running
+        && models.len() >= ArenaSelection::MIN_MODELS
+        && !prompt.read().trim().is_empty()
+        && !missing_key;
+
+    let run = {
+        let models = models.clone();
+        move |_| {
+            let settings = settings.read().clone();
+            let prompt_text = prompt.read().clone();
+            export_json.set(None);
+            export_status.set(None);
+            session.set(Some(ArenaSession {
+                prompt: prompt_text.clone(),
+                started_at: OffsetDateTime::now_utc(),
+                responses: models
+                    .iter()
+                    .map(|model| ArenaResponse::waiting(model.id.clone(), model.name.clone()))
+                    .collect(),
+            }));
+
+            // One task per model so the requests run concurrently and can be cancelled together
+            let started = Instant::now();
+            let running = models
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, model)| {
+                    let settings = settings.clone();
+                    let request =
+                        ChatRequest::new(model.id.clone(), prompt_text.clone(), Map::new());
+                    spawn(async move {
+                        let result = stream_chat_completion(&settings, &request, |delta| {
+                            update_response(session, index, |response| {
+                                if response.first_token_ms.is_none() {
+                                    response.first_token_ms = Some(elapsed_ms(started));
+                                    response.status = ArenaStatus::Streaming;
+                                }
+                                response.text.push_str(delta);
+                            });
+                        })
+                        .await;
+                        update_response(session, index, |response| {
+                            response.latency_ms = Some(elapsed_ms(started));
+                            match result {
+                                Ok(outcome) => {
+                                    response.status = ArenaStatus::Finished;
+                                    if let Some(usage) = outcome.usage {
+                                        response.prompt_tokens = Some(usage.prompt_tokens);
+                                        response.completion_tokens = Some(usage.completion_tokens);
+                                        response.cost =
+                                            Some(model.pricing.usage_cost(usage.billed()));
+                                    }
+                                }
+                                Err(err) => {
+                                    response.status = ArenaStatus::Failed;
+                                    response.error = Some(err.to_string());
+                                }
+                            }
+                        });
+                    })
+                })
+                .collect();
+            tasks.set(running);
+        }
+    };
+
+    let stop = move |_| {
+        for task in tasks.write().drain(..) {
+            task.cancel();
+        }
+        if let Some(session) = session.write().as_mut() {
+            for response in &mut session.responses {
+                if response.status.is_pending() {
+                    response.status = ArenaStatus::Cancelled;
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "arena",
+
+            // Selected models
+            div {
+                class: "arena-selection",
+                span { class: "arena-selection-label", { t!("arena-selected", count: models.len(), max: ArenaSelection::MAX_MODELS) } }
+                for model in models.iter().cloned() {
+                    div {
+                        key: "{model.id}",
+                        class: "arena-chip",
+                        span { class: "arena-chip-name", "{model.name}" }
+                        button {
+                            class: "arena-chip-remove",
+                            title: t!("arena-remove", name: model.name.clone()),
+                            aria_label: t!("arena-remove", name: model.name.clone()),
+                            onclick: move |_| {
+                                selection.write().toggle(&model.id);
+                            },
+                            "×"
+                        }
+                    }
+                }
+                if !models.is_empty() {
+                    button {
+                        class: "arena-clear-button",
+                        onclick: move |_| selection.write().clear(),
+                        { t!("arena-clear") }
+                    }
+                }
+            }
+            if models.len() < ArenaSelection::MIN_MODELS {
+                div { class: "playground-hint", { t!("arena-select-hint", min: ArenaSelection::MIN_MODELS, max: ArenaSelection::MAX_MODELS) } }
+            }
+
+            // Connection and prompt
+            ApiSettingsFields { settings: settings }
+            div {
+                class: "playground-row",
+                label { r#for: "arena-prompt", class: "modal-label", { t!("playground-prompt") } }
+                textarea {
+                    id: "arena-prompt",
+                    class: "arena-prompt",
+                    rows: 4,
+                    value: "{prompt}",
+                    placeholder: "{t!(\"playground-prompt-placeholder\")}",
+                    oninput: move |evt| prompt.set(evt.value()),
+                }
+            }
+
+            // Actions
+            div {
+                class: "arena-actions",
+                button {
+                    class: "retry-button",
+                    disabled: !can_run,
+                    onclick: run,
+                    { t!("arena-run") }
+                }
+                if is_running {
+                    button {
+                        class: "retry-button",
+                        onclick: stop,
+                        { t!("playground-stop") }
+                    }
+                }
+                if missing_key {
+                    span { class: "playground-hint", { t!("playground-error-no-key") } }
+                }
+            }
+
+            // Answers side by side
+            if let Some(current) = session.read().as_ref() {
+                div {
+                    class: "arena-grid",
+                    for response in current.responses.iter() {
+                        ArenaColumn { key: "{response.model_id}", response: response.clone() }
+                    }
+                }
+
+                // Export
+                if !is_running {
+                    div {
+                        class: "arena-actions",
+                        button {
+                            class: "retry-button",
+                            onclick: move |_| {
+                                export_json.set(session.read().as_ref().map(ArenaSession::to_json));
+                                export_status.set(None);
+                            },
+                            { t!("arena-export") }
+                        }
+                        if let Some(json) = export_json.read().clone() {
+                            button {
+                                class: "retry-button",
+                                onclick: move |_| {
+                                    let json = json.clone();
+                                    spawn(async move {
+                                        export_status.set(Some(match clipboard::write_text(json).await {
+                                            Ok(()) => Ok(t!("arena-export-copied")),
+                                            Err(err) => Err(t!("copy-failed", error: err.to_string())),
+                                        }));
+                                    });
+                                },
+                                { t!("arena-export-copy") }
+                            }
+                        }
+                    }
+                    if let Some(json) = export_json.read().as_ref() {
+                        textarea {
+                            class: "arena-export-json",
+                            rows: 8,
+                            readonly: true,
+                            aria_label: t!("arena-export"),
+                            value: "{json}",
+                        }
+                    }
+                    match &*export_status.read() {
+                        Some(Ok(message)) => rsx! {
+                            div { class: "arena-status", role: "status", "{message}" }
+                        },
+                        Some(Err(message)) => rsx! {
+                            div { class: "arena-status error", role: "alert", "{message}" }
+                        },
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Adds the model to (or removes it from) the arena comparison
+#[component]
+pub fn ArenaToggle(model_id: String, selection: Signal<ArenaSelection>) -> Element {
+    let is_selected = selection.read().contains(&model_id);
+    let is_full = selection.read().is_full();
+    let title = match (is_selected, is_full) {
+        (true, _) => t!("arena-toggle-remove"),
+        (false, false) => t!("arena-toggle-add"),
+        (false, true) => t!("arena-toggle-full", max: ArenaSelection::MAX_MODELS),
+    };
+
+    rsx! {
+        button {
+            class: if is_selected { "arena-toggle active" } else { "arena-toggle" },
+            title: "{title}",
+            aria_label: "{title}",
+            aria_pressed: "{is_selected}",
+            disabled: is_full && !is_selected,
+            onclick: move |evt: Event<MouseData>| {
+                // Stop propagation to prevent the card or row click from opening the modal
+                evt.stop_propagation();
+                selection.write().toggle(&model_id);
+            },
+            "⚖"
+        }
+    }
+}
+
+/// One model's answer with its latency, token usage and cost
+#[component]
+fn ArenaColumn(response: ArenaResponse) -> Element {
+    let money = use_money();
+    let locale = money.locale();
+    let seconds = |ms: u64| locale.decimal(Decimal::new(ms as i64, 3).round_dp(2));
+
+    rsx! {
+        section {
+            class: "arena-column",
+            aria_busy: "{response.status.is_pending()}",
+            h3 { class: "arena-column-title", "{response.model_name}" }
+            div { class: "arena-column-id", "{response.model_id}" }
+
+            pre { class: "playground-response", "{response.text}" }
+
+            div {
+                class: "arena-metrics",
+                match response.status {
+                    ArenaStatus::Waiting => rsx! { { t!("playground-running") } },
+                    ArenaStatus::Streaming => rsx! { { t!("arena-streaming") } },
+                    ArenaStatus::Cancelled => rsx! { { t!("playground-cancelled") } },
+                    ArenaStatus::Failed => rsx! {
+                        span { class: "playground-error", { t!("playground-error", error: response.error.clone().unwrap_or_default()) } }
+                    },
+                    ArenaStatus::Finished => rsx! {
+                        if let Some(ms) = response.latency_ms {
+                            div { { t!("arena-latency", seconds: seconds(ms)) } }
+                        }
+                        if let Some(ms) = response.first_token_ms {
+                            div { { t!("arena-first-token", seconds: seconds(ms)) } }
+                        }
+                        if let (Some(prompt), Some(completion)) = (response.prompt_tokens, response.completion_tokens) {
+                            div { { t!("arena-tokens", prompt: locale.integer(prompt), completion: locale.integer(completion)) } }
+                        }
+                        match response.cost {
+                            Some(cost) => rsx! {
+                                div { class: "price-value", { t!("arena-cost", cost: money.format(cost.round_dp(6))) } }
+                            },
+                            None => rsx! {
+                                div { { t!("arena-no-usage") } }
+                            },
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Apply `update` to the answer at `index` of the current session
+fn update_response(
+    mut session: Signal<Option<ArenaSession>>,
+    index: usize,
+    update: impl FnOnce(&mut ArenaResponse),
+) {
+    if let Some(response) = session
+        .write()
+        .as_mut()
+        .and_then(|session| session.responses.get_mut(index))
+    {
+        update(response);
+    }
+}
+
+fn elapsed_ms(started: Instant) -> u64 {
+    started.elapsed().as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    fn arena_with(count: usize) -> Element {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        let models: Vec<Model> = response.data.into_iter().take(count).collect();
+        let selection = use_signal(|| {
+            let mut selection = ArenaSelection::default();
+            for model in &models {
+                selection.toggle(&model.id);
+            }
+            selection
+        });
+        rsx! { Arena { models: models, selection: selection } }
+    }
+
+    #[test]
+    fn test_needs_two_models() {
+        let html = render_to_html(|| arena_with(1));
+        assert!(html.contains("Pick"), "{html}");
+    }
+
+    #[test]
+    fn test_selected_models_are_listed() {
+        let html = render_to_html(|| arena_with(3));
+        assert_eq!(html.matches(r#"class="arena-chip""#).count(), 3, "{html}");
+        assert!(!html.contains("Pick"), "{html}");
+    }
+
+    #[test]
+    fn test_finished_column_shows_metrics() {
+        let html = render_to_html(|| {
+            let mut response = ArenaResponse::waiting("a/model".into(), "Model A".into());
+            response.status = ArenaStatus::Finished;
+            response.text = "Hello there".into();
+            response.latency_ms = Some(1500);
+            response.prompt_tokens = Some(10);
+            response.completion_tokens = Some(20);
+            response.cost = Some(Decimal::new(15, 4));
+            rsx! { ArenaColumn { response: response } }
+        });
+        assert!(html.contains("Hello there"), "{html}");
+        assert!(html.contains("1.50"), "{html}");
+        assert!(html.contains("₽0.0015"), "{html}");
+        assert!(html.contains(r#"aria-busy="false""#), "{html}");
+    }
+}
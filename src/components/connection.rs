@@ -0,0 +1,132 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::cache::CacheError;
+
+/// Page-level listener reporting `navigator.onLine` now and on every change
+const CONNECTION_LISTENER_JS: &str = r#"
+const report = () => dioxus.send(navigator.onLine);
+window.addEventListener('online', report);
+window.addEventListener('offline', report);
+report();
+"#;
+
+/// Registers the service worker that keeps the app usable offline.
+///
+/// Only over HTTPS, so the dev server (and the desktop webview) never serve a stale cached build.
+const SERVICE_WORKER_JS: &str = r#"
+if ('serviceWorker' in navigator && location.protocol === 'https:') {
+    navigator.serviceWorker.register('sw.js')
+        .catch((error) => console.warn('[PWA] Service worker registration failed:', error));
+}
+"#;
+
+/// Whether the browser is online, updated as the connection comes and goes
+pub fn use_online_status() -> Signal<bool> {
+    let mut online = use_signal(|| true);
+
+    use_future(move || async move {
+        let mut listener = document::eval(CONNECTION_LISTENER_JS);
+        while let Ok(status) = listener.recv::<bool>().await {
+            online.set(status);
+        }
+    });
+
+    online
+}
+
+/// Register the service worker once
+pub fn use_service_worker() {
+    use_hook(|| {
+        document::eval(SERVICE_WORKER_JS);
+    });
+}
+
+/// Header badge shown while offline, when the catalog comes from the saved copy
+#[component]
+pub fn OfflineIndicator(online: Signal<bool>) -> Element {
+    if *online.read() {
+        return rsx! {};
+    }
+
+    rsx! {
+        span {
+            class: "offline-indicator",
+            role: "status",
+            title: t!("offline-indicator-hint"),
+            { t!("offline-indicator") }
+        }
+    }
+}
+
+/// Notice that the catalog could not be saved for offline use, with a button to dismiss it
+#[component]
+pub fn CacheWarning(error: Signal<Option<CacheError>>) -> Element {
+    let Some(current) = error.read().clone() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "cache-warning",
+            role: "alert",
+            span {
+                match current {
+                    CacheError::QuotaExceeded => t!("cache-error-quota"),
+                    CacheError::Failed(reason) => t!("cache-error-failed", reason: reason),
+                }
+            }
+            button {
+                class: "cache-warning-dismiss",
+                aria_label: t!("cache-warning-dismiss"),
+                onclick: move |_| error.set(None),
+                "✕"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    #[test]
+    fn test_offline_indicator() {
+        let html = render_to_html(|| {
+            let online = use_signal(|| false);
+            rsx! { OfflineIndicator { online: online } }
+        });
+        assert!(html.contains(r#"class="offline-indicator""#), "{html}");
+        assert!(html.contains(r#"role="status""#), "{html}");
+        assert!(html.contains("Offline"), "{html}");
+
+        let html = render_to_html(|| {
+            let online = use_signal(|| true);
+            rsx! { OfflineIndicator { online: online } }
+        });
+        assert!(!html.contains("offline-indicator"), "{html}");
+    }
+
+    #[test]
+    fn test_cache_warning() {
+        let html = render_to_html(|| {
+            let error = use_signal(|| Some(CacheError::QuotaExceeded));
+            rsx! { CacheWarning { error: error } }
+        });
+        assert!(html.contains(r#"role="alert""#), "{html}");
+        assert!(html.contains("storage for this site is full"), "{html}");
+
+        let html = render_to_html(|| {
+            let error = use_signal(|| Some(CacheError::Failed("UnknownError".to_string())));
+            rsx! { CacheWarning { error: error } }
+        });
+        assert!(html.contains("UnknownError"), "{html}");
+
+        let html = render_to_html(|| {
+            let error = use_signal(|| None::<CacheError>);
+            rsx! { CacheWarning { error: error } }
+        });
+        assert!(!html.contains("cache-warning"), "{html}");
+    }
+}
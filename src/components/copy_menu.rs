@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::clipboard::{self, ClipboardError};
+use crate::models::{Model, PriceUnit};
+use crate::utils::SnippetFormat;
+
+use super::currency::use_money;
+
+/// How long the "Copied" / "Copy failed" feedback stays visible
+const FEEDBACK_DURATION: Duration = Duration::from_secs(2);
+
+/// Outcome of the last copy, shown on the model it was copied from
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyFeedback {
+    /// `canonical_slug` of the model the text was copied from
+    pub slug: String,
+    pub format: SnippetFormat,
+    pub result: Result<(), ClipboardError>,
+}
+
+/// Copy a snippet of `model`, prices per `unit`, to the clipboard and report the outcome
+/// through `feedback`
+pub fn copy_to_clipboard(
+    model: &Model,
+    format: SnippetFormat,
+    unit: PriceUnit,
+    mut feedback: Signal<Option<CopyFeedback>>,
+) {
+    let text = format.render(model, unit);
+    let slug = model.canonical_slug.clone();
+
+    spawn(async move {
+        let outcome = CopyFeedback {
+            slug,
+            format,
+            result: clipboard::write_text(text).await,
+        };
+        feedback.set(Some(outcome.clone()));
+
+        sleep(FEEDBACK_DURATION).await;
+        // Leave the feedback of a newer copy alone
+        if feedback.peek().as_ref() == Some(&outcome) {
+            feedback.set(None);
+        }
+    });
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+}
+
+/// Copy button for the slug with a drop-down of the other snippet formats
+#[component]
+pub fn CopyMenu(model: Model, feedback: Signal<Option<CopyFeedback>>) -> Element {
+    let mut menu_open = use_signal(|| false);
+    let unit = use_money().unit();
+    let result = feedback
+        .read()
+        .as_ref()
+        .filter(|feedback| feedback.slug == model.canonical_slug)
+        .map(|feedback| feedback.result.clone());
+
+    rsx! {
+        div {
+            class: "copy-menu",
+
+            button {
+                class: match result {
+                    Some(Ok(())) => "copy-button copied",
+                    Some(Err(_)) => "copy-button failed",
+                    None => "copy-button",
+                },
+                title: match &result {
+                    Some(Err(err)) => err.to_string(),
+                    _ => String::new(),
+                },
+                onclick: {
+                    let model = model.clone();
+                    move |evt: Event<MouseData>| {
+                        // Stop propagation to prevent the card click from opening the modal
+                        evt.stop_propagation();
+                        copy_to_clipboard(&model, SnippetFormat::Slug, unit, feedback);
+                    }
+                },
+                {
+                    match result {
+                        Some(Ok(())) => t!("button-copied"),
+                        Some(Err(_)) => t!("button-copy-failed"),
+                        None => t!("button-copy"),
+                    }
+                }
+            }
+
+            button {
+                class: "copy-button copy-menu-toggle",
+                aria_label: t!("copy-menu-label"),
+                title: t!("copy-menu-label"),
+                aria_haspopup: "menu",
+                aria_expanded: "{menu_open}",
+                onclick: move |evt: Event<MouseData>| {
+                    evt.stop_propagation();
+                    menu_open.toggle();
+                },
+                "▾"
+            }
+
+            if *menu_open.read() {
+                div {
+                    class: "copy-menu-list",
+                    role: "menu",
+                    for format in SnippetFormat::ALL {
+                        button {
+                            class: "copy-menu-item",
+                            role: "menuitem",
+                            onclick: {
+                                let model = model.clone();
+                                move |evt: Event<MouseData>| {
+                                    evt.stop_propagation();
+                                    menu_open.set(false);
+                                    copy_to_clipboard(&model, format, unit, feedback);
+                                }
+                            },
+                            { t!(format.label_key()) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    fn menu_with(result: Option<Result<(), ClipboardError>>) -> Element {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        let model = response.data[0].clone();
+        let slug = model.canonical_slug.clone();
+        let feedback = use_signal(|| {
+            result.map(|result| CopyFeedback {
+                slug,
+                format: SnippetFormat::Slug,
+                result,
+            })
+        });
+        rsx! { CopyMenu { model: model, feedback: feedback } }
+    }
+
+    #[test]
+    fn test_copy_menu_idle() {
+        let html = render_to_html(|| menu_with(None));
+        assert!(html.contains(r#"class="copy-button""#), "{html}");
+        assert!(html.contains(r#"aria-haspopup="menu""#), "{html}");
+        assert!(html.contains(r#"aria-expanded="false""#), "{html}");
+    }
+
+    #[test]
+    fn test_copy_failure_is_shown() {
+        let html =
+            render_to_html(|| menu_with(Some(Err(ClipboardError::Failed("denied".to_string())))));
+        assert!(html.contains(r#"class="copy-button failed""#), "{html}");
+        assert!(html.contains(r#"title="denied""#), "{html}");
+        assert!(html.contains("Copy failed"), "{html}");
+    }
+}
@@ -0,0 +1,180 @@
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use rust_decimal::Decimal;
+
+use crate::models::{Currency, CurrencySettings, PriceUnit};
+use crate::utils::Money;
+
+/// Money formatter for the display currency and price unit chosen in the footer and the
+/// active language.
+///
+/// The currency settings and the price unit are provided as context by the app; components
+/// rendered on their own (e.g. in tests) fall back to rubles per 1M tokens.
+pub fn use_money() -> Money {
+    let settings = try_use_context::<Signal<CurrencySettings>>();
+    let unit = try_use_context::<Signal<PriceUnit>>()
+        .map(|unit| *unit.read())
+        .unwrap_or_default();
+    let language = i18n().language();
+    match settings {
+        Some(settings) => Money::new(&settings.read(), unit, &language),
+        None => Money::new(&CurrencySettings::default(), unit, &language),
+    }
+}
+
+/// Currency drop-down and exchange rate editor shown in the footer
+#[component]
+pub fn CurrencySwitcher(settings: Signal<CurrencySettings>) -> Element {
+    let current = settings.read().currency;
+    let rate = settings.read().rate(current);
+    let is_custom = settings.read().has_custom_rate(current);
+
+    rsx! {
+        div {
+            class: "currency-switcher",
+            select {
+                class: "theme-switcher",
+                title: t!("currency-label"),
+                aria_label: t!("currency-label"),
+                onchange: move |evt| {
+                    if let Some(selected) = Currency::ALL.into_iter().find(|c| c.code() == evt.value()) {
+                        settings.write().currency = selected;
+                    }
+                },
+                for option_currency in Currency::ALL {
+                    option {
+                        value: option_currency.code(),
+                        selected: current == option_currency,
+                        { t!(option_currency.label_key()) }
+                    }
+                }
+            }
+
+            // Conversion rate from rubles, editable
+            if let Some(rate) = rate {
+                label {
+                    class: "currency-rate",
+                    r#for: "currency-rate-input",
+                    "1 {current.code()} = "
+                }
+                input {
+                    id: "currency-rate-input",
+                    class: "currency-rate-input",
+                    r#type: "number",
+                    min: "0",
+                    step: "0.01",
+                    value: "{rate.rub_per_unit}",
+                    aria_label: t!("currency-rate-label", currency: current.code()),
+                    onchange: move |evt| {
+                        if let Ok(rub_per_unit) = evt.value().trim().parse::<Decimal>() {
+                            let today = time::OffsetDateTime::now_utc().date();
+                            settings.write().set_custom_rate(current, rub_per_unit, today);
+                        }
+                    },
+                }
+                span { class: "currency-rate", " ₽ " }
+                span {
+                    class: "currency-rate-as-of",
+                    if is_custom {
+                        { t!("currency-rate-custom", date: rate.as_of.to_string()) }
+                    } else {
+                        { t!("currency-rate-bundled", date: rate.as_of.to_string()) }
+                    }
+                }
+                if is_custom {
+                    button {
+                        class: "sort-direction-button",
+                        onclick: move |_| settings.write().reset_rate(current),
+                        { t!("currency-rate-reset") }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Drop-down choosing the number of tokens per-token prices are shown for
+#[component]
+pub fn PriceUnitSelect(unit: Signal<PriceUnit>) -> Element {
+    let current = *unit.read();
+
+    rsx! {
+        select {
+            class: "theme-switcher",
+            title: t!("price-unit-label"),
+            aria_label: t!("price-unit-label"),
+            onchange: move |evt| {
+                if let Some(selected) = PriceUnit::ALL.into_iter().find(|u| u.fluent_arg() == evt.value()) {
+                    unit.set(selected);
+                }
+            },
+            for option_unit in PriceUnit::ALL {
+                option {
+                    value: option_unit.fluent_arg(),
+                    selected: current == option_unit,
+                    { t!("price-unit-option", unit: option_unit.fluent_arg()) }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    #[test]
+    fn test_rubles_need_no_rate() {
+        let html = render_to_html(|| {
+            let settings = use_signal(CurrencySettings::default);
+            rsx! { CurrencySwitcher { settings: settings } }
+        });
+        assert!(!html.contains("currency-rate-input"), "{html}");
+    }
+
+    #[test]
+    fn test_bundled_rate_is_shown() {
+        let html = render_to_html(|| {
+            let settings = use_signal(|| CurrencySettings {
+                currency: Currency::Usd,
+                ..Default::default()
+            });
+            rsx! { CurrencySwitcher { settings: settings } }
+        });
+        assert!(html.contains("currency-rate-input"), "{html}");
+        assert!(html.contains("2025-10-01"), "{html}");
+    }
+
+    #[test]
+    fn test_price_unit_options_are_labelled_through_fluent() {
+        let html = render_to_html(|| {
+            let unit = use_signal(PriceUnit::default);
+            rsx! { PriceUnitSelect { unit: unit } }
+        });
+        for label in [
+            "per token",
+            "per 1K tokens",
+            "per 1M tokens",
+            "per 1B tokens",
+        ] {
+            assert!(html.contains(label), "{label}: {html}");
+        }
+    }
+
+    #[test]
+    fn test_price_unit_rescales_prices() {
+        #[component]
+        fn PriceLabel() -> Element {
+            let money = use_money();
+            rsx! { span { "{money.per_unit(Decimal::new(12, 8))}" } }
+        }
+
+        let html = render_to_html(|| {
+            use_context_provider(|| Signal::new(PriceUnit::Thousand));
+            rsx! { PriceLabel {} }
+        });
+        assert!(html.contains("₽0.00012"), "{html}");
+    }
+}
@@ -0,0 +1,59 @@
+use dioxus::prelude::*;
+
+/// Installs the focus trap on the dialog with id `__ID__`: remembers the element that
+/// had focus, moves focus into the dialog and keeps Tab / Shift+Tab cycling inside it.
+const TRAP_FOCUS_JS: &str = r#"
+(() => {
+    const dialog = document.getElementById('__ID__');
+    if (!dialog) return;
+    const focusable = () => Array.from(dialog.querySelectorAll(
+        'a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), ' +
+        'textarea:not([disabled]), summary, [tabindex]:not([tabindex="-1"])'
+    )).filter((element) => element.offsetParent !== null);
+    const trap = (event) => {
+        if (event.key !== 'Tab') return;
+        const items = focusable();
+        if (items.length === 0) {
+            event.preventDefault();
+            return;
+        }
+        const first = items[0];
+        const last = items[items.length - 1];
+        if (event.shiftKey && document.activeElement === first) {
+            event.preventDefault();
+            last.focus();
+        } else if (!event.shiftKey && document.activeElement === last) {
+            event.preventDefault();
+            first.focus();
+        }
+    };
+    window.__dialogFocus = window.__dialogFocus || {};
+    window.__dialogFocus['__ID__'] = { previous: document.activeElement, trap };
+    document.addEventListener('keydown', trap, true);
+    const initial = focusable()[0];
+    (initial || dialog).focus();
+})();
+"#;
+
+/// Removes the focus trap of the dialog with id `__ID__` and gives focus back to the
+/// element that had it before the dialog opened.
+const RESTORE_FOCUS_JS: &str = r#"
+(() => {
+    const state = window.__dialogFocus && window.__dialogFocus['__ID__'];
+    if (!state) return;
+    delete window.__dialogFocus['__ID__'];
+    document.removeEventListener('keydown', state.trap, true);
+    if (state.previous && document.contains(state.previous)) state.previous.focus();
+})();
+"#;
+
+/// Keep keyboard focus inside the dialog element `dialog_id` while the calling
+/// component is mounted, and restore it to the opener once the component goes away.
+pub fn use_dialog_focus(dialog_id: &'static str) {
+    use_effect(move || {
+        document::eval(&TRAP_FOCUS_JS.replace("__ID__", dialog_id));
+    });
+    use_drop(move || {
+        document::eval(&RESTORE_FOCUS_JS.replace("__ID__", dialog_id));
+    });
+}
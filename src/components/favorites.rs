@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::Favorites;
+
+/// Star toggle that adds or removes a model from the favorites
+#[component]
+pub fn FavoriteButton(slug: String, favorites: Signal<Favorites>) -> Element {
+    let is_favorite = favorites.read().contains(&slug);
+
+    rsx! {
+        button {
+            class: if is_favorite { "favorite-button active" } else { "favorite-button" },
+            title: if is_favorite { t!("favorite-remove") } else { t!("favorite-add") },
+            aria_label: if is_favorite { t!("favorite-remove") } else { t!("favorite-add") },
+            aria_pressed: "{is_favorite}",
+            onclick: move |evt: Event<MouseData>| {
+                // Stop propagation to prevent the card click from opening the modal
+                evt.stop_propagation();
+                favorites.write().toggle(&slug);
+            },
+            if is_favorite { "★" } else { "☆" }
+        }
+    }
+}
+
+/// Collapsible panel to export favorites as JSON or import them back
+#[component]
+pub fn FavoritesPanel(favorites: Signal<Favorites>) -> Element {
+    let mut json_text = use_signal(String::new);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    rsx! {
+        details {
+            class: "favorites-panel",
+            summary {
+                class: "favorites-panel-summary",
+                { t!("favorites-panel-title", count: favorites.read().len()) }
+            }
+            div {
+                class: "favorites-panel-body",
+                textarea {
+                    class: "favorites-json",
+                    rows: 5,
+                    value: "{json_text}",
+                    placeholder: "{t!(\"favorites-json-placeholder\")}",
+                    aria_label: t!("favorites-json-placeholder"),
+                    oninput: move |evt| json_text.set(evt.value()),
+                }
+                div {
+                    class: "favorites-panel-actions",
+                    button {
+                        class: "retry-button",
+                        onclick: move |_| {
+                            json_text.set(favorites.read().to_json());
+                            status.set(None);
+                        },
+                        { t!("favorites-export") }
+                    }
+                    button {
+                        class: "retry-button",
+                        onclick: move |_| {
+                            let json = json_text.read().clone();
+                            let result = favorites.write().import_json(&json);
+                            status.set(Some(match result {
+                                Ok(added) => Ok(t!("favorites-imported", count: added)),
+                                Err(err) => Err(t!("favorites-import-failed", error: err.to_string())),
+                            }));
+                        },
+                        { t!("favorites-import") }
+                    }
+                }
+                match &*status.read() {
+                    Some(Ok(message)) => rsx! {
+                        div { class: "favorites-status", role: "status", "{message}" }
+                    },
+                    Some(Err(message)) => rsx! {
+                        div { class: "favorites-status error", role: "alert", "{message}" }
+                    },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,10 @@ use dioxus::prelude::*;
 use dioxus_i18n::t;
 use std::collections::HashSet;
 
-use crate::models::Modality;
+use crate::models::{Modality, RecencyWindow};
+
+/// Id of the model search input, focused by the `/` shortcut
+pub const FILTER_INPUT_ID: &str = "model-filter";
 
 #[component]
 pub fn FilterControls(
@@ -11,18 +14,26 @@ pub fn FilterControls(
     selected_output_modalities: Signal<HashSet<Modality>>,
     all_input_modalities: Vec<Modality>,
     all_output_modalities: Vec<Modality>,
+    favorites_only: Signal<bool>,
+    favorites_count: usize,
+    date_range: Signal<Option<RecencyWindow>>,
+    new_badge_window: Signal<RecencyWindow>,
+    has_last_visit: bool,
+    selected_provider: Signal<Option<String>>,
 ) -> Element {
     rsx! {
         // Text filter input
         div {
             style: "margin-bottom: 20px;",
             label {
-                style: "display: block; margin-bottom: 8px; font-weight: 600; color: #34495e;",
+                r#for: FILTER_INPUT_ID,
+                style: "display: block; margin-bottom: 8px; font-weight: 600; color: var(--color-text-secondary);",
                 { t!("filter-label") }
             }
             div {
                 class: "filter-input-container",
                 input {
+                    id: FILTER_INPUT_ID,
                     class: "filter-input",
                     r#type: "text",
                     value: "{filter_text}",
@@ -33,6 +44,7 @@ pub fn FilterControls(
                     button {
                         class: "filter-clear-button",
                         r#type: "button",
+                        aria_label: t!("filter-clear"),
                         onclick: move |_| filter_text.set(String::new()),
                         "×"
                     }
@@ -48,11 +60,14 @@ pub fn FilterControls(
             div {
                 class: "modality-filter-group",
                 label {
+                    id: "input-modalities-label",
                     class: "modality-filter-label",
                     { t!("input-modalities-label") }
                 }
                 div {
                     class: "modality-toggles",
+                    role: "group",
+                    aria_labelledby: "input-modalities-label",
                     for modality in all_input_modalities.iter() {
                         {
                             let modality_value = *modality;
@@ -65,6 +80,7 @@ pub fn FilterControls(
                                     } else {
                                         "modality-toggle-button"
                                     },
+                                    aria_pressed: "{is_selected}",
                                     onclick: move |_| {
                                         let mut modalities = selected_input_modalities.write();
                                         if modalities.contains(&modality_value) {
@@ -73,7 +89,7 @@ pub fn FilterControls(
                                             modalities.insert(modality_value);
                                         }
                                     },
-                                    "{modality_value:?}"
+                                    { t!(modality_value.label_key()) }
                                 }
                             }
                         }
@@ -85,11 +101,14 @@ pub fn FilterControls(
             div {
                 class: "modality-filter-group",
                 label {
+                    id: "output-modalities-label",
                     class: "modality-filter-label",
                     { t!("output-modalities-label") }
                 }
                 div {
                     class: "modality-toggles",
+                    role: "group",
+                    aria_labelledby: "output-modalities-label",
                     for modality in all_output_modalities.iter() {
                         {
                             let modality_value = *modality;
@@ -102,6 +121,7 @@ pub fn FilterControls(
                                     } else {
                                         "modality-toggle-button"
                                     },
+                                    aria_pressed: "{is_selected}",
                                     onclick: move |_| {
                                         let mut modalities = selected_output_modalities.write();
                                         if modalities.contains(&modality_value) {
@@ -110,13 +130,177 @@ pub fn FilterControls(
                                             modalities.insert(modality_value);
                                         }
                                     },
-                                    "{modality_value:?}"
+                                    { t!(modality_value.label_key()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Provider (set from the providers overview)
+            if let Some(provider) = selected_provider.read().clone() {
+                div {
+                    class: "modality-filter-group",
+                    label {
+                        id: "provider-filter-label",
+                        class: "modality-filter-label",
+                        { t!("provider-filter-label") }
+                    }
+                    div {
+                        class: "modality-toggles",
+                        role: "group",
+                        aria_labelledby: "provider-filter-label",
+                        button {
+                            class: "modality-toggle-button active provider",
+                            title: t!("provider-filter-clear"),
+                            onclick: move |_| selected_provider.set(None),
+                            "{provider} ×"
+                        }
+                    }
+                }
+            }
+
+            // Created date
+            div {
+                class: "modality-filter-group",
+                label {
+                    id: "created-filter-label",
+                    class: "modality-filter-label",
+                    { t!("created-filter-label") }
+                }
+                div {
+                    class: "modality-toggles",
+                    role: "group",
+                    aria_labelledby: "created-filter-label",
+                    for window in RecencyWindow::FILTER_PRESETS {
+                        if has_last_visit || window != RecencyWindow::SinceLastVisit {
+                            button {
+                                class: if *date_range.read() == Some(window) {
+                                    "modality-toggle-button active recent"
+                                } else {
+                                    "modality-toggle-button"
+                                },
+                                aria_pressed: "{*date_range.read() == Some(window)}",
+                                onclick: move |_| {
+                                    let selected = *date_range.peek() == Some(window);
+                                    date_range.set(if selected { None } else { Some(window) });
+                                },
+                                { recency_label(window) }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "new-badge-window",
+                    label { r#for: "new-badge-window", { t!("new-badge-window-label") } }
+                    select {
+                        id: "new-badge-window",
+                        class: "sort-field-select",
+                        onchange: move |evt| {
+                            if let Some(window) = evt.value().parse::<usize>().ok()
+                                .and_then(|index| RecencyWindow::BADGE_CHOICES.get(index))
+                            {
+                                new_badge_window.set(*window);
+                            }
+                        },
+                        for (index, window) in RecencyWindow::BADGE_CHOICES.into_iter().enumerate() {
+                            if has_last_visit || window != RecencyWindow::SinceLastVisit {
+                                option {
+                                    value: "{index}",
+                                    selected: *new_badge_window.read() == window,
+                                    { recency_label(window) }
                                 }
                             }
                         }
                     }
                 }
             }
+
+            // Favorites
+            div {
+                class: "modality-filter-group",
+                label {
+                    id: "favorites-filter-label",
+                    class: "modality-filter-label",
+                    { t!("favorites-filter-label") }
+                }
+                div {
+                    class: "modality-toggles",
+                    role: "group",
+                    aria_labelledby: "favorites-filter-label",
+                    button {
+                        class: if *favorites_only.read() {
+                            "modality-toggle-button active favorites"
+                        } else {
+                            "modality-toggle-button"
+                        },
+                        aria_pressed: "{favorites_only}",
+                        onclick: move |_| favorites_only.toggle(),
+                        { t!("favorites-only", count: favorites_count) }
+                    }
+                }
+            }
         }
     }
 }
+
+/// Localized name of a recency window
+fn recency_label(window: RecencyWindow) -> String {
+    match window {
+        RecencyWindow::Days(days) => t!("recency-last-days", days: days),
+        RecencyWindow::SinceLastVisit => t!("recency-since-last-visit"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    fn filters() -> Element {
+        rsx! {
+            FilterControls {
+                filter_text: use_signal(String::new),
+                selected_input_modalities: use_signal(|| HashSet::from([Modality::Text])),
+                selected_output_modalities: use_signal(HashSet::new),
+                all_input_modalities: vec![Modality::Text, Modality::Image],
+                all_output_modalities: vec![Modality::Text],
+                favorites_only: use_signal(|| false),
+                favorites_count: 0,
+                date_range: use_signal(|| Some(RecencyWindow::Days(7))),
+                new_badge_window: use_signal(RecencyWindow::default),
+                has_last_visit: false,
+                selected_provider: use_signal(|| None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_input_is_labelled() {
+        let html = render_to_html(filters);
+        assert!(html.contains(r#"for="model-filter""#), "{html}");
+        assert!(html.contains(r#"id="model-filter""#), "{html}");
+        assert!(html.contains(r#"for="new-badge-window""#), "{html}");
+        assert!(
+            html.contains(r#"aria-labelledby="input-modalities-label""#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn test_toggles_expose_pressed_state() {
+        let html = render_to_html(filters);
+        assert!(
+            html.contains(r#"aria-pressed="true">Text</button>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"aria-pressed="false">Image</button>"#),
+            "{html}"
+        );
+
+        // The input Text modality and the 7-day date preset, nothing else
+        assert_eq!(html.matches(r#"aria-pressed="true""#).count(), 2, "{html}");
+    }
+}
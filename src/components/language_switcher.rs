@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+
+use crate::i18n::{available_languages, save_language_preference};
+
+/// Language drop-down shown in the footer, listing every bundled locale by its own name
+#[component]
+pub fn LanguageSwitcher() -> Element {
+    let mut i18n = i18n();
+    let current = i18n.language();
+
+    rsx! {
+        select {
+            class: "theme-switcher",
+            title: t!("language-label"),
+            aria_label: t!("language-label"),
+            onchange: move |evt| {
+                if let Some(selected) = available_languages()
+                    .into_iter()
+                    .find(|language| language.id.to_string() == evt.value())
+                {
+                    save_language_preference(&selected.id);
+                    i18n.set_language(selected.id);
+                }
+            },
+            for language in available_languages() {
+                option {
+                    value: "{language.id}",
+                    lang: "{language.id}",
+                    selected: language.id == current,
+                    "{language.name}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    #[test]
+    fn test_lists_every_bundled_language() {
+        let html = render_to_html(|| rsx! { LanguageSwitcher {} });
+        assert!(html.contains("English"), "{html}");
+        assert!(html.contains("Русский"), "{html}");
+        assert_eq!(html.matches("<option").count(), available_languages().len());
+    }
+}
@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use dioxus_i18n::prelude::i18n;
+use dioxus_i18n::t;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::utils::{LocaleFormat, RelativeTime};
+
+/// Number and date conventions of the active language
+pub fn use_locale() -> LocaleFormat {
+    LocaleFormat::for_language(&i18n().language())
+}
+
+/// "3 days ago" for a moment in the past, as of the time provided by the app (which is the time of
+/// the build while it hydrates a pre-rendered page) or else now
+pub fn relative_time_text(at: OffsetDateTime) -> String {
+    let now = try_consume_context::<Signal<OffsetDateTime>>()
+        .map_or_else(OffsetDateTime::now_utc, |now| now());
+    let relative = RelativeTime::between(at, now);
+    t!(relative.label_key(), count: relative.count())
+}
+
+/// How long ago a moment was, with the full date and time as a tooltip
+#[component]
+pub fn RelativeDate(at: OffsetDateTime) -> Element {
+    let locale = use_locale();
+
+    rsx! {
+        time {
+            datetime: at.format(&Rfc3339).unwrap_or_default(),
+            title: locale.timestamp(&at),
+            { relative_time_text(at) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    #[test]
+    fn test_relative_date() {
+        let html = render_to_html(|| {
+            let at = OffsetDateTime::now_utc() - time::Duration::days(3);
+            rsx! { RelativeDate { at: at } }
+        });
+        assert!(html.contains("days ago"), "{html}");
+        assert!(html.contains("datetime="), "{html}");
+    }
+}
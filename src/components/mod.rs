@@ -1,9 +1,47 @@
 mod app;
+mod arena;
+mod connection;
+mod copy_menu;
+mod currency;
+mod dialog;
+mod favorites;
 mod filters;
+mod language_switcher;
+mod locale;
 mod modal;
 mod model_card;
 mod model_list;
+mod model_table;
+mod playground;
+mod presets;
+mod price_chart;
+mod providers;
+mod scatter_chart;
+mod shortcuts_help;
 mod sort_controls;
+mod static_pages;
 mod styles;
+mod theme_switcher;
+mod token_counter;
+mod view_controls;
+mod whats_new;
 
 pub use app::App;
+pub use static_pages::PageHead;
+
+/// Render `content` to static HTML with the English translations loaded
+#[cfg(test)]
+fn render_to_html(content: fn() -> dioxus::prelude::Element) -> String {
+    use dioxus::prelude::*;
+
+    fn root(content: fn() -> Element) -> Element {
+        dioxus_i18n::prelude::use_init_i18n(|| {
+            crate::i18n::i18n_config(unic_langid::langid!("en-US"))
+        });
+        content()
+    }
+
+    let mut dom = VirtualDom::new_with_props(root, content);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
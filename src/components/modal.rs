@@ -1,16 +1,57 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
-use crate::models::{Modality, Model};
-use crate::utils::{
-    format_price_per_invocation, format_price_per_million, format_timestamp, format_with_commas,
-};
+use crate::history::load_price_points;
+use crate::models::{Favorites, Modality, Model};
+
+use super::copy_menu::{CopyFeedback, CopyMenu};
+use super::currency::use_money;
+use super::dialog::use_dialog_focus;
+use super::favorites::FavoriteButton;
+use super::locale::relative_time_text;
+use super::playground::{parameter_description, parameter_name, Playground};
+use super::price_chart::PriceChart;
+
+/// Tabs of the model details dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModalTab {
+    Details,
+    TryIt,
+}
+
+/// Id of the panel showing the content of the selected tab
+const TAB_PANEL_ID: &str = "model-modal-panel";
+
+impl ModalTab {
+    /// Id of the tab's button, which labels the panel while the tab is selected
+    fn button_id(self) -> &'static str {
+        match self {
+            ModalTab::Details => "model-modal-tab-details",
+            ModalTab::TryIt => "model-modal-tab-try-it",
+        }
+    }
+
+    fn label_key(self) -> &'static str {
+        match self {
+            ModalTab::Details => "modal-tab-details",
+            ModalTab::TryIt => "modal-tab-try-it",
+        }
+    }
+}
 
 #[component]
-pub fn ModelModal(model: Model, on_close: EventHandler<()>) -> Element {
-    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_mut))]
-    let mut copied = use_signal(|| false);
-    let provider = model.provider().map(str::to_owned);
+pub fn ModelModal(
+    model: Model,
+    favorites: Signal<Favorites>,
+    copy_feedback: Signal<Option<CopyFeedback>>,
+    on_close: EventHandler<()>,
+) -> Element {
+    use_dialog_focus("model-modal");
+    let mut tab = use_signal(|| ModalTab::Details);
+    let can_try = model
+        .architecture
+        .output_modalities
+        .contains(&Modality::Text);
 
     rsx! {
         div {
@@ -21,187 +62,244 @@ pub fn ModelModal(model: Model, on_close: EventHandler<()>) -> Element {
                     on_close.call(());
                 }
             },
-            tabindex: 0,
-            onmounted: move |evt| async move {
-                let _ = evt.set_focus(true).await;
-            },
 
             div {
+                id: "model-modal",
                 class: "modal-content",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "model-modal-title",
+                tabindex: -1,
                 onclick: move |evt| evt.stop_propagation(),
 
                 // Header
                 div {
                     class: "modal-header",
-                    h2 {
-                        class: "modal-title",
-                        "{model.name}"
+                    div {
+                        class: "modal-title-row",
+                        FavoriteButton { slug: model.canonical_slug.clone(), favorites: favorites }
+                        h2 {
+                            id: "model-modal-title",
+                            class: "modal-title",
+                            "{model.name}"
+                        }
                     }
                     button {
                         class: "modal-close",
+                        aria_label: t!("button-close"),
                         onclick: move |_| on_close.call(()),
                         "×"
                     }
                 }
 
-                // Body
-                div {
-                    class: "modal-body",
-
-                    // Basic Info Section
+                // Tabs (the playground is only offered for models that answer in text). Only the
+                // selected tab is in the tab order; the arrow keys move between tabs.
+                if can_try {
                     div {
-                        class: "modal-section",
-                        div { class: "modal-section-title", { t!("section-basic-info") } }
-                        div {
-                            class: "modal-grid",
-
-                            if let Some(provider) = provider {
-                                span { class: "modal-label", { t!("modal-label-provider") } }
-                                span { class: "modal-value", "{provider}" }
+                        class: "sort-field-group modal-tabs",
+                        role: "tablist",
+                        onkeydown: move |evt| {
+                            let next = match evt.key() {
+                                Key::ArrowLeft | Key::ArrowRight => match *tab.peek() {
+                                    ModalTab::Details => ModalTab::TryIt,
+                                    ModalTab::TryIt => ModalTab::Details,
+                                },
+                                Key::Home => ModalTab::Details,
+                                Key::End => ModalTab::TryIt,
+                                _ => return,
+                            };
+                            evt.prevent_default();
+                            tab.set(next);
+                            document::eval(&format!(
+                                "document.getElementById('{}')?.focus();",
+                                next.button_id()
+                            ));
+                        },
+                        for tab_value in [ModalTab::Details, ModalTab::TryIt] {
+                            button {
+                                id: tab_value.button_id(),
+                                class: if *tab.read() == tab_value { "sort-field-button active" } else { "sort-field-button" },
+                                role: "tab",
+                                aria_selected: "{*tab.read() == tab_value}",
+                                aria_controls: TAB_PANEL_ID,
+                                tabindex: if *tab.read() == tab_value { 0 } else { -1 },
+                                onclick: move |_| tab.set(tab_value),
+                                { t!(tab_value.label_key()) }
                             }
-
-                            span { class: "modal-label", { t!("modal-label-created") } }
-                            span { class: "modal-value", "{format_timestamp(&model.created)}" }
                         }
+                    }
+                }
 
-                        // Canonical Slug with copy button
+                div {
+                    id: TAB_PANEL_ID,
+                    role: can_try.then_some("tabpanel"),
+                    aria_labelledby: can_try.then(|| tab.read().button_id()),
+                    if can_try && *tab.read() == ModalTab::TryIt {
                         div {
-                            style: "margin-top: 12px;",
-                            div {
-                                style: "font-weight: 600; color: #7f8c8d; margin-bottom: 6px; font-size: 14px;",
-                                { t!("modal-label-canonical-slug") }
-                            }
-                            div {
-                                class: "canonical-slug-container",
-                                div {
-                                    class: "canonical-slug",
-                                    style: "flex: 1;",
-                                    "{model.canonical_slug}"
-                                }
-                                button {
-                                    class: if *copied.read() { "copy-button copied" } else { "copy-button" },
-                                    onclick: {
-                                        let _slug = model.canonical_slug.clone();
-                                        move |_| {
-                                            #[cfg(target_arch = "wasm32")]
-                                            {
-                                                use web_sys::window;
-                                                if let Some(window) = window() {
-                                                    let clipboard = window.navigator().clipboard();
-                                                    let _ = clipboard.write_text(&_slug);
-                                                    copied.set(true);
-
-                                                    // Reset after 2 seconds
-                                                    gloo_timers::callback::Timeout::new(2000, move || {
-                                                        copied.set(false);
-                                                    }).forget();
-                                                }
-                                            }
-                                        }
-                                    },
-                                    if *copied.read() {
-                                        { t!("button-copied") }
-                                    } else {
-                                        { t!("button-copy") }
-                                    }
-                                }
-                            }
+                            class: "modal-body",
+                            Playground { key: "{model.id}", model: model.clone() }
                         }
+                    } else {
+                        ModelDetails { model: model.clone(), copy_feedback: copy_feedback }
                     }
+                }
+            }
+        }
+    }
+}
+
+/// Information, prices, price history, architecture and parameters of a model, as shown in the
+/// details tab of the model dialog
+#[component]
+fn ModelDetails(model: Model, copy_feedback: Signal<Option<CopyFeedback>>) -> Element {
+    let money = use_money();
+    let unit = money.unit().fluent_arg();
+    let locale = money.locale();
+    let provider = model.provider().map(str::to_owned);
+    let model_id = model.id.clone();
+    let price_points = use_resource(use_reactive!(|model_id| async move {
+        load_price_points(&model_id).await
+    }));
+
+    rsx! {
+        // Body
+        div {
+            class: "modal-body",
+
+            // Basic Info Section
+            div {
+                class: "modal-section",
+                div { class: "modal-section-title", { t!("section-basic-info") } }
+                div {
+                    class: "modal-grid",
 
-                    // Top Provider Section
+                    if let Some(provider) = provider {
+                        span { class: "modal-label", { t!("modal-label-provider") } }
+                        span { class: "modal-value", "{provider}" }
+                    }
+
+                    span { class: "modal-label", { t!("modal-label-created") } }
+                    span { class: "modal-value", "{locale.timestamp(&model.created)} ({relative_time_text(model.created)})" }
+                }
+
+                // Canonical Slug with copy menu
+                div {
+                    style: "margin-top: 12px;",
+                    div {
+                        style: "font-weight: 600; color: var(--color-text-muted); margin-bottom: 6px; font-size: 14px;",
+                        { t!("modal-label-canonical-slug") }
+                    }
                     div {
-                        class: "modal-section",
-                        div { class: "modal-section-title", { t!("section-provider-config") } }
+                        class: "canonical-slug-container",
                         div {
-                            class: "modal-grid",
-                            span { class: "modal-label", { t!("modal-label-context-length") } }
-                            span { class: "modal-value", "{format_with_commas(model.top_provider.context_length)} {t!(\"unit-tokens\")}" }
-                            span { class: "modal-label", { t!("modal-label-max-completion") } }
-                            span {
-                                class: "modal-value",
-                                if model.top_provider.max_completion_tokens == 0 {
-                                    { t!("value-no-limit") }
-                                } else {
-                                    "{format_with_commas(model.top_provider.max_completion_tokens)} {t!(\"unit-tokens\")}"
-                                }
-                            }
-                            span { class: "modal-label", { t!("modal-label-moderated") } }
-                            span { class: "modal-value", "{model.top_provider.is_moderated}" }
+                            class: "canonical-slug",
+                            style: "flex: 1;",
+                            "{model.canonical_slug}"
                         }
+                        CopyMenu { model: model.clone(), feedback: copy_feedback }
                     }
+                }
+            }
 
-                    // Pricing Section
-                    div {
-                        class: "modal-section",
-                        div { class: "modal-section-title", { t!("section-pricing") } }
-                        div {
-                            class: "modal-grid",
+            // Top Provider Section
+            div {
+                class: "modal-section",
+                div { class: "modal-section-title", { t!("section-provider-config") } }
+                div {
+                    class: "modal-grid",
+                    span { class: "modal-label", { t!("modal-label-context-length") } }
+                    span { class: "modal-value", "{locale.integer(model.top_provider.context_length as u64)} {t!(\"unit-tokens\")}" }
+                    span { class: "modal-label", { t!("modal-label-max-completion") } }
+                    span {
+                        class: "modal-value",
+                        if model.top_provider.max_completion_tokens == 0 {
+                            { t!("value-no-limit") }
+                        } else {
+                            "{locale.integer(model.top_provider.max_completion_tokens as u64)} {t!(\"unit-tokens\")}"
+                        }
+                    }
+                    span { class: "modal-label", { t!("modal-label-moderated") } }
+                    span { class: "modal-value", "{model.top_provider.is_moderated}" }
+                }
+            }
 
-                            // Per million tokens
-                            span { class: "modal-label", { t!("modal-label-prompt-1m") } }
-                            span { class: "modal-price-value", "{format_price_per_million(model.pricing.prompt)}" }
+            // Pricing Section
+            div {
+                class: "modal-section",
+                div { class: "modal-section-title", { t!("section-pricing") } }
+                div {
+                    class: "modal-grid",
 
-                            span { class: "modal-label", { t!("modal-label-completion-1m") } }
-                            span { class: "modal-price-value", "{format_price_per_million(model.pricing.completion)}" }
+                    // Per million tokens
+                    span { class: "modal-label", { t!("modal-label-prompt-price", unit: unit) } }
+                    span { class: "modal-price-value", "{money.per_unit(model.pricing.prompt)}" }
 
-                            span { class: "modal-label", { t!("modal-label-internal-reasoning") } }
-                            span { class: "modal-price-value", "{format_price_per_million(model.pricing.internal_reasoning)}" }
+                    span { class: "modal-label", { t!("modal-label-completion-price", unit: unit) } }
+                    span { class: "modal-price-value", "{money.per_unit(model.pricing.completion)}" }
 
-                            span { class: "modal-label", { t!("modal-label-input-cache-read") } }
-                            span { class: "modal-price-value", "{format_price_per_million(model.pricing.input_cache_read)}" }
+                    span { class: "modal-label", { t!("modal-label-internal-reasoning", unit: unit) } }
+                    span { class: "modal-price-value", "{money.per_unit(model.pricing.internal_reasoning)}" }
 
-                            span { class: "modal-label", { t!("modal-label-input-cache-write") } }
-                            span { class: "modal-price-value", "{format_price_per_million(model.pricing.input_cache_write)}" }
+                    span { class: "modal-label", { t!("modal-label-input-cache-read", unit: unit) } }
+                    span { class: "modal-price-value", "{money.per_unit(model.pricing.input_cache_read)}" }
 
-                            // Per invocation
-                            span { class: "modal-label", { t!("modal-label-image") } }
-                            span { class: "modal-price-value", "{format_price_per_invocation(model.pricing.image)}" }
+                    span { class: "modal-label", { t!("modal-label-input-cache-write", unit: unit) } }
+                    span { class: "modal-price-value", "{money.per_unit(model.pricing.input_cache_write)}" }
 
-                            span { class: "modal-label", { t!("modal-label-request") } }
-                            span { class: "modal-price-value", "{format_price_per_invocation(model.pricing.request)}" }
+                    // Per invocation
+                    span { class: "modal-label", { t!("modal-label-image") } }
+                    span { class: "modal-price-value", "{money.format(model.pricing.image)}" }
 
-                            span { class: "modal-label", { t!("modal-label-web-search") } }
-                            span { class: "modal-price-value", "{format_price_per_invocation(model.pricing.web_search)}" }
-                        }
-                    }
+                    span { class: "modal-label", { t!("modal-label-request") } }
+                    span { class: "modal-price-value", "{money.format(model.pricing.request)}" }
 
-                    // Architecture Section
-                    div {
-                        class: "modal-section",
+                    span { class: "modal-label", { t!("modal-label-web-search") } }
+                    span { class: "modal-price-value", "{money.format(model.pricing.web_search)}" }
+                }
+            }
 
-                        div { class: "modal-section-title", { t!("section-architecture") } }
+            // Price History Section
+            div {
+                class: "modal-section",
+                div { class: "modal-section-title", { t!("section-price-history") } }
+                PriceChart { points: price_points.read().clone().unwrap_or_default() }
+            }
 
-                        ModalitySection {
-                            title: t!("modal-label-input-modalities").to_string(),
-                            modalities: model.architecture.input_modalities.clone()
-                        }
+            // Architecture Section
+            div {
+                class: "modal-section",
 
-                        ModalitySection {
-                            title: t!("modal-label-output-modalities").to_string(),
-                            modalities: model.architecture.output_modalities.clone()
-                        }
-                    }
+                div { class: "modal-section-title", { t!("section-architecture") } }
+
+                ModalitySection {
+                    title: t!("modal-label-input-modalities").to_string(),
+                    modalities: model.architecture.input_modalities.clone()
+                }
+
+                ModalitySection {
+                    title: t!("modal-label-output-modalities").to_string(),
+                    modalities: model.architecture.output_modalities.clone()
+                }
+            }
 
 
-                    // Supported Parameters Section
+            // Supported Parameters Section
+            div {
+                class: "modal-section",
+                div { class: "modal-section-title", { t!("section-parameters") } }
+                if model.supported_parameters.is_empty() {
                     div {
-                        class: "modal-section",
-                        div { class: "modal-section-title", { t!("section-parameters") } }
-                        if model.supported_parameters.is_empty() {
-                            div {
-                                style: "color: #95a5a6; font-style: italic;",
-                                { t!("no-parameters") }
-                            }
-                        } else {
-                            div {
-                                class: "modal-parameters-list",
-                                for param in &model.supported_parameters {
-                                    span {
-                                        class: "modal-parameter-badge",
-                                        "{param}"
-                                    }
-                                }
+                        style: "color: var(--color-text-faint); font-style: italic;",
+                        { t!("no-parameters") }
+                    }
+                } else {
+                    div {
+                        class: "modal-parameters-list",
+                        for param in &model.supported_parameters {
+                            span {
+                                class: "modal-parameter-badge",
+                                title: parameter_description(param),
+                                "{parameter_name(param)}"
                             }
                         }
                     }
@@ -217,7 +315,7 @@ fn ModalitySection(title: String, modalities: Vec<Modality>) -> Element {
         div {
             style: if title == "Input Modalities" { "margin-bottom: 16px;" } else { "" },
             div {
-                style: "font-weight: 600; color: #7f8c8d; margin-bottom: 8px; font-size: 14px;",
+                style: "font-weight: 600; color: var(--color-text-muted); margin-bottom: 8px; font-size: 14px;",
                 "{title}:"
             }
             div {
@@ -228,7 +326,7 @@ fn ModalitySection(title: String, modalities: Vec<Modality>) -> Element {
                         rsx! {
                             span {
                                 class: "modality-badge {modality_lower}",
-                                "{modality:?}"
+                                { t!(modality.label_key()) }
                             }
                         }
                     }
@@ -237,3 +335,100 @@ fn ModalitySection(title: String, modalities: Vec<Modality>) -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    fn modal() -> Element {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        rsx! {
+            ModelModal {
+                model: response.data[0].clone(),
+                favorites: use_signal(Favorites::default),
+                copy_feedback: use_signal(|| None),
+                on_close: |_| {},
+            }
+        }
+    }
+
+    #[test]
+    fn test_modal_is_a_labelled_dialog() {
+        let html = render_to_html(modal);
+        assert!(html.contains(r#"role="dialog""#), "{html}");
+        assert!(html.contains(r#"aria-modal="true""#), "{html}");
+        assert!(
+            html.contains(r#"aria-labelledby="model-modal-title""#),
+            "{html}"
+        );
+        assert!(html.contains(r#"id="model-modal-title""#), "{html}");
+    }
+
+    #[test]
+    fn test_tabs_control_a_labelled_tab_panel() {
+        // The first sample model answers in text, so the dialog offers the Try it tab
+        let html = render_to_html(modal);
+        assert!(html.contains(r#"role="tablist""#), "{html}");
+        assert!(html.contains(r#"id="model-modal-tab-details""#), "{html}");
+        assert!(html.contains(r#"id="model-modal-tab-try-it""#), "{html}");
+        assert_eq!(
+            html.matches(r#"aria-controls="model-modal-panel""#).count(),
+            2,
+            "{html}"
+        );
+        assert!(html.contains(r#"id="model-modal-panel""#), "{html}");
+        assert!(html.contains(r#"role="tabpanel""#), "{html}");
+        assert!(
+            html.contains(r#"aria-labelledby="model-modal-tab-details""#),
+            "{html}"
+        );
+        // Only the selected tab is in the tab order
+        let details = tag_with_id(&html, "model-modal-tab-details");
+        assert!(details.contains(r#"aria-selected="true""#), "{details}");
+        assert!(details.contains(r#"tabindex="0""#), "{details}");
+        let try_it = tag_with_id(&html, "model-modal-tab-try-it");
+        assert!(try_it.contains(r#"aria-selected="false""#), "{try_it}");
+        assert!(try_it.contains(r#"tabindex="-1""#), "{try_it}");
+    }
+
+    /// The opening tag of the element with the given id
+    fn tag_with_id<'a>(html: &'a str, id: &str) -> &'a str {
+        let at = html.find(&format!(r#"id="{id}""#)).unwrap();
+        let start = html[..at].rfind('<').unwrap();
+        let end = at + html[at..].find('>').unwrap();
+        &html[start..=end]
+    }
+
+    #[test]
+    fn test_icon_buttons_have_accessible_names() {
+        let html = render_to_html(modal);
+        assert!(html.contains(r#"aria-label="Close""#), "{html}");
+        assert!(html.contains(r#"aria-label="Add to favorites""#), "{html}");
+        assert!(html.contains(r#"aria-pressed="false""#), "{html}");
+    }
+
+    #[test]
+    fn test_parameters_and_modalities_are_translated() {
+        let html = render_to_html(|| {
+            let response: ApiResponse =
+                serde_json::from_str(include_str!("../models.json")).unwrap();
+            let mut model = response.data[0].clone();
+            model.supported_parameters = vec!["temperature".into(), "x_custom".into()];
+            rsx! {
+                ModelModal {
+                    model: model,
+                    favorites: use_signal(Favorites::default),
+                    copy_feedback: use_signal(|| None),
+                    on_close: |_| {},
+                }
+            }
+        });
+        assert!(html.contains(">Temperature<"), "{html}");
+        assert!(html.contains("Randomness of the output"), "{html}");
+        // Parameters without a translation keep their API identifier
+        assert!(html.contains(">x_custom<"), "{html}");
+        assert!(html.contains(">Text<"), "{html}");
+    }
+}
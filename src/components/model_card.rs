@@ -1,23 +1,56 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use time::OffsetDateTime;
 
-use crate::models::Model;
-use crate::utils::{format_price_per_million, format_timestamp};
+use crate::models::{ArenaSelection, Favorites, Model};
+use crate::utils::TokenCount;
+
+use super::arena::ArenaToggle;
+use super::copy_menu::{CopyFeedback, CopyMenu};
+use super::currency::use_money;
+use super::favorites::FavoriteButton;
+use super::locale::RelativeDate;
+use super::token_counter::PromptCost;
 
 #[component]
 pub fn ModelCard(
     model: Model,
     index: usize,
     on_click: EventHandler<Model>,
-    copied_slug: Signal<Option<String>>,
+    copy_feedback: Signal<Option<CopyFeedback>>,
+    favorites: Signal<Favorites>,
+    arena_selection: Signal<ArenaSelection>,
+    new_since: Option<OffsetDateTime>,
+    is_selected: bool,
+    prompt_tokens: Option<TokenCount>,
+    /// Link to the model's own page, which a plain click opens as the details dialog instead
+    href: Option<String>,
 ) -> Element {
+    let money = use_money();
+    let unit = money.unit().fluent_arg();
+    let locale = money.locale();
     let slug = model.canonical_slug.clone();
     let provider = model.provider().map(str::to_owned);
+    let is_favorite = favorites.read().contains(&slug);
+    let is_new = new_since.is_some_and(|cutoff| model.created >= cutoff);
+    let item_class = [
+        (true, "model-item"),
+        (is_favorite, "favorite"),
+        (is_new, "new"),
+        (is_selected, "selected"),
+    ]
+    .into_iter()
+    .filter_map(|(applies, class)| applies.then_some(class))
+    .collect::<Vec<_>>()
+    .join(" ");
 
     rsx! {
         li {
             key: "{model.name}-{index}",
-            class: "model-item",
+            class: "{item_class}",
+            tabindex: 0,
+            aria_haspopup: "dialog",
+            "data-open-on-enter": "true",
             onclick: {
                 let model_clone = model.clone();
                 move |_| {
@@ -25,10 +58,38 @@ pub fn ModelCard(
                 }
             },
 
-            // Model name
+            // Model name with favorite toggle
             div {
-                class: "model-name",
-                "{model.name}"
+                class: "model-name-row",
+                if let Some(href) = href {
+                    a {
+                        class: "model-name",
+                        href: "{href}",
+                        // Clicks with a modifier keep the link's behaviour (e.g. a new tab)
+                        onclick: move |evt: MouseEvent| {
+                            if evt.modifiers().is_empty() {
+                                evt.prevent_default();
+                            } else {
+                                evt.stop_propagation();
+                            }
+                        },
+                        "{model.name}"
+                    }
+                } else {
+                    div {
+                        class: "model-name",
+                        "{model.name}"
+                    }
+                }
+                if is_new {
+                    span {
+                        class: "new-badge",
+                        title: t!("badge-new-title", date: locale.timestamp(&model.created)),
+                        { t!("badge-new") }
+                    }
+                }
+                ArenaToggle { model_id: model.id.clone(), selection: arena_selection }
+                FavoriteButton { slug: slug.clone(), favorites: favorites }
             }
 
             // Input → Output modality badges
@@ -42,7 +103,7 @@ pub fn ModelCard(
                         rsx! {
                             span {
                                 class: "modality-badge-outline {modality_lower}",
-                                "{modality:?}"
+                                { t!(modality.label_key()) }
                             }
                         }
                     }
@@ -61,7 +122,7 @@ pub fn ModelCard(
                         rsx! {
                             span {
                                 class: "modality-badge {modality_lower}",
-                                "{modality:?}"
+                                { t!(modality.label_key()) }
                             }
                         }
                     }
@@ -80,26 +141,31 @@ pub fn ModelCard(
 
                 // Created timestamp
                 span { class: "metadata-label", { t!("label-created") } }
-                span { class: "metadata-value", "{format_timestamp(&model.created)}" }
+                span { class: "metadata-value", RelativeDate { at: model.created } }
 
                 // Prompt price
-                span { class: "metadata-label", { t!("label-prompt-price") } }
-                span { class: "metadata-value price-value", "{format_price_per_million(model.pricing.prompt)}" }
+                span { class: "metadata-label", { t!("label-prompt-price", unit: unit) } }
+                span { class: "metadata-value price-value", "{money.per_unit(model.pricing.prompt)}" }
 
                 // Completion price
-                span { class: "metadata-label", { t!("label-completion-price") } }
-                span { class: "metadata-value price-value", "{format_price_per_million(model.pricing.completion)}" }
+                span { class: "metadata-label", { t!("label-completion-price", unit: unit) } }
+                span { class: "metadata-value price-value", "{money.per_unit(model.pricing.completion)}" }
+
+                // Cost of the text pasted into the token counter
+                if let Some(count) = prompt_tokens {
+                    span { class: "metadata-label", { t!("label-prompt-cost") } }
+                    span { class: "metadata-value", PromptCost { pricing: model.pricing, count: count } }
+                }
             }
 
-            // Canonical slug with copy button
+            // Canonical slug with copy menu
             {
                 let slug_for_display = slug.clone();
-                let slug_for_copy = slug.clone();
                 rsx! {
                     div {
                         style: "margin-top: 8px;",
                         div {
-                            style: "font-size: 11px; font-weight: 600; color: #7f8c8d; margin-bottom: 4px; text-transform: uppercase;",
+                            style: "font-size: 11px; font-weight: 600; color: var(--color-text-muted); margin-bottom: 4px; text-transform: uppercase;",
                             { t!("label-canonical-slug") }
                         }
                         div {
@@ -112,38 +178,7 @@ pub fn ModelCard(
                                 },
                                 "{slug_for_display}"
                             }
-                            button {
-                                class: if copied_slug.read().as_ref() == Some(&slug_for_copy) {
-                                    "copy-button copied"
-                                } else {
-                                    "copy-button"
-                                },
-                                onclick: move |evt: Event<MouseData>| {
-                                    // Stop propagation to prevent modal from opening
-                                    evt.stop_propagation();
-
-                                    #[cfg(target_arch = "wasm32")]
-                                    {
-                                        use web_sys::window;
-                                        if let Some(window) = window() {
-                                            let clipboard = window.navigator().clipboard();
-                                            let _ = clipboard.write_text(&slug_for_copy);
-                                            copied_slug.set(Some(slug_for_copy.clone()));
-
-                                            // Reset after 2 seconds
-                                            let mut copied_slug_clone = copied_slug;
-                                            gloo_timers::callback::Timeout::new(2000, move || {
-                                                copied_slug_clone.set(None);
-                                            }).forget();
-                                        }
-                                    }
-                                },
-                                if copied_slug.read().as_ref() == Some(&slug_for_copy) {
-                                    { t!("button-copied") }
-                                } else {
-                                    { t!("button-copy") }
-                                }
-                            }
+                            CopyMenu { model: model.clone(), feedback: copy_feedback }
                         }
                     }
                 }
@@ -1,8 +1,12 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use time::OffsetDateTime;
 
-use crate::models::Model;
+use crate::models::{ArenaSelection, Favorites, Model};
+use crate::prerender::model_page_href;
+use crate::utils::TokenCounts;
 
+use super::copy_menu::CopyFeedback;
 use super::model_card::ModelCard;
 
 #[component]
@@ -10,21 +14,16 @@ pub fn ModelList(
     models: Vec<Model>,
     filter: String,
     on_select: EventHandler<Model>,
-    copied_slug: Signal<Option<String>>,
+    copy_feedback: Signal<Option<CopyFeedback>>,
+    favorites: Signal<Favorites>,
+    arena_selection: Signal<ArenaSelection>,
+    new_since: Option<OffsetDateTime>,
+    keyboard_selection: Option<String>,
+    token_counts: Option<TokenCounts>,
 ) -> Element {
     rsx! {
         // Results count
-        div {
-            style: "margin-bottom: 15px; color: #7f8c8d; font-size: 14px;",
-            { t!("models-found", count: models.len()) }
-            if !filter.is_empty() {
-                span {
-                    style: "font-weight: 600; color: #3498db;",
-                    " "
-                    { t!("models-matching", filter: &filter) }
-                }
-            }
-        }
+        ResultsSummary { count: models.len(), filter: filter.clone() }
 
         // Model list (scrollable container)
         div {
@@ -32,12 +31,12 @@ pub fn ModelList(
 
             if models.is_empty() && !filter.is_empty() {
                 div {
-                    style: "text-align: center; padding: 40px; color: #95a5a6;",
+                    style: "text-align: center; padding: 40px; color: var(--color-text-faint);",
                     { t!("no-models-filter") }
                 }
             } else if models.is_empty() {
                 div {
-                    style: "text-align: center; padding: 40px; color: #95a5a6;",
+                    style: "text-align: center; padding: 40px; color: var(--color-text-faint);",
                     { t!("no-models-available") }
                 }
             } else {
@@ -48,10 +47,18 @@ pub fn ModelList(
                             let model_clone = model.clone();
                             rsx! {
                                 ModelCard {
+                                    href: model_page_href(&model.id),
                                     model: model_clone,
                                     index: index,
                                     on_click: move |m: Model| on_select.call(m),
-                                    copied_slug: copied_slug,
+                                    copy_feedback: copy_feedback,
+                                    favorites: favorites,
+                                    arena_selection: arena_selection,
+                                    new_since: new_since,
+                                    is_selected: keyboard_selection.as_ref() == Some(&model.id),
+                                    prompt_tokens: token_counts
+                                        .as_ref()
+                                        .and_then(|counts| counts.get(model.architecture.tokenizer_family())),
                                 }
                             }
                         }
@@ -61,3 +68,39 @@ pub fn ModelList(
         }
     }
 }
+
+/// "Found N models" line shared by the list and table views
+#[component]
+pub fn ResultsSummary(count: usize, filter: String) -> Element {
+    rsx! {
+        div {
+            style: "margin-bottom: 15px; color: var(--color-text-muted); font-size: 14px;",
+            role: "status",
+            aria_live: "polite",
+            aria_atomic: "true",
+            { t!("models-found", count: count) }
+            if !filter.is_empty() {
+                span {
+                    style: "font-weight: 600; color: var(--color-accent);",
+                    " "
+                    { t!("models-matching", filter: &filter) }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+
+    #[test]
+    fn test_results_count_is_announced() {
+        let html = render_to_html(|| {
+            rsx! { ResultsSummary { count: 3, filter: String::new() } }
+        });
+        assert!(html.contains(r#"role="status""#), "{html}");
+        assert!(html.contains(r#"aria-live="polite""#), "{html}");
+    }
+}
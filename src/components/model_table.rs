@@ -0,0 +1,317 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::{ArenaSelection, Favorites, Model, SortDirection, SortKey, TableColumn};
+use crate::utils::TokenCounts;
+
+use super::arena::ArenaToggle;
+use super::currency::use_money;
+use super::favorites::FavoriteButton;
+use super::locale::relative_time_text;
+use super::model_list::ResultsSummary;
+use super::token_counter::PromptCost;
+
+#[component]
+pub fn ModelTable(
+    models: Vec<Model>,
+    filter: String,
+    columns: Vec<TableColumn>,
+    sort_keys: Signal<Vec<SortKey>>,
+    favorites: Signal<Favorites>,
+    arena_selection: Signal<ArenaSelection>,
+    token_counts: Option<TokenCounts>,
+    /// Id of the model selected with the keyboard shortcuts
+    keyboard_selection: Option<String>,
+    on_select: EventHandler<Model>,
+) -> Element {
+    // Keep the canonical column order regardless of the order they were picked in
+    let visible_columns: Vec<TableColumn> = TableColumn::ALL
+        .into_iter()
+        .filter(|column| columns.contains(column))
+        .collect();
+    let unit = use_money().unit().fluent_arg();
+
+    rsx! {
+        ResultsSummary { count: models.len(), filter: filter.clone() }
+
+        div {
+            class: "model-table-container",
+
+            if models.is_empty() && !filter.is_empty() {
+                div {
+                    style: "text-align: center; padding: 40px; color: var(--color-text-faint);",
+                    { t!("no-models-filter") }
+                }
+            } else if models.is_empty() {
+                div {
+                    style: "text-align: center; padding: 40px; color: var(--color-text-faint);",
+                    { t!("no-models-available") }
+                }
+            } else {
+                table {
+                    class: "model-table",
+                    thead {
+                        tr {
+                            for column in visible_columns.iter().copied() {
+                                {
+                                    let column_sort = column.sort_field();
+                                    let keys = sort_keys.read();
+                                    let active_level = column_sort
+                                        .and_then(|field| keys.iter().position(|key| key.field == field));
+                                    // Only the primary key is exposed to assistive technology
+                                    let aria_sort = match (active_level, keys.first()) {
+                                        (Some(0), Some(key)) if key.direction == SortDirection::Ascending => "ascending",
+                                        (Some(0), Some(_)) => "descending",
+                                        _ if column_sort.is_some() => "none",
+                                        _ => "",
+                                    };
+                                    let indicator = match active_level {
+                                        None => String::new(),
+                                        Some(level) => {
+                                            let arrow = if keys[level].direction == SortDirection::Ascending { "▲" } else { "▼" };
+                                            // Only number the levels when sorting by more than one key
+                                            if keys.len() > 1 {
+                                                format!(" {arrow}{}", level + 1)
+                                            } else {
+                                                format!(" {arrow}")
+                                            }
+                                        }
+                                    };
+                                    rsx! {
+                                        th {
+                                            class: match (column_sort.is_some(), active_level.is_some()) {
+                                                (true, true) => "sortable active",
+                                                (true, false) => "sortable",
+                                                _ => "",
+                                            },
+                                            title: if column_sort.is_some() { t!("table-sort-hint") } else { String::new() },
+                                            aria_sort: if aria_sort.is_empty() { None } else { Some(aria_sort) },
+                                            onclick: move |evt: Event<MouseData>| {
+                                                let Some(field) = column_sort else {
+                                                    return;
+                                                };
+                                                let mut keys = sort_keys.write();
+                                                let existing = keys.iter().position(|key| key.field == field);
+
+                                                if evt.modifiers().shift() {
+                                                    // Shift+click adds a secondary key or flips an existing one
+                                                    match existing {
+                                                        Some(level) => keys[level].direction = keys[level].direction.reversed(),
+                                                        None if keys.len() < SortKey::MAX_KEYS => {
+                                                            keys.push(SortKey::new(field, SortDirection::Ascending));
+                                                        }
+                                                        None => {}
+                                                    }
+                                                } else if existing == Some(0) {
+                                                    // Clicking the primary column flips its direction
+                                                    keys[0].direction = keys[0].direction.reversed();
+                                                } else {
+                                                    // Any other column becomes the primary key
+                                                    let direction = keys.first().map_or(SortDirection::Ascending, |key| key.direction);
+                                                    keys.retain(|key| key.field != field);
+                                                    keys.insert(0, SortKey::new(field, direction));
+                                                    keys.truncate(SortKey::MAX_KEYS);
+                                                }
+                                            },
+                                            { t!(column.label_key(), unit: unit) }
+                                            "{indicator}"
+                                        }
+                                    }
+                                }
+                            }
+                            // Cost of the text pasted into the token counter
+                            if token_counts.is_some() {
+                                th { { t!("column-prompt-cost") } }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (index, model) in models.iter().enumerate() {
+                            {
+                                let model_clone = model.clone();
+                                let is_selected = keyboard_selection.as_ref() == Some(&model.id);
+                                rsx! {
+                                    tr {
+                                        key: "{model.id}-{index}",
+                                        class: if is_selected { "selected" },
+                                        aria_selected: is_selected.then_some("true"),
+                                        tabindex: 0,
+                                        aria_haspopup: "dialog",
+                                        "data-open-on-enter": "true",
+                                        onclick: move |_| on_select.call(model_clone.clone()),
+                                        for column in visible_columns.iter().copied() {
+                                            TableCell { model: model.clone(), column: column, favorites: favorites, arena_selection: arena_selection }
+                                        }
+                                        if let Some(counts) = token_counts.as_ref() {
+                                            td {
+                                                if let Some(count) = counts.get(model.architecture.tokenizer_family()) {
+                                                    PromptCost { pricing: model.pricing, count: count }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TableCell(
+    model: Model,
+    column: TableColumn,
+    favorites: Signal<Favorites>,
+    arena_selection: Signal<ArenaSelection>,
+) -> Element {
+    let money = use_money();
+    let locale = money.locale();
+    let pricing = model.pricing;
+
+    match column {
+        TableColumn::Name => rsx! {
+            td {
+                class: "model-table-name",
+                FavoriteButton { slug: model.canonical_slug.clone(), favorites: favorites }
+                ArenaToggle { model_id: model.id.clone(), selection: arena_selection }
+                "{model.name}"
+            }
+        },
+        TableColumn::Provider => rsx! {
+            td { "{model.provider().unwrap_or(\"—\")}" }
+        },
+        TableColumn::PromptPrice => rsx! {
+            td { class: "price-value", "{money.per_unit(pricing.prompt)}" }
+        },
+        TableColumn::CompletionPrice => rsx! {
+            td { class: "price-value", "{money.per_unit(pricing.completion)}" }
+        },
+        TableColumn::InternalReasoningPrice => rsx! {
+            td { class: "price-value", "{money.per_unit(pricing.internal_reasoning)}" }
+        },
+        TableColumn::InputCacheReadPrice => rsx! {
+            td { class: "price-value", "{money.per_unit(pricing.input_cache_read)}" }
+        },
+        TableColumn::InputCacheWritePrice => rsx! {
+            td { class: "price-value", "{money.per_unit(pricing.input_cache_write)}" }
+        },
+        TableColumn::ImagePrice => rsx! {
+            td { class: "price-value", "{money.format(pricing.image)}" }
+        },
+        TableColumn::RequestPrice => rsx! {
+            td { class: "price-value", "{money.format(pricing.request)}" }
+        },
+        TableColumn::WebSearchPrice => rsx! {
+            td { class: "price-value", "{money.format(pricing.web_search)}" }
+        },
+        TableColumn::ContextLength => rsx! {
+            td { class: "numeric-value", "{locale.integer(model.top_provider.context_length as u64)}" }
+        },
+        TableColumn::MaxCompletionTokens => rsx! {
+            td {
+                class: "numeric-value",
+                if model.top_provider.max_completion_tokens == 0 {
+                    { t!("value-no-limit") }
+                } else {
+                    "{locale.integer(model.top_provider.max_completion_tokens as u64)}"
+                }
+            }
+        },
+        TableColumn::Modalities => rsx! {
+            td {
+                div {
+                    class: "modality-badges compact",
+                    for modality in &model.architecture.input_modalities {
+                        {
+                            let modality_lower = format!("{:?}", modality).to_lowercase();
+                            rsx! {
+                                span {
+                                    class: "modality-badge-outline {modality_lower}",
+                                    { t!(modality.label_key()) }
+                                }
+                            }
+                        }
+                    }
+                    span { class: "modality-separator", "⇒" }
+                    for modality in &model.architecture.output_modalities {
+                        {
+                            let modality_lower = format!("{:?}", modality).to_lowercase();
+                            rsx! {
+                                span {
+                                    class: "modality-badge {modality_lower}",
+                                    { t!(modality.label_key()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        TableColumn::Created => rsx! {
+            td {
+                class: "model-table-date",
+                title: relative_time_text(model.created),
+                "{locale.utc_date(&model.created)}"
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    #[test]
+    fn test_keyboard_selection_is_marked() {
+        let html = render_to_html(|| {
+            let response: ApiResponse =
+                serde_json::from_str(include_str!("../models.json")).unwrap();
+            let selected = response.data[1].id.clone();
+            rsx! {
+                ModelTable {
+                    models: response.data,
+                    filter: String::new(),
+                    columns: TableColumn::defaults(),
+                    sort_keys: use_signal(Vec::new),
+                    favorites: use_signal(Favorites::default),
+                    arena_selection: use_signal(ArenaSelection::default),
+                    token_counts: None,
+                    keyboard_selection: selected,
+                    on_select: |_| {},
+                }
+            }
+        });
+        assert_eq!(html.matches(r#"class="selected""#).count(), 1, "{html}");
+        assert_eq!(html.matches(r#"aria-selected="true""#).count(), 1, "{html}");
+    }
+
+    #[test]
+    fn test_rows_can_be_added_to_the_arena() {
+        let html = render_to_html(|| {
+            let response: ApiResponse =
+                serde_json::from_str(include_str!("../models.json")).unwrap();
+            let mut selection = ArenaSelection::default();
+            selection.toggle(&response.data[0].id);
+            rsx! {
+                ModelTable {
+                    models: response.data,
+                    filter: String::new(),
+                    columns: TableColumn::defaults(),
+                    sort_keys: use_signal(Vec::new),
+                    favorites: use_signal(Favorites::default),
+                    arena_selection: use_signal(|| selection),
+                    token_counts: None,
+                    keyboard_selection: None,
+                    on_select: |_| {},
+                }
+            }
+        });
+        assert!(html.contains(r#"class="arena-toggle active""#), "{html}");
+        assert!(html.contains(r#"class="arena-toggle""#), "{html}");
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use dioxus::core::Task;
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use rust_decimal::Decimal;
+use serde_json::Map;
+use web_time::Instant;
+
+use crate::api::{stream_chat_completion, ApiSettings, ChatError, ChatOutcome, ChatRequest};
+use crate::models::{parameter_label_keys, Model, ParameterKind};
+use crate::storage;
+
+use super::currency::use_money;
+
+/// Storage key for the API key and base URL, written only when the user asks to remember the key
+const API_SETTINGS_STORAGE_KEY: &str = "polza-models-api-settings";
+
+/// Progress of the current playground request
+#[derive(Debug, Clone, PartialEq)]
+enum RunState {
+    Idle,
+    Running,
+    Finished {
+        outcome: ChatOutcome,
+        elapsed: Duration,
+    },
+    Failed(ChatError),
+    Cancelled,
+}
+
+/// "Try it" tab: send a prompt to the model and stream the answer
+#[component]
+pub fn Playground(model: Model) -> Element {
+    let money = use_money();
+    let settings = use_api_settings();
+    let mut prompt = use_signal(String::new);
+    let mut parameter_inputs = use_signal(BTreeMap::<String, String>::new);
+    let mut response = use_signal(String::new);
+    let mut state = use_signal(|| RunState::Idle);
+    let mut task = use_signal(|| None::<Task>);
+
+    // Supported parameters that can be edited as a single value
+    let editable: Vec<(String, ParameterKind)> = model
+        .supported_parameters
+        .iter()
+        .filter_map(|name| Some((name.clone(), ParameterKind::for_parameter(name)?)))
+        .collect();
+
+    // Parameters to send; blank inputs fall back to the provider defaults
+    let mut parameters = Map::new();
+    let mut errors = BTreeMap::new();
+    for (name, kind) in &editable {
+        let input = parameter_inputs
+            .read()
+            .get(name)
+            .cloned()
+            .unwrap_or_default();
+        match kind.parse(&input) {
+            Ok(Some(value)) => {
+                parameters.insert(name.clone(), value);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                errors.insert(name.clone(), err);
+            }
+        }
+    }
+
+    let is_running = *state.read() == RunState::Running;
+    let can_send = !is_running && !prompt.read().trim().is_empty() && errors.is_empty();
+
+    let send = {
+        let model_id = model.id.clone();
+        move |_| {
+            let request =
+                ChatRequest::new(model_id.clone(), prompt.read().clone(), parameters.clone());
+            let settings = settings.read().clone();
+            response.set(String::new());
+            state.set(RunState::Running);
+
+            let started = Instant::now();
+            task.set(Some(spawn(async move {
+                let result = stream_chat_completion(&settings, &request, |delta| {
+                    response.write().push_str(delta)
+                })
+                .await;
+                state.set(match result {
+                    Ok(outcome) => RunState::Finished {
+                        outcome,
+                        elapsed: started.elapsed(),
+                    },
+                    Err(err) => RunState::Failed(err),
+                });
+                task.set(None);
+            })));
+        }
+    };
+
+    rsx! {
+        div {
+            class: "playground",
+
+            // Connection
+            ApiSettingsFields { settings: settings }
+
+            // Prompt
+            div {
+                class: "playground-row",
+                label { r#for: "playground-prompt", class: "modal-label", { t!("playground-prompt") } }
+                textarea {
+                    id: "playground-prompt",
+                    class: "favorites-json",
+                    rows: 4,
+                    value: "{prompt}",
+                    placeholder: "{t!(\"playground-prompt-placeholder\")}",
+                    oninput: move |evt| prompt.set(evt.value()),
+                }
+            }
+
+            // Parameters
+            if !editable.is_empty() {
+                div {
+                    class: "playground-parameters",
+                    for (name, kind) in editable.iter().cloned() {
+                        {
+                            let input_id = format!("playground-parameter-{name}");
+                            let value = parameter_inputs.read().get(&name).cloned().unwrap_or_default();
+                            let error = errors.get(&name).copied();
+                            let (input_type, min, max, step) = match kind {
+                                ParameterKind::Float { min, max, step } => {
+                                    ("number", min.to_string(), max.to_string(), step.to_string())
+                                }
+                                ParameterKind::Integer { min } => ("number", min.to_string(), String::new(), "1".to_string()),
+                                ParameterKind::Text => ("text", String::new(), String::new(), String::new()),
+                            };
+                            rsx! {
+                                div {
+                                    key: "{name}",
+                                    class: "playground-parameter",
+                                    label {
+                                        r#for: "{input_id}",
+                                        class: "playground-parameter-name",
+                                        title: parameter_description(&name),
+                                        "{parameter_name(&name)}"
+                                    }
+                                    input {
+                                        id: "{input_id}",
+                                        class: if error.is_some() { "playground-parameter-input invalid" } else { "playground-parameter-input" },
+                                        r#type: input_type,
+                                        min: "{min}",
+                                        max: "{max}",
+                                        step: "{step}",
+                                        value: "{value}",
+                                        placeholder: "{t!(\"playground-parameter-default\")}",
+                                        aria_invalid: "{error.is_some()}",
+                                        oninput: {
+                                            let name = name.clone();
+                                            move |evt: Event<FormData>| {
+                                                parameter_inputs.write().insert(name.clone(), evt.value());
+                                            }
+                                        },
+                                    }
+                                    if let Some(error) = error {
+                                        span { class: "playground-error", { t!(error.message_key()) } }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Actions
+            div {
+                class: "favorites-panel-actions",
+                button {
+                    class: "retry-button",
+                    disabled: !can_send,
+                    onclick: send,
+                    { t!("playground-send") }
+                }
+                if is_running {
+                    button {
+                        class: "retry-button",
+                        onclick: move |_| {
+                            if let Some(running) = task.write().take() {
+                                running.cancel();
+                            }
+                            state.set(RunState::Cancelled);
+                        },
+                        { t!("playground-stop") }
+                    }
+                }
+            }
+
+            // Streamed answer
+            if !response.read().is_empty() || is_running {
+                pre {
+                    class: "playground-response",
+                    aria_busy: "{is_running}",
+                    "{response}"
+                }
+            }
+
+            // Outcome
+            div {
+                class: "playground-status",
+                role: "status",
+                aria_live: "polite",
+                match &*state.read() {
+                    RunState::Idle => rsx! {},
+                    RunState::Running => rsx! { { t!("playground-running") } },
+                    RunState::Cancelled => rsx! { { t!("playground-cancelled") } },
+                    RunState::Failed(ChatError::MissingApiKey) => rsx! {
+                        span { class: "playground-error", { t!("playground-error-no-key") } }
+                    },
+                    RunState::Failed(err) => rsx! {
+                        span { class: "playground-error", { t!("playground-error", error: err.to_string()) } }
+                    },
+                    RunState::Finished { outcome, elapsed } => {
+                        let seconds = money.locale().decimal(Decimal::new(elapsed.as_millis() as i64, 3).round_dp(1));
+                        match outcome.usage {
+                            Some(usage) => {
+                                let cost = model.pricing.usage_cost(usage.billed());
+                                rsx! {
+                                    {
+                                        t!(
+                                            "playground-usage",
+                                            prompt: money.locale().integer(usage.prompt_tokens),
+                                            completion: money.locale().integer(usage.completion_tokens),
+                                            cost: money.format(cost.round_dp(6)),
+                                            seconds: seconds
+                                        )
+                                    }
+                                }
+                            }
+                            None => rsx! { { t!("playground-no-usage", seconds: seconds) } },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Display name of a request parameter, or its API identifier when it has no translation
+pub fn parameter_name(name: &str) -> String {
+    parameter_label_keys(name).map_or_else(|| name.to_string(), |(label, _)| t!(label))
+}
+
+/// What a request parameter does, shown as a tooltip; `None` when it has no translation
+pub fn parameter_description(name: &str) -> Option<String> {
+    parameter_label_keys(name).map(|(_, description)| t!(description))
+}
+
+/// API settings of the session, shared by the playground and the arena.
+///
+/// They live in memory and are dropped with the tab; only with "remember key" ticked are they
+/// written to storage. Settings stored without that flag (by earlier versions) are discarded.
+pub fn use_session_api_settings() -> Signal<ApiSettings> {
+    let settings = use_signal(|| {
+        storage::load::<ApiSettings>(API_SETTINGS_STORAGE_KEY)
+            .filter(|settings| settings.remember_key)
+            .unwrap_or_default()
+    });
+
+    use_effect(move || {
+        let settings = settings.read();
+        if settings.remember_key {
+            storage::save(API_SETTINGS_STORAGE_KEY, &*settings);
+        } else {
+            storage::remove(API_SETTINGS_STORAGE_KEY);
+        }
+    });
+
+    settings
+}
+
+/// API settings provided by the app through [`use_session_api_settings`]; components rendered on
+/// their own (e.g. in tests) get settings of their own
+pub fn use_api_settings() -> Signal<ApiSettings> {
+    let own = use_signal(ApiSettings::default);
+    try_use_context().unwrap_or(own)
+}
+
+/// API key and base URL inputs shared by the playground and the arena
+#[component]
+pub fn ApiSettingsFields(settings: Signal<ApiSettings>) -> Element {
+    rsx! {
+        div {
+            class: "playground-row",
+            label { r#for: "playground-api-key", class: "modal-label", { t!("playground-api-key") } }
+            input {
+                id: "playground-api-key",
+                class: "filter-input",
+                r#type: "password",
+                autocomplete: "off",
+                value: "{settings.read().api_key}",
+                placeholder: "{t!(\"playground-api-key-placeholder\")}",
+                oninput: move |evt| settings.write().api_key = evt.value(),
+            }
+            label {
+                class: "column-picker-option",
+                input {
+                    r#type: "checkbox",
+                    checked: settings.read().remember_key,
+                    onchange: move |evt| settings.write().remember_key = evt.checked(),
+                }
+                { t!("playground-remember-key") }
+            }
+            div { class: "playground-hint", { t!("playground-api-key-hint") } }
+        }
+        details {
+            class: "playground-advanced",
+            summary { { t!("playground-advanced") } }
+            label { r#for: "playground-base-url", class: "modal-label", { t!("playground-base-url") } }
+            input {
+                id: "playground-base-url",
+                class: "filter-input",
+                r#type: "url",
+                value: "{settings.read().base_url}",
+                oninput: move |evt| settings.write().base_url = evt.value(),
+            }
+        }
+    }
+}
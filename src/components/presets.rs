@@ -0,0 +1,219 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use std::collections::HashSet;
+
+use crate::models::{name_taken, FilterPreset, Modality, SortKey};
+
+/// Saved filter presets shown as chips above the filter controls
+#[component]
+pub fn PresetChips(
+    presets: Signal<Vec<FilterPreset>>,
+    filter_text: Signal<String>,
+    selected_input_modalities: Signal<HashSet<Modality>>,
+    selected_output_modalities: Signal<HashSet<Modality>>,
+    sort_keys: Signal<Vec<SortKey>>,
+) -> Element {
+    // Name being typed for a new preset (Some while the save form is open)
+    let mut new_name = use_signal(|| None::<String>);
+    // Index and name of the preset being renamed
+    let mut renaming = use_signal(|| None::<(usize, String)>);
+
+    let saved = presets.read().clone();
+
+    rsx! {
+        div {
+            class: "preset-bar",
+
+            for (index, preset) in saved.into_iter().enumerate() {
+                {
+                    let is_active = preset.matches(
+                        &filter_text.read(),
+                        &selected_input_modalities.read(),
+                        &selected_output_modalities.read(),
+                        &sort_keys.read(),
+                    );
+                    let rename_value = renaming
+                        .read()
+                        .as_ref()
+                        .filter(|(renamed, _)| *renamed == index)
+                        .map(|(_, name)| name.clone());
+                    let rename_taken = rename_value
+                        .as_ref()
+                        .is_some_and(|name| name_taken(&presets.read(), index, name.trim()));
+
+                    rsx! {
+                        div {
+                            key: "{index}-{preset.name}",
+                            class: if is_active { "preset-chip active" } else { "preset-chip" },
+
+                            if let Some(value) = rename_value {
+                                input {
+                                    class: if rename_taken { "preset-name-input invalid" } else { "preset-name-input" },
+                                    r#type: "text",
+                                    value: "{value}",
+                                    aria_label: t!("preset-rename"),
+                                    aria_invalid: "{rename_taken}",
+                                    title: if rename_taken { t!("preset-name-taken") } else { String::new() },
+                                    oninput: move |evt| renaming.set(Some((index, evt.value()))),
+                                    onkeydown: move |evt| {
+                                        if evt.key() == Key::Enter {
+                                            commit_rename(presets, renaming);
+                                        } else if evt.key() == Key::Escape {
+                                            renaming.set(None);
+                                        }
+                                    },
+                                }
+                                button {
+                                    class: "preset-chip-action",
+                                    title: "{t!(\"preset-save\")}",
+                                    aria_label: t!("preset-save"),
+                                    disabled: rename_taken,
+                                    onclick: move |_| commit_rename(presets, renaming),
+                                    "✓"
+                                }
+                            } else {
+                                button {
+                                    class: "preset-chip-apply",
+                                    onclick: {
+                                        let preset = preset.clone();
+                                        move |_| {
+                                            filter_text.set(preset.filter_text.clone());
+                                            selected_input_modalities.set(preset.input_modalities.iter().copied().collect());
+                                            selected_output_modalities.set(preset.output_modalities.iter().copied().collect());
+                                            sort_keys.set(preset.sort_keys.clone());
+                                        }
+                                    },
+                                    "{preset.name}"
+                                }
+                                button {
+                                    class: "preset-chip-action",
+                                    title: "{t!(\"preset-rename\")}",
+                                    aria_label: t!("preset-rename"),
+                                    onclick: {
+                                        let name = preset.name.clone();
+                                        move |_| renaming.set(Some((index, name.clone())))
+                                    },
+                                    "✎"
+                                }
+                                button {
+                                    class: "preset-chip-action",
+                                    title: "{t!(\"preset-delete\")}",
+                                    aria_label: t!("preset-delete"),
+                                    onclick: move |_| {
+                                        let mut presets = presets.write();
+                                        if index < presets.len() {
+                                            presets.remove(index);
+                                        }
+                                    },
+                                    "×"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Save the current filters as a new preset
+            if let Some(name) = new_name.read().clone() {
+                div {
+                    class: "preset-chip editing",
+                    input {
+                        class: "preset-name-input",
+                        r#type: "text",
+                        value: "{name}",
+                        placeholder: "{t!(\"preset-name-placeholder\")}",
+                        aria_label: t!("preset-name-placeholder"),
+                        oninput: move |evt| new_name.set(Some(evt.value())),
+                        onkeydown: move |evt| {
+                            if evt.key() == Key::Enter {
+                                save_preset(presets, new_name, filter_text, selected_input_modalities, selected_output_modalities, sort_keys);
+                            } else if evt.key() == Key::Escape {
+                                new_name.set(None);
+                            }
+                        },
+                    }
+                    button {
+                        class: "preset-chip-action",
+                        title: "{t!(\"preset-save\")}",
+                        aria_label: t!("preset-save"),
+                        onclick: move |_| save_preset(presets, new_name, filter_text, selected_input_modalities, selected_output_modalities, sort_keys),
+                        "✓"
+                    }
+                    button {
+                        class: "preset-chip-action",
+                        title: "{t!(\"preset-cancel\")}",
+                        aria_label: t!("preset-cancel"),
+                        onclick: move |_| new_name.set(None),
+                        "×"
+                    }
+                }
+            } else {
+                button {
+                    class: "preset-add-button",
+                    onclick: move |_| new_name.set(Some(String::new())),
+                    { t!("preset-save-current") }
+                }
+            }
+        }
+    }
+}
+
+/// Store the current filters under the typed name, replacing a preset with the same name
+fn save_preset(
+    mut presets: Signal<Vec<FilterPreset>>,
+    mut new_name: Signal<Option<String>>,
+    filter_text: Signal<String>,
+    selected_input_modalities: Signal<HashSet<Modality>>,
+    selected_output_modalities: Signal<HashSet<Modality>>,
+    sort_keys: Signal<Vec<SortKey>>,
+) {
+    let name = new_name
+        .read()
+        .as_deref()
+        .unwrap_or_default()
+        .trim()
+        .to_owned();
+    if name.is_empty() {
+        return;
+    }
+
+    let preset = FilterPreset::capture(
+        name,
+        &filter_text.read(),
+        &selected_input_modalities.read(),
+        &selected_output_modalities.read(),
+        &sort_keys.read(),
+    );
+
+    let mut presets = presets.write();
+    match presets
+        .iter_mut()
+        .find(|existing| existing.name == preset.name)
+    {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+    new_name.set(None);
+}
+
+/// Apply the pending rename, ignoring empty names.
+///
+/// A name already used by another preset is refused and the rename stays open.
+fn commit_rename(
+    mut presets: Signal<Vec<FilterPreset>>,
+    mut renaming: Signal<Option<(usize, String)>>,
+) {
+    let Some((index, name)) = renaming.peek().clone() else {
+        return;
+    };
+    let name = name.trim();
+    if name_taken(&presets.peek(), index, name) {
+        return;
+    }
+    renaming.set(None);
+    if !name.is_empty() {
+        if let Some(preset) = presets.write().get_mut(index) {
+            preset.name = name.to_owned();
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::history::PricePoint;
+use crate::utils::LinearScale;
+
+use super::currency::use_money;
+
+// Chart geometry in SVG user units
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 200.0;
+const MARGIN_LEFT: f64 = 90.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 16.0;
+const MARGIN_BOTTOM: f64 = 28.0;
+
+/// A plotted point: SVG position plus the original date and price for the tooltip
+struct ChartPoint {
+    x: f64,
+    y: f64,
+    at: time::OffsetDateTime,
+    price: Decimal,
+}
+
+/// Line chart of prompt and completion prices over time, drawn with plain SVG
+#[component]
+pub fn PriceChart(points: Vec<PricePoint>) -> Element {
+    let money = use_money();
+    if points.is_empty() {
+        return rsx! {
+            div { class: "price-chart-note", { t!("price-history-empty") } }
+        };
+    }
+
+    // Plotted in the chosen price unit; the labels format the exact `Decimal` prices
+    let unit = money.unit();
+    let locale = money.locale();
+    let plotted = |price: Decimal| unit.scale(price).to_f64().unwrap_or(0.0);
+    let prices = points.iter().flat_map(|p| [p.prompt, p.completion]);
+    let min_price = prices.clone().min().unwrap_or_default();
+    let max_price = prices.clone().max().unwrap_or_default();
+
+    let (Some(x_scale), Some(y_scale)) = (
+        LinearScale::fit(
+            points.iter().map(|p| p.at.unix_timestamp() as f64),
+            (MARGIN_LEFT, WIDTH - MARGIN_RIGHT),
+        ),
+        LinearScale::fit(prices.map(plotted), (HEIGHT - MARGIN_BOTTOM, MARGIN_TOP)),
+    ) else {
+        return rsx! {};
+    };
+
+    let series = |price_of: fn(&PricePoint) -> Decimal| -> Vec<ChartPoint> {
+        points
+            .iter()
+            .map(|p| ChartPoint {
+                x: x_scale.map(p.at.unix_timestamp() as f64),
+                y: y_scale.map(plotted(price_of(p))),
+                at: p.at,
+                price: price_of(p),
+            })
+            .collect()
+    };
+    let all_series = [
+        (
+            "prompt",
+            t!("price-history-prompt", unit: unit.fluent_arg()),
+            series(|p| p.prompt),
+        ),
+        (
+            "completion",
+            t!("price-history-completion", unit: unit.fluent_arg()),
+            series(|p| p.completion),
+        ),
+    ];
+
+    let first = points[0].at;
+    let last = points[points.len() - 1].at;
+    let axis_bottom = HEIGHT - MARGIN_BOTTOM;
+
+    rsx! {
+        div {
+            class: "price-chart",
+            svg {
+                class: "price-chart-svg",
+                view_box: "0 0 {WIDTH} {HEIGHT}",
+                preserve_aspect_ratio: "xMidYMid meet",
+
+                // Axes
+                line { class: "price-chart-axis", x1: "{MARGIN_LEFT}", y1: "{MARGIN_TOP}", x2: "{MARGIN_LEFT}", y2: "{axis_bottom}" }
+                line { class: "price-chart-axis", x1: "{MARGIN_LEFT}", y1: "{axis_bottom}", x2: "{WIDTH - MARGIN_RIGHT}", y2: "{axis_bottom}" }
+
+                // Y labels (min and max price per price unit)
+                text {
+                    class: "price-chart-label",
+                    x: "{MARGIN_LEFT - 6.0}",
+                    y: "{y_scale.map(plotted(max_price)) + 4.0}",
+                    text_anchor: "end",
+                    "{money.per_unit(max_price)}"
+                }
+                if min_price != max_price {
+                    text {
+                        class: "price-chart-label",
+                        x: "{MARGIN_LEFT - 6.0}",
+                        y: "{y_scale.map(plotted(min_price)) + 4.0}",
+                        text_anchor: "end",
+                        "{money.per_unit(min_price)}"
+                    }
+                }
+
+                // X labels (first and last date)
+                text {
+                    class: "price-chart-label",
+                    x: "{x_scale.map(first.unix_timestamp() as f64)}",
+                    y: "{HEIGHT - 8.0}",
+                    text_anchor: if points.len() == 1 { "middle" } else { "start" },
+                    "{locale.utc_date(&first)}"
+                }
+                if points.len() > 1 {
+                    text {
+                        class: "price-chart-label",
+                        x: "{x_scale.map(last.unix_timestamp() as f64)}",
+                        y: "{HEIGHT - 8.0}",
+                        text_anchor: "end",
+                        "{locale.utc_date(&last)}"
+                    }
+                }
+
+                // One line plus hoverable points per series
+                for (class_name, label, chart_points) in all_series.iter() {
+                    if chart_points.len() > 1 {
+                        polyline {
+                            class: "price-chart-line {class_name}",
+                            fill: "none",
+                            points: chart_points.iter().map(|p| format!("{:.1},{:.1}", p.x, p.y)).collect::<Vec<_>>().join(" "),
+                        }
+                    }
+                    for point in chart_points.iter() {
+                        circle {
+                            class: "price-chart-point {class_name}",
+                            cx: "{point.x:.1}",
+                            cy: "{point.y:.1}",
+                            r: "4",
+                            title {
+                                "{label} {locale.timestamp(&point.at)} — {money.per_unit(point.price)}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Legend
+            div {
+                class: "price-chart-legend",
+                for (class_name, label, _) in all_series.iter() {
+                    span {
+                        class: "price-chart-legend-item",
+                        span { class: "price-chart-swatch {class_name}" }
+                        "{label}"
+                    }
+                }
+            }
+
+            if points.len() == 1 {
+                div { class: "price-chart-note", { t!("price-history-single") } }
+            }
+        }
+    }
+}
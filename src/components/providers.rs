@@ -0,0 +1,153 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::Model;
+use crate::utils::{summarize_providers, PriceStats};
+
+use super::currency::use_money;
+
+/// Per-provider overview: model count, price spread, modalities, newest model and moderation
+#[component]
+pub fn ProviderOverview(
+    models: Vec<Model>,
+    on_select_provider: EventHandler<String>,
+    on_select_model: EventHandler<Model>,
+) -> Element {
+    let summaries = use_memo(use_reactive!(|models| summarize_providers(&models)));
+    let money = use_money();
+    let unit = money.unit().fluent_arg();
+    let locale = money.locale();
+
+    rsx! {
+        div {
+            style: "margin-bottom: 12px; color: var(--color-text-muted); font-size: 14px;",
+            { t!("providers-found", count: summaries.read().len()) }
+        }
+
+        div {
+            class: "model-table-container",
+            table {
+                class: "model-table provider-table",
+                thead {
+                    tr {
+                        th { { t!("providers-column-provider") } }
+                        th { { t!("providers-column-models") } }
+                        th { { t!("providers-column-prompt", unit: unit) } }
+                        th { { t!("providers-column-completion", unit: unit) } }
+                        th { { t!("providers-column-modalities") } }
+                        th { { t!("providers-column-newest") } }
+                        th { { t!("providers-column-moderated") } }
+                    }
+                }
+                tbody {
+                    for summary in summaries.read().iter().cloned() {
+                        tr {
+                            key: "{summary.name}",
+                            title: t!("providers-row-hint"),
+                            tabindex: 0,
+                            "data-open-on-enter": "true",
+                            onclick: {
+                                let name = summary.name.clone();
+                                move |_| on_select_provider.call(name.clone())
+                            },
+
+                            td { class: "model-table-name", "{summary.name}" }
+                            td { class: "numeric-value", "{locale.integer(summary.model_count as u64)}" }
+                            td { class: "numeric-value", PriceSpread { stats: summary.prompt } }
+                            td { class: "numeric-value", PriceSpread { stats: summary.completion } }
+                            td {
+                                div {
+                                    class: "modality-badges compact",
+                                    for modality in summary.input_modalities.iter() {
+                                        {
+                                            let modality_lower = format!("{:?}", modality).to_lowercase();
+                                            rsx! {
+                                                span {
+                                                    class: "modality-badge-outline {modality_lower}",
+                                                    { t!(modality.label_key()) }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    span { class: "modality-separator", "⇒" }
+                                    for modality in summary.output_modalities.iter() {
+                                        {
+                                            let modality_lower = format!("{:?}", modality).to_lowercase();
+                                            rsx! {
+                                                span {
+                                                    class: "modality-badge {modality_lower}",
+                                                    { t!(modality.label_key()) }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            td {
+                                button {
+                                    class: "provider-newest-link",
+                                    title: "{locale.timestamp(&summary.newest.created)}",
+                                    onclick: {
+                                        let newest = summary.newest.clone();
+                                        move |evt: Event<MouseData>| {
+                                            // Stop propagation so the row does not apply the provider filter
+                                            evt.stop_propagation();
+                                            on_select_model.call(newest.clone());
+                                        }
+                                    },
+                                    "{summary.newest.name}"
+                                }
+                            }
+                            td { class: "numeric-value", "{locale.percent(summary.moderated_share)}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Min / median / max of a price, per price unit
+#[component]
+fn PriceSpread(stats: PriceStats) -> Element {
+    let money = use_money();
+    rsx! {
+        div {
+            class: "price-spread",
+            span { title: t!("providers-min"), "{money.per_unit(stats.min)}" }
+            span { class: "price-spread-median", title: t!("providers-median"), "{money.per_unit(stats.median)}" }
+            span { title: t!("providers-max"), "{money.per_unit(stats.max)}" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    #[test]
+    fn test_provider_rows_are_keyboard_reachable() {
+        let html = render_to_html(|| {
+            let response: ApiResponse =
+                serde_json::from_str(include_str!("../models.json")).unwrap();
+            rsx! {
+                ProviderOverview {
+                    models: response.data,
+                    on_select_provider: |_| {},
+                    on_select_model: |_| {},
+                }
+            }
+        });
+        // The header row has no attributes, so only the provider rows match
+        let rows = html.matches("<tr ").count();
+        assert!(rows > 0, "{html}");
+        assert_eq!(html.matches(r#"tabindex="0""#).count(), rows, "{html}");
+        assert_eq!(
+            html.matches(r#"data-open-on-enter="true""#).count(),
+            rows,
+            "{html}"
+        );
+    }
+}
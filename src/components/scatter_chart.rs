@@ -0,0 +1,232 @@
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+
+use crate::models::{ChartAxis, Model};
+use crate::utils::{axis_value, provider_color, AxisScale, Money};
+
+use super::currency::use_money;
+
+// Chart geometry in SVG user units
+const WIDTH: f64 = 760.0;
+const HEIGHT: f64 = 440.0;
+const MARGIN_LEFT: f64 = 90.0;
+const MARGIN_RIGHT: f64 = 24.0;
+const MARGIN_TOP: f64 = 16.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+
+/// Scatter plot of the filtered models on two selectable axes
+#[component]
+pub fn ScatterChart(
+    models: Vec<Model>,
+    x_axis: Signal<ChartAxis>,
+    y_axis: Signal<ChartAxis>,
+    on_select: EventHandler<Model>,
+) -> Element {
+    let money = use_money();
+    let (x, y) = (*x_axis.read(), *y_axis.read());
+
+    // Models that can be placed on both axes
+    let placed: Vec<(f64, f64, &Model)> = models
+        .iter()
+        .filter_map(|model| {
+            Some((
+                axis_value(model, x, money.unit())?,
+                axis_value(model, y, money.unit())?,
+                model,
+            ))
+        })
+        .collect();
+    let hidden = models.len() - placed.len();
+
+    let x_scale = AxisScale::fit(
+        x,
+        placed.iter().map(|(value, _, _)| *value),
+        (MARGIN_LEFT, WIDTH - MARGIN_RIGHT),
+    );
+    let y_scale = AxisScale::fit(
+        y,
+        placed.iter().map(|(_, value, _)| *value),
+        (HEIGHT - MARGIN_BOTTOM, MARGIN_TOP),
+    );
+
+    let providers: BTreeSet<&str> = placed
+        .iter()
+        .filter_map(|(_, _, model)| model.provider())
+        .collect();
+    let axis_bottom = HEIGHT - MARGIN_BOTTOM;
+    let unit = money.unit().fluent_arg();
+    let (x_label, y_label) = (t!(x.label_key(), unit: unit), t!(y.label_key(), unit: unit));
+
+    rsx! {
+        div {
+            class: "scatter-chart",
+
+            // Axis selectors
+            div {
+                class: "scatter-chart-controls",
+                AxisSelect { label: t!("chart-x-axis"), axis: x_axis }
+                AxisSelect { label: t!("chart-y-axis"), axis: y_axis }
+                if y.is_logarithmic() || x.is_logarithmic() {
+                    span { class: "scatter-chart-note", { t!("chart-log-scale-note") } }
+                }
+            }
+
+            if let (Some(x_scale), Some(y_scale)) = (x_scale, y_scale) {
+                svg {
+                    class: "scatter-chart-svg",
+                    view_box: "0 0 {WIDTH} {HEIGHT}",
+                    preserve_aspect_ratio: "xMidYMid meet",
+
+                    // Gridlines and tick labels
+                    for tick in x_scale.ticks() {
+                        line {
+                            class: "scatter-chart-grid",
+                            x1: "{x_scale.map(tick):.1}",
+                            y1: "{MARGIN_TOP}",
+                            x2: "{x_scale.map(tick):.1}",
+                            y2: "{axis_bottom}",
+                        }
+                        text {
+                            class: "scatter-chart-label",
+                            x: "{x_scale.map(tick):.1}",
+                            y: "{axis_bottom + 16.0}",
+                            text_anchor: "middle",
+                            "{format_axis_value(money, x, tick)}"
+                        }
+                    }
+                    for tick in y_scale.ticks() {
+                        line {
+                            class: "scatter-chart-grid",
+                            x1: "{MARGIN_LEFT}",
+                            y1: "{y_scale.map(tick):.1}",
+                            x2: "{WIDTH - MARGIN_RIGHT}",
+                            y2: "{y_scale.map(tick):.1}",
+                        }
+                        text {
+                            class: "scatter-chart-label",
+                            x: "{MARGIN_LEFT - 6.0}",
+                            y: "{y_scale.map(tick) + 4.0:.1}",
+                            text_anchor: "end",
+                            "{format_axis_value(money, y, tick)}"
+                        }
+                    }
+
+                    // Axis titles
+                    text {
+                        class: "scatter-chart-title",
+                        x: "{(MARGIN_LEFT + WIDTH - MARGIN_RIGHT) / 2.0}",
+                        y: "{HEIGHT - 4.0}",
+                        text_anchor: "middle",
+                        "{x_label}"
+                    }
+                    text {
+                        class: "scatter-chart-title",
+                        x: "14",
+                        y: "{(MARGIN_TOP + axis_bottom) / 2.0}",
+                        text_anchor: "middle",
+                        transform: "rotate(-90 14 {(MARGIN_TOP + axis_bottom) / 2.0})",
+                        "{y_label}"
+                    }
+
+                    // Points (click opens the model details)
+                    for (x_value, y_value, model) in placed.iter() {
+                        {
+                            let model_clone = (*model).clone();
+                            let color = provider_color(model.provider().unwrap_or_default());
+                            rsx! {
+                                circle {
+                                    key: "{model.id}",
+                                    class: "scatter-chart-point",
+                                    cx: "{x_scale.map(*x_value):.1}",
+                                    cy: "{y_scale.map(*y_value):.1}",
+                                    r: "5",
+                                    fill: "{color}",
+                                    onclick: move |_| on_select.call(model_clone.clone()),
+                                    title {
+                                        "{model.name}\n{x_label}: {format_point_value(money, x, model, *x_value)}\n{y_label}: {format_point_value(money, y, model, *y_value)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Provider legend
+                div {
+                    class: "scatter-chart-legend",
+                    for provider in providers {
+                        span {
+                            class: "price-chart-legend-item",
+                            span {
+                                class: "price-chart-swatch",
+                                style: "background: {provider_color(provider)};",
+                            }
+                            "{provider}"
+                        }
+                    }
+                }
+            } else {
+                div { class: "price-chart-note", { t!("chart-no-points") } }
+            }
+
+            if hidden > 0 {
+                div { class: "price-chart-note", { t!("chart-hidden-models", count: hidden) } }
+            }
+        }
+    }
+}
+
+/// Drop-down choosing the quantity plotted on one axis
+#[component]
+fn AxisSelect(label: String, axis: Signal<ChartAxis>) -> Element {
+    rsx! {
+        label {
+            class: "scatter-chart-axis-select",
+            "{label}"
+            select {
+                class: "sort-field-select",
+                onchange: move |evt| {
+                    if let Some(selected) = evt.value().parse::<usize>().ok()
+                        .and_then(|index| ChartAxis::ALL.get(index))
+                    {
+                        axis.set(*selected);
+                    }
+                },
+                for (index, option_axis) in ChartAxis::ALL.into_iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *axis.read() == option_axis,
+                        { t!(option_axis.label_key()) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Human-readable tick value for an axis (prices are already per price unit)
+fn format_axis_value(money: Money, axis: ChartAxis, value: f64) -> String {
+    match axis {
+        ChartAxis::PromptPrice | ChartAxis::CompletionPrice => {
+            let price = Decimal::from_f64(value).unwrap_or_default();
+            money.format(price.round_sf(4).unwrap_or(price))
+        }
+        ChartAxis::ContextLength => money.locale().integer(value.round() as u64),
+        ChartAxis::Created => time::OffsetDateTime::from_unix_timestamp(value as i64)
+            .map(|created| money.locale().utc_date(&created))
+            .unwrap_or_default(),
+    }
+}
+
+/// Tooltip value of a point; prices are formatted from the exact catalog price
+fn format_point_value(money: Money, axis: ChartAxis, model: &Model, value: f64) -> String {
+    match axis {
+        ChartAxis::PromptPrice => money.per_unit(model.pricing.prompt),
+        ChartAxis::CompletionPrice => money.per_unit(model.pricing.completion),
+        ChartAxis::ContextLength | ChartAxis::Created => format_axis_value(money, axis, value),
+    }
+}
@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::utils::Shortcut;
+
+use super::dialog::use_dialog_focus;
+
+/// Cheat sheet listing every keyboard shortcut
+#[component]
+pub fn ShortcutsHelp(on_close: EventHandler<()>) -> Element {
+    use_dialog_focus("shortcuts-help");
+
+    rsx! {
+        div {
+            class: "modal-overlay",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "shortcuts-help",
+                class: "modal-content shortcuts-help",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "shortcuts-help-title",
+                tabindex: -1,
+                onclick: move |evt| evt.stop_propagation(),
+
+                div {
+                    class: "modal-header",
+                    h2 { id: "shortcuts-help-title", class: "modal-title", { t!("shortcuts-title") } }
+                    button {
+                        class: "modal-close",
+                        aria_label: t!("button-close"),
+                        onclick: move |_| on_close.call(()),
+                        "×"
+                    }
+                }
+
+                div {
+                    class: "modal-body",
+                    div {
+                        class: "modal-grid",
+                        for shortcut in Shortcut::ALL {
+                            span {
+                                class: "shortcut-keys",
+                                for (index, key) in shortcut.keys().iter().enumerate() {
+                                    if index > 0 { " / " }
+                                    kbd { "{key}" }
+                                }
+                            }
+                            span { class: "modal-value", { t!(shortcut.label_key()) } }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
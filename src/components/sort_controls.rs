@@ -1,76 +1,97 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
-use crate::models::{SortDirection, SortField};
+use crate::models::{SortDirection, SortField, SortKey};
 
 #[component]
-pub fn SortControls(
-    sort_field: Signal<SortField>,
-    sort_direction: Signal<SortDirection>,
-) -> Element {
+pub fn SortControls(sort_keys: Signal<Vec<SortKey>>) -> Element {
+    let keys = sort_keys.read().clone();
+
     rsx! {
         div {
             class: "sort-controls-container",
 
-            // Sort field selector (segmented control)
-            div {
-                class: "sort-field-group",
+            for (level, key) in keys.iter().copied().enumerate() {
+                div {
+                    key: "{level}",
+                    class: "sort-key-row",
 
-                button {
-                    class: if *sort_field.read() == SortField::Name {
-                        "sort-field-button active"
-                    } else {
-                        "sort-field-button"
-                    },
-                    onclick: move |_| sort_field.set(SortField::Name),
-                    { t!("sort-name") }
-                }
+                    span {
+                        class: "sort-key-label",
+                        if level == 0 {
+                            { t!("sort-by") }
+                        } else {
+                            { t!("sort-then-by") }
+                        }
+                    }
 
-                button {
-                    class: if *sort_field.read() == SortField::Created {
-                        "sort-field-button active"
-                    } else {
-                        "sort-field-button"
-                    },
-                    onclick: move |_| sort_field.set(SortField::Created),
-                    { t!("sort-created") }
-                }
+                    // Sort field selector
+                    select {
+                        class: "sort-field-select",
+                        aria_label: if level == 0 { t!("sort-by") } else { t!("sort-then-by") },
+                        onchange: move |evt| {
+                            if let Some(field) = evt.value().parse::<usize>().ok().and_then(|i| SortField::ALL.get(i)) {
+                                if let Some(key) = sort_keys.write().get_mut(level) {
+                                    key.field = *field;
+                                }
+                            }
+                        },
+                        for (index, field) in SortField::ALL.iter().enumerate() {
+                            option {
+                                value: "{index}",
+                                selected: *field == key.field,
+                                { t!(field.label_key()) }
+                            }
+                        }
+                    }
 
-                button {
-                    class: if *sort_field.read() == SortField::PromptPrice {
-                        "sort-field-button active"
-                    } else {
-                        "sort-field-button"
-                    },
-                    onclick: move |_| sort_field.set(SortField::PromptPrice),
-                    { t!("sort-prompt-price") }
-                }
+                    // Sort direction toggle
+                    button {
+                        class: "sort-direction-button",
+                        onclick: move |_| {
+                            if let Some(key) = sort_keys.write().get_mut(level) {
+                                key.direction = key.direction.reversed();
+                            }
+                        },
+                        if key.direction == SortDirection::Ascending {
+                            { t!("sort-ascending") }
+                        } else {
+                            { t!("sort-descending") }
+                        }
+                    }
 
-                button {
-                    class: if *sort_field.read() == SortField::CompletionPrice {
-                        "sort-field-button active"
-                    } else {
-                        "sort-field-button"
-                    },
-                    onclick: move |_| sort_field.set(SortField::CompletionPrice),
-                    { t!("sort-completion-price") }
+                    // Secondary keys can be removed, the primary one always stays
+                    if level > 0 {
+                        button {
+                            class: "sort-remove-button",
+                            title: "{t!(\"sort-remove-key\")}",
+                            aria_label: "{t!(\"sort-remove-key\")}",
+                            onclick: move |_| {
+                                let mut keys = sort_keys.write();
+                                if level < keys.len() {
+                                    keys.remove(level);
+                                }
+                            },
+                            "×"
+                        }
+                    }
                 }
             }
 
-            // Sort direction toggle
-            button {
-                class: "sort-direction-button",
-                onclick: move |_| {
-                    let current = *sort_direction.read();
-                    sort_direction.set(match current {
-                        SortDirection::Ascending => SortDirection::Descending,
-                        SortDirection::Descending => SortDirection::Ascending,
-                    });
-                },
-                if *sort_direction.read() == SortDirection::Ascending {
-                    { t!("sort-ascending") }
-                } else {
-                    { t!("sort-descending") }
+            if keys.len() < SortKey::MAX_KEYS {
+                button {
+                    class: "sort-add-button",
+                    onclick: move |_| {
+                        let mut keys = sort_keys.write();
+                        // Offer the first field that is not sorted on yet
+                        if let Some(field) = SortField::ALL
+                            .into_iter()
+                            .find(|field| keys.iter().all(|key| key.field != *field))
+                        {
+                            keys.push(SortKey::new(field, SortDirection::Ascending));
+                        }
+                    },
+                    { t!("sort-add-key") }
                 }
             }
         }
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::Model;
+
+/// `<title>` and description of a pre-rendered page: the catalog, or the page of `model`
+#[component]
+pub fn PageHead(model: Option<Model>) -> Element {
+    match model {
+        Some(model) => rsx! {
+            title { { plain(t!("static-page-title", name: model.name.clone())) } }
+            meta {
+                name: "description",
+                content: plain(t!("static-model-description", name: model.name.clone())),
+            }
+        },
+        None => rsx! {
+            title { { t!("app-title") } }
+            meta { name: "description", content: t!("app-subtitle") }
+        },
+    }
+}
+
+/// Text without the Unicode isolation marks Fluent puts around arguments, which search results
+/// would show verbatim
+fn plain(text: String) -> String {
+    text.replace(['\u{2068}', '\u{2069}'], "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::ApiResponse;
+
+    fn snapshot() -> Vec<Model> {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        response.data
+    }
+
+    #[test]
+    fn test_page_head_describes_the_model() {
+        let html = render_to_html(|| {
+            let model = snapshot().remove(0);
+            rsx! { PageHead { model: model } }
+        });
+        let model = snapshot().remove(0);
+        assert!(html.starts_with("<title>"), "{html}");
+        assert!(
+            html.contains(&format!("<title>{} · Polza AI Models</title>", model.name)),
+            "{html}"
+        );
+        assert!(html.contains(r#"<meta name="description""#), "{html}");
+
+        let html = render_to_html(|| rsx! { PageHead {} });
+        assert!(html.contains("<title>Polza AI Models</title>"), "{html}");
+    }
+}
@@ -5,6 +5,191 @@ pub fn GlobalStyles() -> Element {
     rsx! {
         style {
             "
+            /* Themes: light is the default, auto follows prefers-color-scheme */
+            body {{
+                margin: 0;
+            }}
+
+            .theme-root {{
+                --color-bg: white;
+                --color-surface: white;
+                --color-surface-alt: #f8f9fa;
+                --color-surface-muted: #ecf0f1;
+                --color-surface-hover: #f0f0f0;
+                --color-border: #e0e0e0;
+                --color-border-strong: #bdc3c7;
+                --color-text: #2c3e50;
+                --color-text-secondary: #34495e;
+                --color-text-muted: #7f8c8d;
+                --color-text-faint: #95a5a6;
+                --color-accent: #3498db;
+                --color-accent-hover: #2980b9;
+                --color-accent-active: #21618c;
+                --color-accent-soft: #eaf4fc;
+                --color-on-accent: white;
+                --color-success: #27ae60;
+                --color-new: #2ecc71;
+                --color-new-soft: #f4fbf6;
+                --color-danger: #e74c3c;
+                --color-warning: #e67e22;
+                --color-favorite: #f1c40f;
+                --color-favorite-strong: #f39c12;
+                --color-highlight: #9b59b6;
+                --color-provider: #8e44ad;
+                --color-shadow: rgba(0, 0, 0, 0.1);
+                --color-shadow-strong: rgba(0, 0, 0, 0.3);
+                --color-overlay: rgba(0, 0, 0, 0.6);
+                --color-modality-text: #3498db;
+                --color-modality-image: #9b59b6;
+                --color-modality-file: #e67e22;
+                --color-modality-embeddings: #1abc9c;
+                --color-modality-audio: #e74c3c;
+                --color-modality-video: #f1c40f;
+                color-scheme: light;
+                min-height: 100vh;
+                background: var(--color-bg);
+                color: var(--color-text);
+            }}
+
+            .theme-root[data-theme=dark] {{
+                --color-bg: #121417;
+                --color-surface: #1e2228;
+                --color-surface-alt: #181b20;
+                --color-surface-muted: #2a2f37;
+                --color-surface-hover: #2f353e;
+                --color-border: #3a414b;
+                --color-border-strong: #4d5560;
+                --color-text: #e6e9ed;
+                --color-text-secondary: #c9d1d9;
+                --color-text-muted: #9aa5b1;
+                --color-text-faint: #7d8794;
+                --color-accent: #4ea3e0;
+                --color-accent-hover: #6bb4e8;
+                --color-accent-active: #2f86c8;
+                --color-accent-soft: #1d3346;
+                --color-on-accent: white;
+                --color-success: #3ecf7a;
+                --color-new: #2ecc71;
+                --color-new-soft: #1a2e22;
+                --color-danger: #ff6b5b;
+                --color-warning: #f0913a;
+                --color-favorite: #f1c40f;
+                --color-favorite-strong: #f39c12;
+                --color-highlight: #b07cc6;
+                --color-provider: #a569bd;
+                --color-shadow: rgba(0, 0, 0, 0.4);
+                --color-shadow-strong: rgba(0, 0, 0, 0.6);
+                --color-overlay: rgba(0, 0, 0, 0.7);
+                color-scheme: dark;
+            }}
+
+            @media (prefers-color-scheme: dark) {{
+                .theme-root[data-theme=auto] {{
+                    --color-bg: #121417;
+                    --color-surface: #1e2228;
+                    --color-surface-alt: #181b20;
+                    --color-surface-muted: #2a2f37;
+                    --color-surface-hover: #2f353e;
+                    --color-border: #3a414b;
+                    --color-border-strong: #4d5560;
+                    --color-text: #e6e9ed;
+                    --color-text-secondary: #c9d1d9;
+                    --color-text-muted: #9aa5b1;
+                    --color-text-faint: #7d8794;
+                    --color-accent: #4ea3e0;
+                    --color-accent-hover: #6bb4e8;
+                    --color-accent-active: #2f86c8;
+                    --color-accent-soft: #1d3346;
+                    --color-on-accent: white;
+                    --color-success: #3ecf7a;
+                    --color-new: #2ecc71;
+                    --color-new-soft: #1a2e22;
+                    --color-danger: #ff6b5b;
+                    --color-warning: #f0913a;
+                    --color-favorite: #f1c40f;
+                    --color-favorite-strong: #f39c12;
+                    --color-highlight: #b07cc6;
+                    --color-provider: #a569bd;
+                    --color-shadow: rgba(0, 0, 0, 0.4);
+                    --color-shadow-strong: rgba(0, 0, 0, 0.6);
+                    --color-overlay: rgba(0, 0, 0, 0.7);
+                    color-scheme: dark;
+                }}
+            }}
+
+            .theme-root[data-theme=high-contrast] {{
+                --color-bg: black;
+                --color-surface: black;
+                --color-surface-alt: black;
+                --color-surface-muted: #1a1a1a;
+                --color-surface-hover: #333;
+                --color-border: white;
+                --color-border-strong: white;
+                --color-text: white;
+                --color-text-secondary: white;
+                --color-text-muted: #e0e0e0;
+                --color-text-faint: #d0d0d0;
+                --color-accent: #ffd400;
+                --color-accent-hover: #ffe34d;
+                --color-accent-active: #ffea80;
+                --color-accent-soft: #332b00;
+                --color-on-accent: black;
+                --color-success: #4dff88;
+                --color-new: #4dff88;
+                --color-new-soft: #002b12;
+                --color-danger: #ff6666;
+                --color-warning: #ffb84d;
+                --color-favorite: #ffd400;
+                --color-favorite-strong: #ffd400;
+                --color-highlight: #ffd400;
+                --color-provider: #e0a0ff;
+                --color-shadow: transparent;
+                --color-shadow-strong: transparent;
+                --color-overlay: rgba(0, 0, 0, 0.85);
+                --color-modality-text: #66c2ff;
+                --color-modality-image: #e0a0ff;
+                --color-modality-file: #ffb84d;
+                --color-modality-embeddings: #4dffdb;
+                --color-modality-audio: #ff6666;
+                --color-modality-video: #ffd400;
+                color-scheme: dark;
+            }}
+
+            .theme-switcher {{
+                padding: 5px 8px;
+                font-size: 12px;
+                background: var(--color-surface);
+                color: var(--color-text);
+                border: 1px solid var(--color-border);
+                border-radius: 4px;
+                margin-left: 8px;
+                cursor: pointer;
+            }}
+
+            .currency-switcher {{
+                display: inline-flex;
+                align-items: center;
+                flex-wrap: wrap;
+                justify-content: center;
+                gap: 4px;
+                margin-top: 8px;
+                font-size: 12px;
+            }}
+
+            .currency-rate-input {{
+                width: 80px;
+                padding: 4px 6px;
+                font-size: 12px;
+                background: var(--color-surface);
+                color: var(--color-text);
+                border: 1px solid var(--color-border);
+                border-radius: 4px;
+            }}
+
+            .currency-rate-as-of {{
+                color: var(--color-text-faint);
+            }}
+
             .filter-input-container {{
                 position: relative;
                 display: flex;
@@ -16,7 +201,7 @@ pub fn GlobalStyles() -> Element {
                 padding: 12px;
                 padding-right: 40px;
                 font-size: 16px;
-                border: 2px solid #e0e0e0;
+                border: 2px solid var(--color-border);
                 border-radius: 6px;
                 box-sizing: border-box;
                 transition: border-color 0.2s;
@@ -24,7 +209,7 @@ pub fn GlobalStyles() -> Element {
 
             .filter-input:focus {{
                 outline: none;
-                border-color: #3498db;
+                border-color: var(--color-accent);
             }}
 
             .filter-clear-button {{
@@ -33,7 +218,7 @@ pub fn GlobalStyles() -> Element {
                 background: none;
                 border: none;
                 font-size: 24px;
-                color: #95a5a6;
+                color: var(--color-text-faint);
                 cursor: pointer;
                 padding: 4px 8px;
                 display: flex;
@@ -45,336 +230,1352 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .filter-clear-button:hover {{
-                background: #f0f0f0;
-                color: #2c3e50;
+                background: var(--color-surface-hover);
+                color: var(--color-text);
             }}
 
             .model-item {{
-                background: white;
+                background: var(--color-surface);
                 padding: 16px 20px;
                 margin-bottom: 12px;
                 border-radius: 8px;
-                border-left: 4px solid #3498db;
+                border-left: 4px solid var(--color-accent);
                 transition: transform 0.2s, box-shadow 0.2s;
                 cursor: pointer;
             }}
 
             .model-item:hover {{
                 transform: translateX(4px);
-                box-shadow: 0 4px 12px rgba(0,0,0,0.1);
+                box-shadow: 0 4px 12px var(--color-shadow);
             }}
 
             .model-name {{
                 font-size: 18px;
                 font-weight: 600;
-                color: #2c3e50;
+                color: var(--color-text);
                 margin-bottom: 8px;
             }}
 
-            .modality-badges {{
-                display: flex;
-                gap: 6px;
-                flex-wrap: wrap;
-                margin-bottom: 10px;
+            /* Model names link to the pre-rendered model pages */
+            a.model-name {{
+                text-decoration: none;
+            }}
+
+            a.model-name:hover {{
+                text-decoration: underline;
+            }}
+
+            .model-item.favorite {{
+                border-left-color: var(--color-favorite);
+            }}
+
+            .model-item.new {{
+                background: var(--color-new-soft);
+            }}
+
+            .new-badge {{
+                margin-right: auto;
+                padding: 2px 6px;
+                border-radius: 4px;
+                background: var(--color-new);
+                color: var(--color-on-accent);
+                font-size: 10px;
+                font-weight: 700;
+                letter-spacing: 0.5px;
+            }}
+
+            .model-name-row {{
+                display: flex;
+                align-items: flex-start;
+                justify-content: space-between;
+                gap: 8px;
+            }}
+
+            .favorite-button {{
+                background: none;
+                border: none;
+                font-size: 20px;
+                line-height: 1;
+                color: var(--color-border-strong);
+                cursor: pointer;
+                padding: 2px 4px;
+                border-radius: 4px;
+                transition: color 0.2s, transform 0.2s;
+            }}
+
+            .favorite-button:hover {{
+                color: var(--color-favorite-strong);
+                transform: scale(1.15);
+            }}
+
+            .favorite-button.active {{
+                color: var(--color-favorite);
+            }}
+
+            .model-table-name .favorite-button {{
+                font-size: 15px;
+                margin-right: 4px;
+            }}
+
+            .modal-title-row {{
+                display: flex;
+                align-items: center;
+                gap: 8px;
+            }}
+
+            .modality-toggle-button.active.favorites {{
+                background: var(--color-favorite-strong);
+            }}
+
+            .modality-toggle-button.active.recent {{
+                background: var(--color-new);
+            }}
+
+            .new-badge-window {{
+                display: flex;
+                align-items: center;
+                gap: 8px;
+                margin-top: 8px;
+                font-size: 13px;
+                color: var(--color-text-muted);
+            }}
+
+            .favorites-panel {{
+                background: var(--color-surface);
+                border: 2px solid var(--color-border);
+                border-radius: 8px;
+                padding: 10px 16px;
+                margin-bottom: 20px;
+            }}
+
+            .favorites-panel-summary {{
+                cursor: pointer;
+                font-weight: 600;
+                font-size: 14px;
+                color: var(--color-text-secondary);
+            }}
+
+            .favorites-panel-body {{
+                margin-top: 12px;
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+            }}
+
+            .favorites-json {{
+                width: 100%;
+                box-sizing: border-box;
+                font-family: 'Monaco', 'Consolas', monospace;
+                font-size: 12px;
+                padding: 8px;
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                resize: vertical;
+            }}
+
+            .favorites-panel-actions {{
+                display: flex;
+                gap: 8px;
+            }}
+
+            .favorites-status {{
+                font-size: 13px;
+                color: var(--color-success);
+            }}
+
+            .favorites-status.error {{
+                color: var(--color-danger);
+            }}
+
+            .token-counter-summary {{
+                display: flex;
+                flex-direction: column;
+                gap: 6px;
+                font-size: 13px;
+                color: var(--color-text-secondary);
+            }}
+
+            .token-counts {{
+                list-style: none;
+                margin: 0;
+                padding: 0;
+                display: grid;
+                grid-template-columns: max-content max-content max-content;
+                gap: 4px 12px;
+            }}
+
+            .token-counts li {{
+                display: contents;
+            }}
+
+            .token-count-family {{
+                font-weight: 600;
+            }}
+
+            .token-count-value {{
+                font-variant-numeric: tabular-nums;
+                text-align: right;
+            }}
+
+            .token-count-kind {{
+                font-size: 11px;
+                color: var(--color-text-faint);
+                text-transform: uppercase;
+            }}
+
+            .token-count-kind.exact {{
+                color: var(--color-success);
+            }}
+
+            .price-chart-svg {{
+                width: 100%;
+                height: auto;
+                display: block;
+            }}
+
+            .price-chart-axis {{
+                stroke: var(--color-border-strong);
+                stroke-width: 1;
+            }}
+
+            .price-chart-label {{
+                font-size: 11px;
+                fill: var(--color-text-muted);
+            }}
+
+            .price-chart-line {{
+                stroke-width: 2;
+            }}
+
+            .price-chart-line.prompt,
+            .price-chart-point.prompt {{ stroke: var(--color-accent); }}
+            .price-chart-line.completion,
+            .price-chart-point.completion {{ stroke: var(--color-success); }}
+            .price-chart-point.prompt,
+            .price-chart-swatch.prompt {{ fill: var(--color-accent); background: var(--color-accent); }}
+            .price-chart-point.completion,
+            .price-chart-swatch.completion {{ fill: var(--color-success); background: var(--color-success); }}
+
+            .price-chart-point {{
+                cursor: pointer;
+            }}
+
+            .price-chart-legend {{
+                display: flex;
+                gap: 16px;
+                font-size: 13px;
+                color: var(--color-text-muted);
+            }}
+
+            .price-chart-legend-item {{
+                display: flex;
+                align-items: center;
+                gap: 6px;
+            }}
+
+            .price-chart-swatch {{
+                width: 12px;
+                height: 12px;
+                border-radius: 2px;
+            }}
+
+            .price-chart-note {{
+                font-size: 13px;
+                color: var(--color-text-faint);
+                margin-top: 6px;
+            }}
+
+            .scatter-chart-controls {{
+                display: flex;
+                align-items: center;
+                gap: 16px;
+                flex-wrap: wrap;
+                margin-bottom: 12px;
+            }}
+
+            .scatter-chart-axis-select {{
+                display: flex;
+                align-items: center;
+                gap: 8px;
+                font-size: 13px;
+                font-weight: 600;
+                color: var(--color-text-secondary);
+            }}
+
+            .scatter-chart-note {{
+                font-size: 12px;
+                color: var(--color-text-faint);
+            }}
+
+            .scatter-chart-svg {{
+                width: 100%;
+                height: auto;
+                display: block;
+                background: var(--color-surface);
+                border-radius: 8px;
+            }}
+
+            .scatter-chart-grid {{
+                stroke: var(--color-border);
+                stroke-width: 1;
+            }}
+
+            .scatter-chart-label {{
+                font-size: 11px;
+                fill: var(--color-text-muted);
+            }}
+
+            .scatter-chart-title {{
+                font-size: 12px;
+                font-weight: 600;
+                fill: var(--color-text-secondary);
+            }}
+
+            .scatter-chart-point {{
+                fill-opacity: 0.8;
+                stroke: var(--color-surface);
+                stroke-width: 1;
+                cursor: pointer;
+            }}
+
+            .scatter-chart-point:hover {{
+                fill-opacity: 1;
+                stroke: var(--color-text);
+                stroke-width: 2;
+            }}
+
+            .scatter-chart-legend {{
+                display: flex;
+                flex-wrap: wrap;
+                gap: 8px 16px;
+                margin-top: 10px;
+                font-size: 12px;
+                color: var(--color-text-muted);
+            }}
+
+            .page-tabs {{
+                display: inline-flex;
+                margin-bottom: 16px;
+            }}
+
+            .modality-toggle-button.active.provider {{
+                background: var(--color-provider);
+            }}
+
+            .price-spread {{
+                display: flex;
+                flex-direction: column;
+                align-items: flex-end;
+                font-size: 12px;
+                color: var(--color-text-muted);
+            }}
+
+            .price-spread-median {{
+                font-weight: 600;
+                color: var(--color-text);
+            }}
+
+            .provider-newest-link {{
+                background: none;
+                border: none;
+                padding: 0;
+                color: var(--color-accent);
+                cursor: pointer;
+                text-align: left;
+                font-size: inherit;
+            }}
+
+            .provider-newest-link:hover {{
+                text-decoration: underline;
+            }}
+
+            .modality-badges {{
+                display: flex;
+                gap: 6px;
+                flex-wrap: wrap;
+                margin-bottom: 10px;
+            }}
+
+            .modality-badge {{
+                display: inline-block;
+                padding: 4px 10px;
+                font-size: 12px;
+                font-weight: 500;
+                border-radius: 12px;
+                background: var(--color-surface-muted);
+                color: var(--color-text);
+            }}
+
+            .modality-badge.text {{ background: var(--color-modality-text); color: var(--color-on-accent); }}
+            .modality-badge.image {{ background: var(--color-modality-image); color: var(--color-on-accent); }}
+            .modality-badge.file {{ background: var(--color-modality-file); color: var(--color-on-accent); }}
+            .modality-badge.embeddings {{ background: var(--color-modality-embeddings); color: var(--color-on-accent); }}
+            .modality-badge.audio {{ background: var(--color-modality-audio); color: var(--color-on-accent); }}
+            .modality-badge.video {{ background: var(--color-modality-video); color: var(--color-on-accent); }}
+
+            .modality-badge-outline {{
+                display: inline-block;
+                padding: 4px 10px;
+                font-size: 12px;
+                font-weight: 500;
+                border-radius: 12px;
+                background: transparent;
+                border: 2px solid var(--color-border-strong);
+                color: var(--color-text-muted);
+            }}
+
+            .modality-badge-outline.text {{ border-color: var(--color-modality-text); color: var(--color-modality-text); }}
+            .modality-badge-outline.image {{ border-color: var(--color-modality-image); color: var(--color-modality-image); }}
+            .modality-badge-outline.file {{ border-color: var(--color-modality-file); color: var(--color-modality-file); }}
+            .modality-badge-outline.embeddings {{ border-color: var(--color-modality-embeddings); color: var(--color-modality-embeddings); }}
+            .modality-badge-outline.audio {{ border-color: var(--color-modality-audio); color: var(--color-modality-audio); }}
+            .modality-badge-outline.video {{ border-color: var(--color-modality-video); color: var(--color-modality-video); }}
+
+            .modality-separator {{
+                display: inline-flex;
+                align-items: center;
+                justify-content: center;
+                padding: 4px 8px;
+                font-size: 14px;
+                font-weight: 700;
+                border-radius: 10px;
+                background: var(--color-surface-muted);
+                color: var(--color-text-muted);
+                margin: 0 4px;
+            }}
+
+            .whats-new-panel {{
+                background: var(--color-surface);
+                border: 2px solid var(--color-border);
+                border-left: 4px solid var(--color-highlight);
+                border-radius: 8px;
+                padding: 10px 16px;
+                margin-bottom: 20px;
+            }}
+
+            .whats-new-summary {{
+                cursor: pointer;
+                font-weight: 600;
+                font-size: 14px;
+                color: var(--color-text-secondary);
+            }}
+
+            .whats-new-body {{
+                margin-top: 10px;
+                max-height: 360px;
+                overflow-y: auto;
+            }}
+
+            .whats-new-period,
+            .whats-new-empty {{
+                font-size: 13px;
+                color: var(--color-text-muted);
+                margin-bottom: 8px;
+            }}
+
+            .whats-new-heading {{
+                font-size: 13px;
+                font-weight: 700;
+                text-transform: uppercase;
+                letter-spacing: 0.5px;
+                margin: 12px 0 6px;
+            }}
+
+            .whats-new-heading.added {{ color: var(--color-success); }}
+            .whats-new-heading.removed {{ color: var(--color-danger); }}
+            .whats-new-heading.changed {{ color: var(--color-warning); }}
+
+            .whats-new-list {{
+                list-style: none;
+                padding: 0;
+                margin: 0;
+            }}
+
+            .whats-new-item {{
+                padding: 6px 8px;
+                border-radius: 4px;
+                font-size: 13px;
+                color: var(--color-text);
+            }}
+
+            .whats-new-item.clickable {{
+                cursor: pointer;
+            }}
+
+            .whats-new-item.clickable:hover {{
+                background: var(--color-surface-alt);
+            }}
+
+            .whats-new-model-name {{
+                font-weight: 600;
+                margin-bottom: 2px;
+            }}
+
+            .whats-new-price-change {{
+                font-size: 12px;
+                padding-left: 12px;
+            }}
+
+            .price-old {{
+                font-family: 'Monaco', 'Consolas', monospace;
+                color: var(--color-text-faint);
+                text-decoration: line-through;
+            }}
+
+            .price-new {{
+                font-family: 'Monaco', 'Consolas', monospace;
+                font-weight: 600;
+            }}
+
+            .price-new.up {{ color: var(--color-danger); }}
+            .price-new.down {{ color: var(--color-success); }}
+
+            .preset-bar {{
+                display: flex;
+                flex-wrap: wrap;
+                gap: 8px;
+                margin-bottom: 12px;
+            }}
+
+            .preset-chip {{
+                display: inline-flex;
+                align-items: center;
+                gap: 2px;
+                border: 2px solid var(--color-border);
+                border-radius: 16px;
+                background: var(--color-surface);
+                padding: 2px 4px 2px 2px;
+            }}
+
+            .preset-chip.active {{
+                border-color: var(--color-accent);
+                background: var(--color-accent-soft);
+            }}
+
+            .preset-chip-apply {{
+                background: none;
+                border: none;
+                padding: 4px 8px;
+                font-size: 13px;
+                font-weight: 600;
+                color: var(--color-text);
+                cursor: pointer;
+            }}
+
+            .preset-chip-action {{
+                background: none;
+                border: none;
+                padding: 2px 6px;
+                font-size: 14px;
+                color: var(--color-text-faint);
+                cursor: pointer;
+                border-radius: 10px;
+                line-height: 1;
+            }}
+
+            .preset-chip-action:hover {{
+                background: var(--color-surface-hover);
+                color: var(--color-text);
+            }}
+
+            .preset-name-input {{
+                border: none;
+                outline: none;
+                padding: 4px 8px;
+                font-size: 13px;
+                min-width: 160px;
+                background: transparent;
+            }}
+
+            .preset-name-input.invalid {{
+                color: var(--color-danger);
+                text-decoration: underline wavy var(--color-danger);
+            }}
+
+            .preset-add-button {{
+                background: none;
+                border: 2px dashed var(--color-border);
+                border-radius: 16px;
+                padding: 4px 12px;
+                font-size: 13px;
+                font-weight: 600;
+                color: var(--color-text-muted);
+                cursor: pointer;
+            }}
+
+            .preset-add-button:hover {{
+                border-color: var(--color-accent);
+                color: var(--color-accent);
+            }}
+
+            .modality-filter-section {{
+                background: var(--color-surface);
+                padding: 16px;
+                border-radius: 8px;
+                border: 2px solid var(--color-border);
+                margin-bottom: 20px;
+            }}
+
+            .modality-filter-group {{
+                margin-bottom: 16px;
+            }}
+
+            .modality-filter-group:last-child {{
+                margin-bottom: 0;
+            }}
+
+            .modality-filter-label {{
+                font-weight: 600;
+                color: var(--color-text-secondary);
+                margin-bottom: 8px;
+                font-size: 14px;
+                display: block;
+            }}
+
+            .modality-toggles {{
+                display: flex;
+                gap: 8px;
+                flex-wrap: wrap;
+            }}
+
+            .modality-toggle-button {{
+                padding: 8px 16px;
+                font-size: 13px;
+                font-weight: 600;
+                border: 2px solid var(--color-border);
+                border-radius: 20px;
+                background: var(--color-surface-alt);
+                color: var(--color-text-muted);
+                cursor: pointer;
+                transition: all 0.2s;
+                user-select: none;
+            }}
+
+            .modality-toggle-button:hover {{
+                border-color: var(--color-border-strong);
+                transform: translateY(-1px);
+                box-shadow: 0 2px 4px var(--color-shadow);
+            }}
+
+            .modality-toggle-button.active {{
+                color: var(--color-on-accent);
+                border-color: transparent;
+            }}
+
+            .modality-toggle-button.active.text {{
+                background: var(--color-modality-text);
+            }}
+
+            .modality-toggle-button.active.image {{
+                background: var(--color-modality-image);
+            }}
+
+            .modality-toggle-button.active.file {{
+                background: var(--color-modality-file);
+            }}
+
+            .modality-toggle-button.active.embeddings {{
+                background: var(--color-modality-embeddings);
+            }}
+
+            .modality-toggle-button.active.audio {{
+                background: var(--color-modality-audio);
+            }}
+
+            .modality-toggle-button.active.video {{
+                background: var(--color-modality-video);
+            }}
+
+            .sort-controls-container {{
+                display: flex;
+                flex-direction: column;
+                align-items: flex-start;
+                gap: 8px;
+                margin-bottom: 16px;
+            }}
+
+            .sort-key-row {{
+                display: flex;
+                align-items: center;
+                gap: 8px;
+                flex-wrap: wrap;
+            }}
+
+            .sort-key-label {{
+                font-size: 13px;
+                font-weight: 600;
+                color: var(--color-text-muted);
+                min-width: 70px;
+            }}
+
+            .sort-field-select {{
+                padding: 7px 10px;
+                font-size: 13px;
+                font-weight: 600;
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                background: var(--color-surface-alt);
+                color: var(--color-text);
+                cursor: pointer;
+            }}
+
+            .sort-field-select:focus {{
+                outline: none;
+                border-color: var(--color-accent);
+            }}
+
+            .sort-remove-button {{
+                background: none;
+                border: none;
+                font-size: 20px;
+                color: var(--color-text-faint);
+                cursor: pointer;
+                padding: 2px 8px;
+                border-radius: 4px;
+                line-height: 1;
+            }}
+
+            .sort-remove-button:hover {{
+                background: var(--color-surface-hover);
+                color: var(--color-danger);
+            }}
+
+            .sort-add-button {{
+                background: none;
+                border: 2px dashed var(--color-border);
+                border-radius: 6px;
+                padding: 6px 12px;
+                font-size: 13px;
+                font-weight: 600;
+                color: var(--color-text-muted);
+                cursor: pointer;
+            }}
+
+            .sort-add-button:hover {{
+                border-color: var(--color-accent);
+                color: var(--color-accent);
+            }}
+
+            .sort-field-group {{
+                display: flex;
+                border-radius: 6px;
+                overflow: hidden;
+                border: 2px solid var(--color-border);
+            }}
+
+            .sort-field-button {{
+                padding: 8px 16px;
+                font-size: 13px;
+                font-weight: 600;
+                background: var(--color-surface-alt);
+                color: var(--color-text-muted);
+                border: none;
+                border-right: 1px solid var(--color-border);
+                cursor: pointer;
+                transition: all 0.2s;
+                user-select: none;
+                white-space: nowrap;
+            }}
+
+            .sort-field-button:last-child {{
+                border-right: none;
+            }}
+
+            /* Mobile responsive layout for sort buttons */
+            @media (max-width: 640px) {{
+                .sort-field-group {{
+                    flex-direction: column;
+                    width: 100%;
+                }}
+
+                .sort-field-button {{
+                    border-right: none;
+                    border-bottom: 1px solid var(--color-border);
+                    padding: 12px 16px;
+                    font-size: 14px;
+                }}
+
+                .sort-field-button:last-child {{
+                    border-bottom: none;
+                }}
+
+                .sort-direction-button {{
+                    width: 100%;
+                    justify-content: center;
+                    padding: 12px 16px;
+                    font-size: 14px;
+                }}
+            }}
+
+            .sort-field-button:hover {{
+                background: var(--color-surface-muted);
+            }}
+
+            .sort-field-button.active {{
+                background: var(--color-accent);
+                color: var(--color-on-accent);
+            }}
+
+            .sort-direction-button {{
+                padding: 8px 16px;
+                font-size: 13px;
+                font-weight: 600;
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                background: var(--color-surface-alt);
+                color: var(--color-text-muted);
+                cursor: pointer;
+                transition: all 0.2s;
+                user-select: none;
+                display: flex;
+                align-items: center;
+                gap: 6px;
+            }}
+
+            .sort-direction-button:hover {{
+                background: var(--color-surface-muted);
+                border-color: var(--color-border-strong);
+            }}
+
+            .view-controls-container {{
+                display: flex;
+                align-items: flex-start;
+                gap: 12px;
+                margin-bottom: 16px;
+                flex-wrap: wrap;
+            }}
+
+            .column-picker {{
+                position: relative;
+            }}
+
+            .column-picker summary {{
+                list-style: none;
+            }}
+
+            .column-picker summary::-webkit-details-marker {{
+                display: none;
+            }}
+
+            .column-picker-menu {{
+                position: absolute;
+                top: calc(100% + 4px);
+                left: 0;
+                z-index: 20;
+                background: var(--color-surface);
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                padding: 8px 12px;
+                box-shadow: 0 4px 12px var(--color-shadow);
+                display: flex;
+                flex-direction: column;
+                gap: 6px;
+                min-width: 220px;
+            }}
+
+            .column-picker-option {{
+                display: flex;
+                align-items: center;
+                gap: 8px;
+                font-size: 13px;
+                color: var(--color-text-secondary);
+                cursor: pointer;
+                white-space: nowrap;
+            }}
+
+            .model-table-container {{
+                max-height: 600px;
+                overflow: auto;
+                background: var(--color-surface);
+                border-radius: 8px;
+                border: 2px solid var(--color-border);
+            }}
+
+            .model-table {{
+                width: 100%;
+                border-collapse: collapse;
+                font-size: 13px;
+            }}
+
+            .model-table th {{
+                position: sticky;
+                top: 0;
+                background: var(--color-surface-alt);
+                color: var(--color-text-muted);
+                font-weight: 600;
+                text-align: left;
+                padding: 8px 10px;
+                border-bottom: 2px solid var(--color-border);
+                white-space: nowrap;
+                user-select: none;
+                z-index: 1;
+            }}
+
+            .model-table th.sortable {{
+                cursor: pointer;
+            }}
+
+            .model-table th.sortable:hover {{
+                background: var(--color-surface-muted);
+            }}
+
+            .model-table th.active {{
+                color: var(--color-accent);
+            }}
+
+            .model-table td {{
+                padding: 6px 10px;
+                border-bottom: 1px solid var(--color-surface-muted);
+                color: var(--color-text);
+                white-space: nowrap;
+            }}
+
+            .model-table tbody tr {{
+                cursor: pointer;
+                transition: background 0.2s;
+            }}
+
+            .model-table tbody tr:hover {{
+                background: var(--color-accent-soft);
+            }}
+
+            .model-table-name {{
+                font-weight: 600;
+            }}
+
+            .model-table .numeric-value {{
+                text-align: right;
+                font-family: 'Monaco', 'Consolas', monospace;
+            }}
+
+            .modality-badges.compact {{
+                flex-wrap: nowrap;
+                margin-bottom: 0;
+                gap: 4px;
+            }}
+
+            .modality-badges.compact .modality-badge,
+            .modality-badges.compact .modality-badge-outline {{
+                padding: 1px 6px;
+                font-size: 11px;
+            }}
+
+            .modality-badges.compact .modality-separator {{
+                padding: 1px 4px;
+                font-size: 11px;
+                margin: 0;
+            }}
+
+            .model-metadata {{
+                display: grid;
+                grid-template-columns: auto 1fr;
+                gap: 8px 12px;
+                font-size: 13px;
+                color: var(--color-text-secondary);
+                margin-bottom: 10px;
+            }}
+
+            .model-list-container {{
+                max-height: 600px;
+                overflow-y: auto;
+                overflow-x: hidden;
+                scroll-behavior: smooth;
+                padding-right: 4px;
+            }}
+
+            .model-list-container::-webkit-scrollbar {{
+                width: 8px;
+            }}
+
+            .model-list-container::-webkit-scrollbar-track {{
+                background: var(--color-surface-muted);
+                border-radius: 4px;
+            }}
+
+            .model-list-container::-webkit-scrollbar-thumb {{
+                background: var(--color-border-strong);
+                border-radius: 4px;
+            }}
+
+            .model-list-container::-webkit-scrollbar-thumb:hover {{
+                background: var(--color-text-faint);
+            }}
+
+            .metadata-label {{
+                font-weight: 600;
+                color: var(--color-text-muted);
+            }}
+
+            .metadata-value {{
+                color: var(--color-text);
+            }}
+
+            .price-value {{
+                font-family: 'Monaco', 'Consolas', monospace;
+                color: var(--color-success);
+                font-weight: 600;
+            }}
+
+            .canonical-slug {{
+                background: var(--color-surface-alt);
+                border: 1px solid var(--color-border);
+                border-radius: 4px;
+                padding: 8px 10px;
+                font-family: 'Monaco', 'Consolas', monospace;
+                font-size: 13px;
+                color: var(--color-text-secondary);
+                user-select: all;
+                cursor: text;
+                overflow-x: auto;
+            }}
+
+            .offline-indicator {{
+                padding: 4px 10px;
+                border-radius: 12px;
+                background: var(--color-warning);
+                color: var(--color-on-accent);
+                font-size: 13px;
+                font-weight: 600;
+                cursor: help;
+            }}
+
+            .cache-warning {{
+                display: flex;
+                align-items: center;
+                justify-content: space-between;
+                gap: 12px;
+                margin-bottom: 20px;
+                padding: 10px 14px;
+                border-left: 4px solid var(--color-warning);
+                border-radius: 6px;
+                background: var(--color-surface-alt);
+                color: var(--color-text);
+                font-size: 14px;
+            }}
+
+            .cache-warning-dismiss {{
+                border: none;
+                background: none;
+                color: var(--color-text-muted);
+                cursor: pointer;
+                font-size: 16px;
+            }}
+
+            .retry-button {{
+                padding: 10px 20px;
+                background: var(--color-accent);
+                color: var(--color-on-accent);
+                border: none;
+                border-radius: 6px;
+                cursor: pointer;
+                font-size: 14px;
+                font-weight: 600;
+                transition: background 0.2s;
+            }}
+
+            .retry-button:hover {{
+                background: var(--color-accent-hover);
             }}
 
-            .modality-badge {{
-                display: inline-block;
-                padding: 4px 10px;
-                font-size: 12px;
-                font-weight: 500;
-                border-radius: 12px;
-                background: #ecf0f1;
-                color: #2c3e50;
+            .retry-button:disabled {{
+                opacity: 0.5;
+                cursor: not-allowed;
             }}
 
-            .modality-badge.text {{ background: #3498db; color: white; }}
-            .modality-badge.image {{ background: #9b59b6; color: white; }}
-            .modality-badge.file {{ background: #e67e22; color: white; }}
-            .modality-badge.embeddings {{ background: #1abc9c; color: white; }}
-            .modality-badge.audio {{ background: #e74c3c; color: white; }}
-            .modality-badge.video {{ background: #f1c40f; color: white; }}
-
-            .modality-badge-outline {{
-                display: inline-block;
-                padding: 4px 10px;
-                font-size: 12px;
-                font-weight: 500;
-                border-radius: 12px;
-                background: transparent;
-                border: 2px solid #bdc3c7;
-                color: #7f8c8d;
+            .modal-tabs {{
+                margin: 0 24px;
             }}
 
-            .modality-badge-outline.text {{ border-color: #3498db; color: #3498db; }}
-            .modality-badge-outline.image {{ border-color: #9b59b6; color: #9b59b6; }}
-            .modality-badge-outline.file {{ border-color: #e67e22; color: #e67e22; }}
-            .modality-badge-outline.embeddings {{ border-color: #1abc9c; color: #1abc9c; }}
-            .modality-badge-outline.audio {{ border-color: #e74c3c; color: #e74c3c; }}
-            .modality-badge-outline.video {{ border-color: #f1c40f; color: #f1c40f; }}
-
-            .modality-separator {{
-                display: inline-flex;
-                align-items: center;
-                justify-content: center;
-                padding: 4px 8px;
-                font-size: 14px;
-                font-weight: 700;
-                border-radius: 10px;
-                background: #ecf0f1;
-                color: #7f8c8d;
-                margin: 0 4px;
+            .playground {{
+                display: flex;
+                flex-direction: column;
+                gap: 12px;
             }}
 
-            .modality-filter-section {{
-                background: white;
-                padding: 16px;
-                border-radius: 8px;
-                border: 2px solid #e0e0e0;
-                margin-bottom: 20px;
+            .playground-row {{
+                display: flex;
+                flex-direction: column;
+                gap: 6px;
             }}
 
-            .modality-filter-group {{
-                margin-bottom: 16px;
+            .playground-hint {{
+                font-size: 12px;
+                color: var(--color-text-faint);
             }}
 
-            .modality-filter-group:last-child {{
-                margin-bottom: 0;
+            .playground-advanced summary {{
+                cursor: pointer;
+                color: var(--color-text-muted);
+                font-size: 13px;
+                margin-bottom: 6px;
             }}
 
-            .modality-filter-label {{
-                font-weight: 600;
-                color: #34495e;
-                margin-bottom: 8px;
-                font-size: 14px;
-                display: block;
+            .playground-parameters {{
+                display: grid;
+                grid-template-columns: repeat(auto-fill, minmax(180px, 1fr));
+                gap: 8px 12px;
             }}
 
-            .modality-toggles {{
+            .playground-parameter {{
                 display: flex;
-                gap: 8px;
-                flex-wrap: wrap;
+                flex-direction: column;
+                gap: 4px;
             }}
 
-            .modality-toggle-button {{
-                padding: 8px 16px;
+            .playground-parameter-name {{
+                font-family: 'Monaco', 'Consolas', monospace;
+                font-size: 12px;
+                color: var(--color-text-secondary);
+            }}
+
+            .playground-parameter-input {{
+                padding: 6px 8px;
                 font-size: 13px;
-                font-weight: 600;
-                border: 2px solid #e0e0e0;
-                border-radius: 20px;
-                background: #f8f9fa;
-                color: #7f8c8d;
-                cursor: pointer;
-                transition: all 0.2s;
-                user-select: none;
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                background: var(--color-surface);
+                color: var(--color-text);
             }}
 
-            .modality-toggle-button:hover {{
-                border-color: #bdc3c7;
-                transform: translateY(-1px);
-                box-shadow: 0 2px 4px rgba(0,0,0,0.1);
+            .playground-parameter-input.invalid {{
+                border-color: var(--color-danger);
             }}
 
-            .modality-toggle-button.active {{
-                color: white;
-                border-color: transparent;
+            .playground-response {{
+                margin: 0;
+                padding: 12px;
+                min-height: 60px;
+                max-height: 320px;
+                overflow-y: auto;
+                white-space: pre-wrap;
+                word-break: break-word;
+                background: var(--color-surface-muted);
+                border-radius: 6px;
+                font-size: 13px;
             }}
 
-            .modality-toggle-button.active.text {{
-                background: #3498db;
+            .playground-status {{
+                font-size: 13px;
+                color: var(--color-text-muted);
             }}
 
-            .modality-toggle-button.active.image {{
-                background: #9b59b6;
+            .playground-error {{
+                color: var(--color-danger);
+                font-size: 12px;
             }}
 
-            .modality-toggle-button.active.file {{
-                background: #e67e22;
+            .arena-toggle {{
+                background: none;
+                border: 1px solid transparent;
+                font-size: 16px;
+                line-height: 1;
+                color: var(--color-border-strong);
+                cursor: pointer;
+                padding: 2px 4px;
+                border-radius: 4px;
             }}
 
-            .modality-toggle-button.active.embeddings {{
-                background: #1abc9c;
+            .arena-toggle:hover:not(:disabled) {{
+                color: var(--color-accent);
             }}
 
-            .modality-toggle-button.active.audio {{
-                background: #e74c3c;
+            .arena-toggle.active {{
+                color: var(--color-accent);
+                border-color: var(--color-accent);
             }}
 
-            .modality-toggle-button.active.video {{
-                background: #f1c40f;
+            .arena-toggle:disabled {{
+                cursor: not-allowed;
+                opacity: 0.4;
             }}
 
-            .sort-controls-container {{
+            .arena {{
                 display: flex;
-                align-items: center;
+                flex-direction: column;
                 gap: 12px;
-                margin-bottom: 16px;
-                flex-wrap: wrap;
             }}
 
-            .sort-field-group {{
+            .arena-selection {{
                 display: flex;
-                border-radius: 6px;
-                overflow: hidden;
-                border: 2px solid #e0e0e0;
+                flex-wrap: wrap;
+                align-items: center;
+                gap: 8px;
             }}
 
-            .sort-field-button {{
-                padding: 8px 16px;
-                font-size: 13px;
+            .arena-selection-label {{
                 font-weight: 600;
-                background: #f8f9fa;
-                color: #7f8c8d;
-                border: none;
-                border-right: 1px solid #e0e0e0;
-                cursor: pointer;
-                transition: all 0.2s;
-                user-select: none;
-                white-space: nowrap;
+                color: var(--color-text-secondary);
+                font-size: 14px;
             }}
 
-            .sort-field-button:last-child {{
-                border-right: none;
+            .arena-chip {{
+                display: inline-flex;
+                align-items: center;
+                gap: 2px;
+                border: 2px solid var(--color-accent);
+                border-radius: 16px;
+                background: var(--color-accent-soft);
+                padding: 2px 4px 2px 2px;
             }}
 
-            /* Mobile responsive layout for sort buttons */
-            @media (max-width: 640px) {{
-                .sort-field-group {{
-                    flex-direction: column;
-                    width: 100%;
-                }}
-
-                .sort-field-button {{
-                    border-right: none;
-                    border-bottom: 1px solid #e0e0e0;
-                    padding: 12px 16px;
-                    font-size: 14px;
-                }}
-
-                .sort-field-button:last-child {{
-                    border-bottom: none;
-                }}
-
-                .sort-direction-button {{
-                    width: 100%;
-                    justify-content: center;
-                    padding: 12px 16px;
-                    font-size: 14px;
-                }}
+            .arena-chip-name {{
+                padding: 4px 4px 4px 12px;
+                font-size: 13px;
             }}
 
-            .sort-field-button:hover {{
-                background: #ecf0f1;
+            .arena-chip-remove {{
+                background: none;
+                border: none;
+                padding: 2px 6px;
+                font-size: 14px;
+                color: var(--color-text-faint);
+                cursor: pointer;
+                border-radius: 10px;
+                line-height: 1;
             }}
 
-            .sort-field-button.active {{
-                background: #3498db;
-                color: white;
+            .arena-chip-remove:hover {{
+                background: var(--color-surface-hover);
+                color: var(--color-text);
             }}
 
-            .sort-direction-button {{
-                padding: 8px 16px;
+            .arena-clear-button {{
+                background: none;
+                border: 2px dashed var(--color-border);
+                border-radius: 16px;
+                padding: 4px 12px;
                 font-size: 13px;
                 font-weight: 600;
-                border: 2px solid #e0e0e0;
-                border-radius: 6px;
-                background: #f8f9fa;
-                color: #7f8c8d;
+                color: var(--color-text-muted);
                 cursor: pointer;
-                transition: all 0.2s;
-                user-select: none;
+            }}
+
+            .arena-clear-button:hover {{
+                border-color: var(--color-accent);
+                color: var(--color-accent);
+            }}
+
+            .arena-prompt,
+            .arena-export-json {{
+                width: 100%;
+                box-sizing: border-box;
+                font-family: 'Monaco', 'Consolas', monospace;
+                font-size: 12px;
+                padding: 8px;
+                border: 2px solid var(--color-border);
+                border-radius: 6px;
+                resize: vertical;
+            }}
+
+            .arena-actions {{
                 display: flex;
                 align-items: center;
-                gap: 6px;
+                gap: 8px;
             }}
 
-            .sort-direction-button:hover {{
-                background: #ecf0f1;
-                border-color: #bdc3c7;
+            .arena-status {{
+                font-size: 13px;
+                color: var(--color-success);
             }}
 
-            .model-metadata {{
+            .arena-status.error {{
+                color: var(--color-danger);
+            }}
+
+            .arena-grid {{
                 display: grid;
-                grid-template-columns: auto 1fr;
-                gap: 8px 12px;
-                font-size: 13px;
-                color: #555;
-                margin-bottom: 10px;
+                grid-template-columns: repeat(auto-fit, minmax(240px, 1fr));
+                gap: 12px;
             }}
 
-            .model-list-container {{
-                max-height: 600px;
-                overflow-y: auto;
-                overflow-x: hidden;
-                scroll-behavior: smooth;
-                padding-right: 4px;
+            .arena-column {{
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+                min-width: 0;
+                padding: 12px;
+                background: var(--color-surface);
+                border: 1px solid var(--color-border);
+                border-radius: 8px;
             }}
 
-            .model-list-container::-webkit-scrollbar {{
-                width: 8px;
+            .arena-column-title {{
+                margin: 0;
+                font-size: 15px;
+                color: var(--color-text);
             }}
 
-            .model-list-container::-webkit-scrollbar-track {{
-                background: #ecf0f1;
-                border-radius: 4px;
+            .arena-column-id {{
+                font-family: monospace;
+                font-size: 11px;
+                color: var(--color-text-muted);
+                word-break: break-all;
             }}
 
-            .model-list-container::-webkit-scrollbar-thumb {{
-                background: #bdc3c7;
-                border-radius: 4px;
+            .arena-column .playground-response {{
+                flex: 1;
             }}
 
-            .model-list-container::-webkit-scrollbar-thumb:hover {{
-                background: #95a5a6;
+            .arena-metrics {{
+                display: flex;
+                flex-direction: column;
+                gap: 2px;
+                font-size: 12px;
+                color: var(--color-text-muted);
             }}
 
-            .metadata-label {{
-                font-weight: 600;
-                color: #7f8c8d;
+            @keyframes spin {{
+                from {{ transform: rotate(0deg); }}
+                to {{ transform: rotate(360deg); }}
             }}
 
-            .metadata-value {{
-                color: #2c3e50;
+            .model-item.selected,
+            .model-table tbody tr.selected {{
+                outline: 2px solid var(--color-accent);
+                outline-offset: 2px;
             }}
 
-            .price-value {{
-                font-family: 'Monaco', 'Consolas', monospace;
-                color: #27ae60;
-                font-weight: 600;
+            .shortcuts-help {{
+                max-width: 480px;
             }}
 
-            .canonical-slug {{
-                background: #f8f9fa;
-                border: 1px solid #e0e0e0;
-                border-radius: 4px;
-                padding: 8px 10px;
-                font-family: 'Monaco', 'Consolas', monospace;
-                font-size: 13px;
-                color: #34495e;
-                user-select: all;
-                cursor: text;
-                overflow-x: auto;
+            .shortcut-keys {{
+                white-space: nowrap;
+                color: var(--color-text-muted);
             }}
 
-            .retry-button {{
-                padding: 10px 20px;
-                background: #3498db;
-                color: white;
-                border: none;
-                border-radius: 6px;
-                cursor: pointer;
-                font-size: 14px;
-                font-weight: 600;
-                transition: background 0.2s;
+            .shortcut-keys kbd {{
+                display: inline-block;
+                min-width: 18px;
+                padding: 2px 6px;
+                border: 1px solid var(--color-border-strong);
+                border-bottom-width: 2px;
+                border-radius: 4px;
+                background: var(--color-surface-muted);
+                color: var(--color-text);
+                font-family: ui-monospace, monospace;
+                font-size: 12px;
+                text-align: center;
             }}
 
-            .retry-button:hover {{
-                background: #2980b9;
+            .visually-hidden {{
+                position: absolute;
+                width: 1px;
+                height: 1px;
+                padding: 0;
+                margin: -1px;
+                overflow: hidden;
+                clip: rect(0, 0, 0, 0);
+                white-space: nowrap;
+                border: 0;
             }}
 
-            @keyframes spin {{
-                from {{ transform: rotate(0deg); }}
-                to {{ transform: rotate(360deg); }}
+            .model-item:focus-visible,
+            .model-table tbody tr:focus-visible,
+            .whats-new-item.clickable:focus-visible,
+            .modal-content:focus-visible {{
+                outline: 2px solid var(--color-accent);
+                outline-offset: 2px;
             }}
 
             .modal-overlay {{
@@ -383,7 +1584,7 @@ pub fn GlobalStyles() -> Element {
                 left: 0;
                 right: 0;
                 bottom: 0;
-                background: rgba(0, 0, 0, 0.6);
+                background: var(--color-overlay);
                 display: flex;
                 align-items: center;
                 justify-content: center;
@@ -393,22 +1594,22 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .modal-content {{
-                background: white;
+                background: var(--color-surface);
                 border-radius: 12px;
                 max-width: 800px;
                 width: 100%;
                 max-height: 90vh;
                 overflow-y: auto;
                 position: relative;
-                box-shadow: 0 20px 60px rgba(0, 0, 0, 0.3);
+                box-shadow: 0 20px 60px var(--color-shadow-strong);
             }}
 
             .modal-header {{
                 position: sticky;
                 top: 0;
-                background: white;
+                background: var(--color-surface);
                 padding: 20px 24px;
-                border-bottom: 2px solid #e0e0e0;
+                border-bottom: 2px solid var(--color-border);
                 display: flex;
                 justify-content: space-between;
                 align-items: center;
@@ -418,7 +1619,7 @@ pub fn GlobalStyles() -> Element {
             .modal-title {{
                 font-size: 22px;
                 font-weight: 700;
-                color: #2c3e50;
+                color: var(--color-text);
                 margin: 0;
             }}
 
@@ -426,7 +1627,7 @@ pub fn GlobalStyles() -> Element {
                 background: none;
                 border: none;
                 font-size: 28px;
-                color: #95a5a6;
+                color: var(--color-text-faint);
                 cursor: pointer;
                 padding: 0;
                 width: 32px;
@@ -439,8 +1640,8 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .modal-close:hover {{
-                background: #f0f0f0;
-                color: #2c3e50;
+                background: var(--color-surface-hover);
+                color: var(--color-text);
             }}
 
             .modal-body {{
@@ -458,11 +1659,11 @@ pub fn GlobalStyles() -> Element {
             .modal-section-title {{
                 font-size: 16px;
                 font-weight: 700;
-                color: #2c3e50;
+                color: var(--color-text);
                 margin-bottom: 12px;
                 text-transform: uppercase;
                 letter-spacing: 0.5px;
-                border-bottom: 2px solid #3498db;
+                border-bottom: 2px solid var(--color-accent);
                 padding-bottom: 6px;
             }}
 
@@ -475,16 +1676,16 @@ pub fn GlobalStyles() -> Element {
 
             .modal-label {{
                 font-weight: 600;
-                color: #7f8c8d;
+                color: var(--color-text-muted);
             }}
 
             .modal-value {{
-                color: #2c3e50;
+                color: var(--color-text);
             }}
 
             .modal-price-value {{
                 font-family: 'Monaco', 'Consolas', monospace;
-                color: #27ae60;
+                color: var(--color-success);
                 font-weight: 600;
             }}
 
@@ -496,8 +1697,8 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .modal-parameter-badge {{
-                background: #ecf0f1;
-                color: #2c3e50;
+                background: var(--color-surface-muted);
+                color: var(--color-text);
                 padding: 6px 12px;
                 border-radius: 6px;
                 font-size: 13px;
@@ -505,8 +1706,8 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .copy-button {{
-                background: #3498db;
-                color: white;
+                background: var(--color-accent);
+                color: var(--color-on-accent);
                 border: none;
                 border-radius: 4px;
                 padding: 6px 12px;
@@ -518,15 +1719,61 @@ pub fn GlobalStyles() -> Element {
             }}
 
             .copy-button:hover {{
-                background: #2980b9;
+                background: var(--color-accent-hover);
             }}
 
             .copy-button:active {{
-                background: #21618c;
+                background: var(--color-accent-active);
             }}
 
             .copy-button.copied {{
-                background: #27ae60;
+                background: var(--color-success);
+            }}
+
+            .copy-button.failed {{
+                background: var(--color-danger);
+            }}
+
+            .copy-menu {{
+                position: relative;
+                display: flex;
+                align-items: center;
+            }}
+
+            .copy-menu .copy-menu-toggle {{
+                margin-left: 2px;
+                padding: 6px 8px;
+            }}
+
+            .copy-menu-list {{
+                position: absolute;
+                top: 100%;
+                right: 0;
+                z-index: 10;
+                display: flex;
+                flex-direction: column;
+                min-width: 200px;
+                margin-top: 4px;
+                padding: 4px;
+                background: var(--color-surface);
+                border: 1px solid var(--color-border);
+                border-radius: 6px;
+                box-shadow: 0 4px 12px var(--color-shadow);
+            }}
+
+            .copy-menu-item {{
+                background: none;
+                border: none;
+                border-radius: 4px;
+                padding: 8px 10px;
+                text-align: left;
+                font-size: 13px;
+                color: var(--color-text);
+                cursor: pointer;
+            }}
+
+            .copy-menu-item:hover {{
+                background: var(--color-surface-hover);
             }}
 
             .canonical-slug-container {{
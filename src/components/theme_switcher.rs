@@ -0,0 +1,28 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::Theme;
+
+/// Theme drop-down shown in the footer next to the language switcher
+#[component]
+pub fn ThemeSwitcher(theme: Signal<Theme>) -> Element {
+    rsx! {
+        select {
+            class: "theme-switcher",
+            title: t!("theme-label"),
+            aria_label: t!("theme-label"),
+            onchange: move |evt| {
+                if let Some(selected) = Theme::ALL.into_iter().find(|t| t.attribute() == evt.value()) {
+                    theme.set(selected);
+                }
+            },
+            for option_theme in Theme::ALL {
+                option {
+                    value: option_theme.attribute(),
+                    selected: *theme.read() == option_theme,
+                    { t!(option_theme.label_key()) }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::{BilledTokens, Pricing};
+use crate::utils::{LocaleFormat, TokenCount, TokenCounts};
+
+use super::currency::use_money;
+use super::locale::use_locale;
+
+/// Collapsible panel to paste a prompt and see its token count per tokenizer family
+#[component]
+pub fn TokenCounter(text: Signal<String>, counts: Option<TokenCounts>) -> Element {
+    let locale = use_locale();
+    let characters = text.read().chars().count();
+
+    rsx! {
+        details {
+            class: "favorites-panel token-counter",
+            summary {
+                class: "favorites-panel-summary",
+                { t!("token-counter-title") }
+            }
+            div {
+                class: "favorites-panel-body",
+                textarea {
+                    class: "favorites-json",
+                    rows: 5,
+                    value: "{text}",
+                    placeholder: "{t!(\"token-counter-placeholder\")}",
+                    aria_label: t!("token-counter-title"),
+                    oninput: move |evt| text.set(evt.value()),
+                }
+                if let Some(counts) = counts {
+                    div {
+                        class: "token-counter-summary",
+                        role: "status",
+                        div { { t!("token-counter-characters", count: characters) } }
+                        ul {
+                            class: "token-counts",
+                            for (family, count) in counts.iter() {
+                                li {
+                                    key: "{family:?}",
+                                    span { class: "token-count-family", { t!(family.label_key()) } }
+                                    span { class: "token-count-value", { token_count_text(locale, count) } }
+                                    span {
+                                        class: if count.exact { "token-count-kind exact" } else { "token-count-kind" },
+                                        if count.exact {
+                                            { t!("token-counter-exact") }
+                                        } else {
+                                            { t!("token-counter-estimate") }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "playground-hint", { t!(hint_key()) } }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Cost of sending the pasted text as a prompt (input tokens plus the per-request fee)
+#[component]
+pub fn PromptCost(pricing: Pricing, count: TokenCount) -> Element {
+    let money = use_money();
+    let cost = pricing.usage_cost(BilledTokens {
+        prompt: count.tokens,
+        ..BilledTokens::default()
+    });
+
+    rsx! {
+        span {
+            class: "price-value",
+            title: token_count_text(money.locale(), count),
+            "{money.format(cost.round_dp(6))}"
+        }
+    }
+}
+
+/// "1,234 tokens", prefixed with "≈" when estimated
+fn token_count_text(locale: LocaleFormat, count: TokenCount) -> String {
+    let tokens = locale.integer(count.tokens);
+    if count.exact {
+        t!("token-count", tokens: tokens)
+    } else {
+        t!("token-count-estimate", tokens: tokens)
+    }
+}
+
+/// Whether the OpenAI vocabularies are bundled for exact counts
+const EXACT_COUNTS: bool = cfg!(feature = "exact-tokenizers");
+
+/// Hint under the counter, depending on whether the OpenAI vocabularies are bundled
+fn hint_key() -> &'static str {
+    if EXACT_COUNTS {
+        "token-counter-hint"
+    } else {
+        "token-counter-hint-estimated"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::models::TokenizerFamily;
+
+    #[test]
+    fn test_counts_are_listed_per_family() {
+        let html = render_to_html(|| {
+            let text = use_signal(|| "Hello world".to_string());
+            let counts = TokenCounts::for_families(
+                &text.read(),
+                [TokenizerFamily::Claude, TokenizerFamily::Gemini],
+            );
+            rsx! { TokenCounter { text: text, counts: counts } }
+        });
+        assert_eq!(html.matches("<li").count(), 2, "{html}");
+        assert!(html.contains("Claude"), "{html}");
+        assert!(html.contains("Gemini"), "{html}");
+    }
+
+    #[test]
+    fn test_empty_text_shows_no_counts() {
+        let html = render_to_html(|| {
+            let text = use_signal(String::new);
+            rsx! { TokenCounter { text: text, counts: None } }
+        });
+        assert!(!html.contains("token-counts"), "{html}");
+    }
+}
@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::models::{TableColumn, ViewMode};
+
+use super::currency::use_money;
+
+#[component]
+pub fn ViewControls(
+    view_mode: Signal<ViewMode>,
+    visible_columns: Signal<Vec<TableColumn>>,
+) -> Element {
+    let current_mode = *view_mode.read();
+    let unit = use_money().unit().fluent_arg();
+
+    rsx! {
+        div {
+            class: "view-controls-container",
+
+            // View mode selector (segmented control)
+            div {
+                class: "sort-field-group",
+                role: "group",
+                aria_label: t!("view-mode-label"),
+
+                button {
+                    class: if current_mode == ViewMode::Cards {
+                        "sort-field-button active"
+                    } else {
+                        "sort-field-button"
+                    },
+                    aria_pressed: "{current_mode == ViewMode::Cards}",
+                    onclick: move |_| view_mode.set(ViewMode::Cards),
+                    { t!("view-cards") }
+                }
+
+                button {
+                    class: if current_mode == ViewMode::Table {
+                        "sort-field-button active"
+                    } else {
+                        "sort-field-button"
+                    },
+                    aria_pressed: "{current_mode == ViewMode::Table}",
+                    onclick: move |_| view_mode.set(ViewMode::Table),
+                    { t!("view-table") }
+                }
+
+                button {
+                    class: if current_mode == ViewMode::Chart {
+                        "sort-field-button active"
+                    } else {
+                        "sort-field-button"
+                    },
+                    aria_pressed: "{current_mode == ViewMode::Chart}",
+                    onclick: move |_| view_mode.set(ViewMode::Chart),
+                    { t!("view-chart") }
+                }
+            }
+
+            // Column picker (table view only)
+            if current_mode == ViewMode::Table {
+                details {
+                    class: "column-picker",
+                    summary {
+                        class: "sort-direction-button",
+                        { t!("columns-button") }
+                    }
+                    div {
+                        class: "column-picker-menu",
+                        for column in TableColumn::ALL.into_iter().filter(TableColumn::is_optional) {
+                            label {
+                                class: "column-picker-option",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: visible_columns.read().contains(&column),
+                                    onchange: move |_| {
+                                        let mut columns = visible_columns.write();
+                                        if let Some(position) = columns.iter().position(|c| *c == column) {
+                                            columns.remove(position);
+                                        } else {
+                                            columns.push(column);
+                                        }
+                                    },
+                                }
+                                { t!(column.label_key(), unit: unit) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
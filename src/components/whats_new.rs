@@ -0,0 +1,148 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::history::CatalogChanges;
+use crate::models::Model;
+
+use super::currency::use_money;
+
+/// Change log between the two most recent catalog snapshots
+#[component]
+pub fn WhatsNew(changes: CatalogChanges, on_select: EventHandler<Model>) -> Element {
+    let money = use_money();
+    let unit = money.unit().fluent_arg();
+    let locale = money.locale();
+    let diff = &changes.diff;
+    let total = diff.added.len() + diff.removed.len() + diff.price_changes.len();
+
+    rsx! {
+        details {
+            class: "whats-new-panel",
+            summary {
+                class: "whats-new-summary",
+                { t!("whats-new-title", count: total) }
+            }
+            div {
+                class: "whats-new-body",
+                div {
+                    class: "whats-new-period",
+                    { t!("whats-new-period", since: locale.timestamp(&changes.since), until: locale.timestamp(&changes.until)) }
+                }
+
+                if diff.is_empty() {
+                    div {
+                        class: "whats-new-empty",
+                        { t!("whats-new-no-changes") }
+                    }
+                }
+
+                // Added models
+                if !diff.added.is_empty() {
+                    div { class: "whats-new-heading added", { t!("whats-new-added", count: diff.added.len()) } }
+                    ul {
+                        class: "whats-new-list",
+                        for model in diff.added.iter().cloned() {
+                            li {
+                                key: "{model.id}",
+                                class: "whats-new-item clickable",
+                                tabindex: 0,
+                                aria_haspopup: "dialog",
+                                "data-open-on-enter": "true",
+                                onclick: {
+                                    let model = model.clone();
+                                    move |_| on_select.call(model.clone())
+                                },
+                                "{model.name}"
+                            }
+                        }
+                    }
+                }
+
+                // Removed models (no longer in the catalog, so not clickable)
+                if !diff.removed.is_empty() {
+                    div { class: "whats-new-heading removed", { t!("whats-new-removed", count: diff.removed.len()) } }
+                    ul {
+                        class: "whats-new-list",
+                        for model in diff.removed.iter() {
+                            li {
+                                key: "{model.id}",
+                                class: "whats-new-item",
+                                "{model.name}"
+                            }
+                        }
+                    }
+                }
+
+                // Price changes with old → new values
+                if !diff.price_changes.is_empty() {
+                    div { class: "whats-new-heading changed", { t!("whats-new-price-changes", count: diff.price_changes.len()) } }
+                    ul {
+                        class: "whats-new-list",
+                        for change in diff.price_changes.iter().cloned() {
+                            li {
+                                key: "{change.model.id}",
+                                class: "whats-new-item clickable",
+                                tabindex: 0,
+                                aria_haspopup: "dialog",
+                                "data-open-on-enter": "true",
+                                onclick: {
+                                    let model = change.model.clone();
+                                    move |_| on_select.call(model.clone())
+                                },
+                                div { class: "whats-new-model-name", "{change.model.name}" }
+                                for field_change in change.changes.iter() {
+                                    div {
+                                        class: "whats-new-price-change",
+                                        span { class: "metadata-label", { t!(field_change.field.label_key(), unit: unit) } }
+                                        " "
+                                        span { class: "price-old", "{money.pricing_field(field_change.field, field_change.old)}" }
+                                        " → "
+                                        span {
+                                            class: if field_change.new > field_change.old { "price-new up" } else { "price-new down" },
+                                            "{money.pricing_field(field_change.field, field_change.new)}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::render_to_html;
+    use crate::history::diff_catalogs;
+    use crate::models::ApiResponse;
+
+    #[test]
+    fn test_clickable_items_are_keyboard_reachable() {
+        let html = render_to_html(|| {
+            let response: ApiResponse =
+                serde_json::from_str(include_str!("../models.json")).unwrap();
+            let old = response.data[..3].to_vec();
+            let mut new = response.data[1..].to_vec();
+            new[0].pricing.prompt *= rust_decimal::Decimal::TWO;
+            let changes = CatalogChanges {
+                since: time::OffsetDateTime::UNIX_EPOCH,
+                until: time::OffsetDateTime::UNIX_EPOCH,
+                diff: diff_catalogs(&old, &new),
+            };
+            rsx! { WhatsNew { changes: changes, on_select: |_| {} } }
+        });
+        // Added and repriced models open the modal; removed ones are plain text
+        let clickable = html.matches("whats-new-item clickable").count();
+        assert!(clickable > 0, "{html}");
+        assert!(html.contains(r#"class="whats-new-item""#), "{html}");
+        assert_eq!(html.matches(r#"tabindex="0""#).count(), clickable, "{html}");
+        assert_eq!(
+            html.matches(r#"data-open-on-enter="true""#).count(),
+            clickable,
+            "{html}"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::models::{Model, PricingField};
+
+/// Differences between two catalogs, keyed by model `id`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogDiff {
+    /// Models present only in the newer catalog
+    pub added: Vec<Model>,
+    /// Models present only in the older catalog
+    pub removed: Vec<Model>,
+    /// Models present in both catalogs whose pricing changed
+    pub price_changes: Vec<PriceChange>,
+}
+
+impl CatalogDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.price_changes.is_empty()
+    }
+}
+
+/// Pricing changes of a single model
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceChange {
+    /// The model as it appears in the newer catalog
+    pub model: Model,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Old and new value of one pricing component
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldChange {
+    pub field: PricingField,
+    pub old: Decimal,
+    pub new: Decimal,
+}
+
+/// Compare two catalogs and report added, removed and repriced models.
+///
+/// The input order does not matter; every list in the result is sorted by `id`.
+pub fn diff_catalogs(old: &[Model], new: &[Model]) -> CatalogDiff {
+    let old_by_id: HashMap<&str, &Model> = old.iter().map(|m| (m.id.as_str(), m)).collect();
+    let new_by_id: HashMap<&str, &Model> = new.iter().map(|m| (m.id.as_str(), m)).collect();
+
+    let mut added: Vec<Model> = new
+        .iter()
+        .filter(|m| !old_by_id.contains_key(m.id.as_str()))
+        .cloned()
+        .collect();
+
+    let mut removed: Vec<Model> = old
+        .iter()
+        .filter(|m| !new_by_id.contains_key(m.id.as_str()))
+        .cloned()
+        .collect();
+
+    let mut price_changes: Vec<PriceChange> = new
+        .iter()
+        .filter_map(|new_model| {
+            let old_model = old_by_id.get(new_model.id.as_str())?;
+            let changes: Vec<FieldChange> = PricingField::ALL
+                .into_iter()
+                .filter_map(|field| {
+                    let old = old_model.pricing.get(field);
+                    let new = new_model.pricing.get(field);
+                    (old != new).then_some(FieldChange { field, old, new })
+                })
+                .collect();
+
+            (!changes.is_empty()).then(|| PriceChange {
+                model: new_model.clone(),
+                changes,
+            })
+        })
+        .collect();
+
+    added.sort_by(|a, b| a.id.cmp(&b.id));
+    removed.sort_by(|a, b| a.id.cmp(&b.id));
+    price_changes.sort_by(|a, b| a.model.id.cmp(&b.model.id));
+
+    CatalogDiff {
+        added,
+        removed,
+        price_changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiResponse;
+
+    fn catalog() -> Vec<Model> {
+        let example = include_str!("../models.json");
+        serde_json::from_str::<ApiResponse>(example).unwrap().data
+    }
+
+    #[test]
+    fn test_identical_catalogs_have_no_diff() {
+        let models = catalog();
+        let diff = diff_catalogs(&models, &models);
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_order_does_not_matter() {
+        let models = catalog();
+        let mut reversed = models.clone();
+        reversed.reverse();
+        assert!(diff_catalogs(&models, &reversed).is_empty());
+    }
+
+    #[test]
+    fn test_added_and_removed_models() {
+        let models = catalog();
+        let old = models[1..].to_vec();
+        let new = models[..models.len() - 1].to_vec();
+
+        let diff = diff_catalogs(&old, &new);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].id, models[0].id);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].id, models[models.len() - 1].id);
+        assert!(diff.price_changes.is_empty());
+    }
+
+    #[test]
+    fn test_price_changes_per_field() {
+        let old = catalog();
+        let mut new = old.clone();
+        new[0].pricing.prompt = Decimal::new(5, 6);
+        new[0].pricing.web_search = Decimal::new(1, 2);
+        // Renaming alone is not a price change
+        new[1].name = "Renamed".to_string();
+
+        let diff = diff_catalogs(&old, &new);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.price_changes.len(), 1);
+
+        let change = &diff.price_changes[0];
+        assert_eq!(change.model.id, old[0].id);
+        assert_eq!(
+            change.changes,
+            vec![
+                FieldChange {
+                    field: PricingField::Prompt,
+                    old: old[0].pricing.prompt,
+                    new: Decimal::new(5, 6),
+                },
+                FieldChange {
+                    field: PricingField::WebSearch,
+                    old: old[0].pricing.web_search,
+                    new: Decimal::new(1, 2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_equal_decimals_with_different_scale_are_unchanged() {
+        let old = catalog();
+        let mut new = old.clone();
+        // "0.00032260" and "0.0003226" are the same price
+        new[0].pricing.prompt = old[0].pricing.prompt.normalize();
+
+        assert!(diff_catalogs(&old, &new).is_empty());
+    }
+}
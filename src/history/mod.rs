@@ -0,0 +1,18 @@
+mod diff;
+mod prices;
+mod snapshots;
+
+// Where snapshots and prices are kept: IndexedDB on the web, JSON files elsewhere
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(target_arch = "wasm32")]
+use wasm as store;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(not(target_arch = "wasm32"))]
+use native as store;
+
+pub use diff::{diff_catalogs, CatalogDiff, FieldChange, PriceChange};
+pub use prices::{load_price_points, record_prices, PriceHistory, PricePoint};
+pub use snapshots::{latest_changes, record_snapshot, CatalogChanges, CatalogSnapshot};
@@ -0,0 +1,50 @@
+use crate::cache::CacheError;
+use crate::storage;
+
+use super::{CatalogSnapshot, PriceHistory, PricePoint};
+
+/// Storage key for persisting catalog snapshots
+const SNAPSHOTS_STORAGE_KEY: &str = "polza-models-snapshots";
+
+/// Storage key for persisting the price history
+const PRICE_HISTORY_STORAGE_KEY: &str = "polza-models-price-history";
+
+fn load_snapshots() -> Vec<CatalogSnapshot> {
+    storage::load(SNAPSHOTS_STORAGE_KEY).unwrap_or_default()
+}
+
+/// The `count` most recent snapshots, oldest first
+pub async fn latest_snapshots(count: usize) -> Vec<CatalogSnapshot> {
+    let mut snapshots = load_snapshots();
+    let excess = snapshots.len().saturating_sub(count);
+    snapshots.drain(..excess);
+    snapshots
+}
+
+/// Add a snapshot, keeping only the `keep` most recent ones
+pub async fn save_snapshot(snapshot: &CatalogSnapshot, keep: usize) -> Result<(), CacheError> {
+    let mut snapshots = load_snapshots();
+    snapshots.push(snapshot.clone());
+    let excess = snapshots.len().saturating_sub(keep);
+    snapshots.drain(..excess);
+    storage::try_save(SNAPSHOTS_STORAGE_KEY, &snapshots).map_err(CacheError::Failed)
+}
+
+/// The whole price history
+pub async fn load_price_history() -> PriceHistory {
+    storage::load(PRICE_HISTORY_STORAGE_KEY).unwrap_or_default()
+}
+
+/// Price points of one model, oldest first
+pub async fn load_price_points(id: &str) -> Vec<PricePoint> {
+    load_price_history().await.points(id).to_vec()
+}
+
+/// Append newly recorded points to the price history
+pub async fn save_price_points(points: &[(String, PricePoint)]) -> Result<(), CacheError> {
+    let mut history = load_price_history().await;
+    for (id, point) in points {
+        history.push(id.clone(), *point);
+    }
+    storage::try_save(PRICE_HISTORY_STORAGE_KEY, &history).map_err(CacheError::Failed)
+}
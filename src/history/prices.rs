@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::CacheError;
+use crate::models::Model;
+
+use super::store;
+
+/// Prompt and completion price of a model at a given moment
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PricePoint {
+    #[serde(with = "time::serde::timestamp")]
+    pub at: time::OffsetDateTime,
+    pub prompt: Decimal,
+    pub completion: Decimal,
+}
+
+/// Price points per model `id`, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct PriceHistory(BTreeMap<String, Vec<PricePoint>>);
+
+impl PriceHistory {
+    /// Append the current prices of every model, returning the added points.
+    ///
+    /// A point is only added when the prices differ from the model's latest point,
+    /// so the history stores price changes rather than every fetch.
+    pub fn record(
+        &mut self,
+        models: &[Model],
+        at: time::OffsetDateTime,
+    ) -> Vec<(String, PricePoint)> {
+        let mut added = Vec::new();
+        for model in models {
+            let points = self.0.entry(model.id.clone()).or_default();
+            let unchanged = points.last().is_some_and(|last| {
+                last.prompt == model.pricing.prompt && last.completion == model.pricing.completion
+            });
+            if !unchanged {
+                let point = PricePoint {
+                    at,
+                    prompt: model.pricing.prompt,
+                    completion: model.pricing.completion,
+                };
+                points.push(point);
+                added.push((model.id.clone(), point));
+            }
+        }
+        added
+    }
+
+    /// Append a point recorded earlier (points are added oldest first)
+    pub fn push(&mut self, id: String, point: PricePoint) {
+        self.0.entry(id).or_default().push(point);
+    }
+
+    /// Every point with the id of its model, by model and oldest first
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PricePoint)> {
+        self.0
+            .iter()
+            .flat_map(|(id, points)| points.iter().map(move |point| (id.as_str(), point)))
+    }
+
+    /// Recorded points of a model, oldest first
+    pub fn points(&self, id: &str) -> &[PricePoint] {
+        self.0.get(id).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Load the stored price points of a model, oldest first
+pub async fn load_price_points(id: &str) -> Vec<PricePoint> {
+    store::load_price_points(id).await
+}
+
+/// Append the prices of a freshly fetched catalog to the stored history
+pub async fn record_prices(models: &[Model]) -> Result<(), CacheError> {
+    let mut history = store::load_price_history().await;
+    let added = history.record(models, time::OffsetDateTime::now_utc());
+    if added.is_empty() {
+        return Ok(());
+    }
+    store::save_price_points(&added).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiResponse;
+
+    fn catalog() -> Vec<Model> {
+        let example = include_str!("../models.json");
+        serde_json::from_str::<ApiResponse>(example).unwrap().data
+    }
+
+    fn at(seconds: i64) -> time::OffsetDateTime {
+        time::OffsetDateTime::from_unix_timestamp(seconds).unwrap()
+    }
+
+    #[test]
+    fn test_first_record_adds_a_point_per_model() {
+        let models = catalog();
+        let mut history = PriceHistory::default();
+        history.record(&models, at(100));
+
+        for model in &models {
+            let points = history.points(&model.id);
+            assert_eq!(points.len(), 1);
+            assert_eq!(points[0].prompt, model.pricing.prompt);
+            assert_eq!(points[0].completion, model.pricing.completion);
+        }
+        assert!(history.points("unknown/model").is_empty());
+    }
+
+    #[test]
+    fn test_unchanged_prices_are_deduplicated() {
+        let models = catalog();
+        let mut history = PriceHistory::default();
+        history.record(&models, at(100));
+        history.record(&models, at(200));
+
+        assert_eq!(history.points(&models[0].id).len(), 1);
+        assert_eq!(history.points(&models[0].id)[0].at, at(100));
+    }
+
+    #[test]
+    fn test_changed_prices_append_a_point() {
+        let mut models = catalog();
+        let mut history = PriceHistory::default();
+        history.record(&models, at(100));
+
+        models[0].pricing.completion = Decimal::new(42, 6);
+        history.record(&models, at(200));
+
+        let points = history.points(&models[0].id);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[1].at, at(200));
+        assert_eq!(points[1].completion, Decimal::new(42, 6));
+
+        // Other models are untouched
+        assert_eq!(history.points(&models[1].id).len(), 1);
+    }
+
+    #[test]
+    fn test_record_returns_the_added_points() {
+        let mut models = catalog();
+        let mut history = PriceHistory::default();
+        assert_eq!(history.record(&models, at(100)).len(), models.len());
+        assert!(history.record(&models, at(200)).is_empty());
+
+        models[0].pricing.prompt = Decimal::new(7, 6);
+        let added = history.record(&models, at(300));
+        assert_eq!(
+            added,
+            vec![(models[0].id.clone(), history.points(&models[0].id)[1])]
+        );
+
+        // Replaying the stored points rebuilds the same history
+        let mut replayed = PriceHistory::default();
+        for (id, point) in history.iter() {
+            replayed.push(id.to_owned(), *point);
+        }
+        assert_eq!(replayed, history);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut history = PriceHistory::default();
+        history.record(&catalog(), at(100));
+
+        let json = serde_json::to_string(&history).unwrap();
+        let restored: PriceHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, history);
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::cache::CacheError;
+use crate::models::Model;
+
+use super::diff::{diff_catalogs, CatalogDiff};
+use super::store;
+
+/// Maximum number of snapshots kept (oldest are dropped first)
+const MAX_SNAPSHOTS: usize = 5;
+
+/// A catalog as fetched from the API at a given moment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CatalogSnapshot {
+    #[serde(with = "time::serde::timestamp")]
+    pub taken_at: time::OffsetDateTime,
+    pub models: Vec<Model>,
+}
+
+impl CatalogSnapshot {
+    /// Snapshot of a catalog, without the name tokens the filter derives from the names
+    pub fn new(models: &[Model], taken_at: time::OffsetDateTime) -> Self {
+        let models = models
+            .iter()
+            .map(|model| Model {
+                name_tokens: Vec::new(),
+                ..model.clone()
+            })
+            .collect();
+        Self { taken_at, models }
+    }
+}
+
+/// Changes between the two most recent snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogChanges {
+    pub since: time::OffsetDateTime,
+    pub until: time::OffsetDateTime,
+    pub diff: CatalogDiff,
+}
+
+/// Store a freshly fetched catalog as a new snapshot, dropping the oldest beyond
+/// [`MAX_SNAPSHOTS`].
+///
+/// Nothing is recorded when the catalog is identical to the latest snapshot,
+/// so refreshing repeatedly does not push real changes out of the history.
+pub async fn record_snapshot(models: &[Model]) -> Result<(), CacheError> {
+    if let [latest] = store::latest_snapshots(1).await.as_slice() {
+        if diff_catalogs(&latest.models, models).is_empty() {
+            return Ok(());
+        }
+    }
+
+    let snapshot = CatalogSnapshot::new(models, time::OffsetDateTime::now_utc());
+    store::save_snapshot(&snapshot, MAX_SNAPSHOTS).await
+}
+
+/// Compute the changes between the two most recent snapshots (if there are two)
+pub async fn latest_changes() -> Option<CatalogChanges> {
+    let snapshots = store::latest_snapshots(2).await;
+    let [previous, latest] = snapshots.as_slice() else {
+        return None;
+    };
+
+    Some(CatalogChanges {
+        since: previous.taken_at,
+        until: latest.taken_at,
+        diff: diff_catalogs(&previous.models, &latest.models),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiResponse;
+    use crate::utils::tokenize;
+
+    #[test]
+    fn test_snapshots_leave_out_name_tokens() {
+        let mut models = serde_json::from_str::<ApiResponse>(include_str!("../models.json"))
+            .unwrap()
+            .data;
+        for model in &mut models {
+            model.name_tokens = tokenize(&model.name);
+        }
+
+        let snapshot = CatalogSnapshot::new(&models, time::OffsetDateTime::UNIX_EPOCH);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(!json.contains("name_tokens"), "{json}");
+
+        let restored: CatalogSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, snapshot);
+        assert!(diff_catalogs(&models, &restored.models).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+use gloo_console::log;
+use gloo_storage::errors::StorageError;
+use gloo_storage::{LocalStorage, Storage};
+use js_sys::Array;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use web_sys::IdbTransactionMode;
+
+use crate::cache::idb::{self, from_js, to_js, PRICES_STORE, PRICE_MODEL_INDEX, SNAPSHOTS_STORE};
+use crate::cache::CacheError;
+
+use super::{CatalogSnapshot, PriceHistory, PricePoint};
+
+/// LocalStorage key of the snapshots kept by versions before the IndexedDB history
+const LEGACY_SNAPSHOTS_KEY: &str = "polza-models-snapshots";
+
+/// LocalStorage key of the price history kept by versions before the IndexedDB history
+const LEGACY_PRICE_HISTORY_KEY: &str = "polza-models-price-history";
+
+/// A price point as an IndexedDB record, keyed by `[id, at]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PriceRecord {
+    id: String,
+    #[serde(with = "time::serde::timestamp")]
+    at: time::OffsetDateTime,
+    prompt: Decimal,
+    completion: Decimal,
+}
+
+impl PriceRecord {
+    fn new(id: &str, point: &PricePoint) -> Self {
+        Self {
+            id: id.to_owned(),
+            at: point.at,
+            prompt: point.prompt,
+            completion: point.completion,
+        }
+    }
+
+    fn point(&self) -> PricePoint {
+        PricePoint {
+            at: self.at,
+            prompt: self.prompt,
+            completion: self.completion,
+        }
+    }
+}
+
+/// The `count` most recent snapshots, oldest first
+pub async fn latest_snapshots(count: usize) -> Vec<CatalogSnapshot> {
+    migrate_local_storage().await;
+
+    let read = async {
+        let database = idb::open().await?;
+        // Keys are the snapshot times, so the newest come last
+        let keys = {
+            let store = database
+                .transaction_with_str(SNAPSHOTS_STORE)?
+                .object_store(SNAPSHOTS_STORE)?;
+            Array::from(&idb::wait(&store.get_all_keys()?).await?)
+        };
+        let store = database
+            .transaction_with_str(SNAPSHOTS_STORE)?
+            .object_store(SNAPSHOTS_STORE)?;
+        let requests = keys
+            .iter()
+            .skip((keys.length() as usize).saturating_sub(count))
+            .map(|key| store.get(&key))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut records = Vec::with_capacity(requests.len());
+        for request in &requests {
+            records.push(idb::wait(request).await?);
+        }
+        Ok::<_, JsValue>(records)
+    };
+
+    match read.await {
+        Ok(records) => records.iter().filter_map(from_js).collect(),
+        Err(err) => {
+            log!(format!(
+                "[History] ✗ Failed to read snapshots: {}",
+                idb::describe(&err)
+            ));
+            Vec::new()
+        }
+    }
+}
+
+/// Add a snapshot, keeping only the `keep` most recent ones
+pub async fn save_snapshot(snapshot: &CatalogSnapshot, keep: usize) -> Result<(), CacheError> {
+    let write = async {
+        let record = to_js(snapshot)?;
+        let database = idb::open().await?;
+        let keys = {
+            let store = database
+                .transaction_with_str(SNAPSHOTS_STORE)?
+                .object_store(SNAPSHOTS_STORE)?;
+            Array::from(&idb::wait(&store.get_all_keys()?).await?)
+        };
+
+        let transaction = database
+            .transaction_with_str_and_mode(SNAPSHOTS_STORE, IdbTransactionMode::Readwrite)?;
+        let queued = (|| {
+            let store = transaction.object_store(SNAPSHOTS_STORE)?;
+            store.put(&record)?;
+            // The new snapshot is the newest one, so the oldest make room for it
+            let excess = (keys.length() as usize + 1).saturating_sub(keep);
+            for key in keys.iter().take(excess) {
+                store.delete(&key)?;
+            }
+            Ok::<_, JsValue>(())
+        })();
+        if let Err(err) = queued {
+            let _ = transaction.abort();
+            return Err(err);
+        }
+        idb::committed(&transaction).await
+    };
+
+    write.await.map_err(|err| {
+        log!(format!(
+            "[History] ✗ Failed to save snapshot: {}",
+            idb::describe(&err)
+        ));
+        idb::cache_error(&err)
+    })
+}
+
+/// The whole price history
+pub async fn load_price_history() -> PriceHistory {
+    migrate_local_storage().await;
+
+    let read = async {
+        let database = idb::open().await?;
+        let store = database
+            .transaction_with_str(PRICES_STORE)?
+            .object_store(PRICES_STORE)?;
+        idb::wait(&store.get_all()?).await
+    };
+
+    let mut history = PriceHistory::default();
+    match read.await {
+        // Records come sorted by `[id, at]`, so every model's points are oldest first
+        Ok(records) => Array::from(&records)
+            .iter()
+            .filter_map(|record| from_js::<PriceRecord>(&record))
+            .for_each(|record| history.push(record.id.clone(), record.point())),
+        Err(err) => log!(format!(
+            "[History] ✗ Failed to read the price history: {}",
+            idb::describe(&err)
+        )),
+    }
+    history
+}
+
+/// Price points of one model, oldest first
+pub async fn load_price_points(id: &str) -> Vec<PricePoint> {
+    migrate_local_storage().await;
+
+    let read = async {
+        let database = idb::open().await?;
+        let index = database
+            .transaction_with_str(PRICES_STORE)?
+            .object_store(PRICES_STORE)?
+            .index(PRICE_MODEL_INDEX)?;
+        idb::wait(&index.get_all_with_key(&id.into())?).await
+    };
+
+    match read.await {
+        Ok(records) => Array::from(&records)
+            .iter()
+            .filter_map(|record| from_js::<PriceRecord>(&record))
+            .map(|record| record.point())
+            .collect(),
+        Err(err) => {
+            log!(format!(
+                "[History] ✗ Failed to read price points: {}",
+                idb::describe(&err)
+            ));
+            Vec::new()
+        }
+    }
+}
+
+/// Append newly recorded points to the price history
+pub async fn save_price_points(points: &[(String, PricePoint)]) -> Result<(), CacheError> {
+    let write = async {
+        let records = points
+            .iter()
+            .map(|(id, point)| to_js(&PriceRecord::new(id, point)))
+            .collect::<Result<Vec<_>, _>>()?;
+        idb::put_all(PRICES_STORE, &records).await
+    };
+
+    write.await.map_err(|err| {
+        log!(format!(
+            "[History] ✗ Failed to save price points: {}",
+            idb::describe(&err)
+        ));
+        idb::cache_error(&err)
+    })
+}
+
+/// Move the snapshots and price history kept in LocalStorage by earlier versions into IndexedDB, once
+async fn migrate_local_storage() {
+    migrate_key(
+        LEGACY_SNAPSHOTS_KEY,
+        SNAPSHOTS_STORE,
+        |snapshots: Vec<CatalogSnapshot>| {
+            snapshots
+                .iter()
+                .map(|snapshot| to_js(&CatalogSnapshot::new(&snapshot.models, snapshot.taken_at)))
+                .collect()
+        },
+    )
+    .await;
+    migrate_key(
+        LEGACY_PRICE_HISTORY_KEY,
+        PRICES_STORE,
+        |history: PriceHistory| {
+            history
+                .iter()
+                .map(|(id, point)| to_js(&PriceRecord::new(id, point)))
+                .collect()
+        },
+    )
+    .await;
+}
+
+/// Copy the value of a LocalStorage key into an object store as `records` and drop the key
+async fn migrate_key<T: DeserializeOwned>(
+    key: &str,
+    store_name: &str,
+    records: impl FnOnce(T) -> Result<Vec<JsValue>, JsValue>,
+) {
+    match LocalStorage::get::<T>(key) {
+        Ok(legacy) => {
+            let copied = async { idb::put_all(store_name, &records(legacy)?).await };
+            match copied.await {
+                Ok(()) => log!(format!(
+                    "[History] ✓ Migrated \"{}\" from localStorage",
+                    key
+                )),
+                Err(err) => log!(format!(
+                    "[History] ✗ Failed to migrate \"{}\": {}",
+                    key,
+                    idb::describe(&err)
+                )),
+            }
+        }
+        Err(StorageError::KeyNotFound(_)) => return,
+        Err(err) => log!(format!(
+            "[History] ✗ Discarding unreadable localStorage \"{}\": {}",
+            key, err
+        )),
+    }
+    // Dropped even if the copy failed: the next fetch records fresh history
+    LocalStorage::delete(key);
+}
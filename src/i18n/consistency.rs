@@ -0,0 +1,580 @@
+//! Checks that the translations and the code agree on message keys, variables and plurals.
+//!
+//! The `.ftl` files are maintained by hand, so every bundled locale is parsed with the Fluent
+//! parser and compared with the keys the sources pass to `t!`:
+//!
+//! - `t!("key", …)` with a literal key, and the hyphenated literals returned by the `*_key` /
+//!   `*_keys` helpers (`label_key`, `message_key`, …) must exist in every locale;
+//! - a message must be quoted somewhere in the sources (or referenced by another message),
+//!   otherwise it is unused;
+//! - every locale must use the same variables in a message, and `t!` calls with a literal key
+//!   must pass exactly those variables;
+//! - plural selects must have a variant for every plural category a whole number can take in
+//!   the language, and counted messages must be plural selects in every locale.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use fluent_syntax::ast::{
+    Entry, Expression, InlineExpression, Pattern, PatternElement, Resource, VariantKey,
+};
+use fluent_syntax::parser;
+use intl_pluralrules::{PluralCategory, PluralRuleType, PluralRules};
+use unic_langid::LanguageIdentifier;
+
+use super::LOCALES;
+
+/// Messages with a number of things in them that must be a plural select in every locale
+const COUNTED_MESSAGES: &[&str] = &[
+    "models-found",
+    "relative-minutes-ago",
+    "relative-hours-ago",
+    "relative-days-ago",
+    "relative-months-ago",
+    "relative-years-ago",
+];
+
+/// A parsed locale file
+struct ParsedLocale {
+    id: LanguageIdentifier,
+    resource: Resource<&'static str>,
+}
+
+impl ParsedLocale {
+    fn messages(&self) -> BTreeMap<&'static str, &Pattern<&'static str>> {
+        self.resource
+            .body
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some((message.id.name, message.value.as_ref()?)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Variables of a message, including those of the messages it references
+    fn variables(&self, key: &str) -> BTreeSet<&'static str> {
+        let messages = self.messages();
+        let mut variables = BTreeSet::new();
+        let mut pending = vec![key];
+        let mut visited = BTreeSet::new();
+        while let Some(key) = pending.pop() {
+            if !visited.insert(key) {
+                continue;
+            }
+            if let Some(pattern) = messages.get(key) {
+                for expression in inline_expressions(pattern) {
+                    match expression {
+                        InlineExpression::VariableReference { id } => {
+                            variables.insert(id.name);
+                        }
+                        InlineExpression::MessageReference { id, .. } => pending.push(id.name),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        variables
+    }
+}
+
+fn parse_locales() -> Vec<ParsedLocale> {
+    LOCALES
+        .iter()
+        .map(|(id, _, source)| {
+            let resource = parser::parse(*source)
+                .unwrap_or_else(|(_, errors)| panic!("{id}.ftl does not parse: {errors:?}"));
+            let junk: Vec<_> = resource
+                .body
+                .iter()
+                .filter_map(|entry| match entry {
+                    Entry::Junk { content } => Some(content),
+                    _ => None,
+                })
+                .collect();
+            assert!(junk.is_empty(), "{id}.ftl has unparsable entries: {junk:?}");
+            ParsedLocale {
+                id: id
+                    .parse()
+                    .expect("locale file names are language identifiers"),
+                resource,
+            }
+        })
+        .collect()
+}
+
+/// Every inline expression of a pattern, including selectors and the ones inside variants
+fn inline_expressions<'p>(
+    pattern: &'p Pattern<&'static str>,
+) -> Vec<&'p InlineExpression<&'static str>> {
+    fn visit_expression<'p>(
+        expression: &'p Expression<&'static str>,
+        found: &mut Vec<&'p InlineExpression<&'static str>>,
+    ) {
+        match expression {
+            Expression::Select { selector, variants } => {
+                visit_inline(selector, found);
+                for variant in variants {
+                    visit_pattern(&variant.value, found);
+                }
+            }
+            Expression::Inline(inline) => visit_inline(inline, found),
+        }
+    }
+
+    fn visit_inline<'p>(
+        inline: &'p InlineExpression<&'static str>,
+        found: &mut Vec<&'p InlineExpression<&'static str>>,
+    ) {
+        found.push(inline);
+        match inline {
+            InlineExpression::Placeable { expression } => visit_expression(expression, found),
+            InlineExpression::FunctionReference { arguments, .. } => {
+                for argument in &arguments.positional {
+                    visit_inline(argument, found);
+                }
+                for argument in &arguments.named {
+                    visit_inline(&argument.value, found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_pattern<'p>(
+        pattern: &'p Pattern<&'static str>,
+        found: &mut Vec<&'p InlineExpression<&'static str>>,
+    ) {
+        for element in &pattern.elements {
+            if let PatternElement::Placeable { expression } = element {
+                visit_expression(expression, found);
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    visit_pattern(pattern, &mut found);
+    found
+}
+
+/// Every select expression of a pattern, including nested ones
+fn selects<'p>(pattern: &'p Pattern<&'static str>) -> Vec<&'p Expression<&'static str>> {
+    fn visit<'p>(
+        pattern: &'p Pattern<&'static str>,
+        found: &mut Vec<&'p Expression<&'static str>>,
+    ) {
+        for element in &pattern.elements {
+            if let PatternElement::Placeable { expression } = element {
+                visit_expression(expression, found);
+            }
+        }
+    }
+
+    fn visit_expression<'p>(
+        expression: &'p Expression<&'static str>,
+        found: &mut Vec<&'p Expression<&'static str>>,
+    ) {
+        match expression {
+            Expression::Select { variants, .. } => {
+                found.push(expression);
+                for variant in variants {
+                    visit(&variant.value, found);
+                }
+            }
+            Expression::Inline(InlineExpression::Placeable { expression }) => {
+                visit_expression(expression, found)
+            }
+            Expression::Inline(_) => {}
+        }
+    }
+
+    let mut found = Vec::new();
+    visit(pattern, &mut found);
+    found
+}
+
+fn plural_category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::ZERO => "zero",
+        PluralCategory::ONE => "one",
+        PluralCategory::TWO => "two",
+        PluralCategory::FEW => "few",
+        PluralCategory::MANY => "many",
+        PluralCategory::OTHER => "other",
+    }
+}
+
+/// Plural categories a whole number can take in the language, e.g. `one` and `other` in English
+fn whole_number_categories(language: &LanguageIdentifier) -> BTreeSet<&'static str> {
+    // Plural rules are per language, without the region
+    let bare = LanguageIdentifier::from_parts(language.language, None, None, &[]);
+    let rules = PluralRules::create(bare, PluralRuleType::CARDINAL)
+        .unwrap_or_else(|error| panic!("no plural rules for {language}: {error}"));
+    (0..1000u32)
+        .map(|n| plural_category_name(rules.select(n).expect("whole numbers have a category")))
+        .collect()
+}
+
+/// Variant keys of a select on a variable, if every key is a plural category or a number
+fn plural_variant_keys(select: &Expression<&'static str>) -> Option<BTreeSet<&'static str>> {
+    let Expression::Select { selector, variants } = select else {
+        return None;
+    };
+    if !matches!(selector, InlineExpression::VariableReference { .. }) {
+        return None;
+    }
+    let mut keys = BTreeSet::new();
+    for variant in variants {
+        match variant.key {
+            VariantKey::Identifier { name } => {
+                if !["zero", "one", "two", "few", "many", "other"].contains(&name) {
+                    return None;
+                }
+                keys.insert(name);
+            }
+            VariantKey::NumberLiteral { .. } => {}
+        }
+    }
+    Some(keys)
+}
+
+/// A `t!` call found in the sources
+#[derive(Debug)]
+struct Translation {
+    location: String,
+    /// The literal key, or `None` for a key computed at run time
+    key: Option<String>,
+    /// Names of the named arguments
+    arguments: BTreeSet<String>,
+}
+
+/// Rust sources of the crate with their paths, `build.rs` included and this file excluded
+fn sources() -> Vec<(String, String)> {
+    fn collect(dir: &Path, found: &mut Vec<(String, String)>) {
+        for entry in fs::read_dir(dir).expect("source directory is readable") {
+            let path = entry.expect("source entry is readable").path();
+            if path.is_dir() {
+                collect(&path, found);
+            } else if path.extension().is_some_and(|ext| ext == "rs")
+                // This file quotes `t!` calls as examples
+                && !path.ends_with(file!())
+            {
+                let source = fs::read_to_string(&path).expect("source file is readable");
+                found.push((path.display().to_string(), source));
+            }
+        }
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut found = Vec::new();
+    collect(&root.join("src"), &mut found);
+    let build_script = root.join("build.rs");
+    let source = fs::read_to_string(&build_script).expect("build.rs is readable");
+    found.push((build_script.display().to_string(), source));
+    found
+}
+
+fn is_message_id(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_lowercase())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !text.ends_with('-')
+}
+
+/// Message-id-like string literals, including the escaped ones inside format strings
+fn quoted_ids(source: &str) -> impl Iterator<Item = &str> {
+    source
+        .split('"')
+        .map(|piece| piece.strip_suffix('\\').unwrap_or(piece))
+        .filter(|piece| is_message_id(piece))
+}
+
+/// Index just past the bracket closing the one before `start`, skipping string literals
+fn closing_bracket(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let (mut depth, mut in_string, mut i) = (1, false, start);
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'"' => in_string = !in_string,
+            b'(' | b'[' | b'{' if !in_string => depth += 1,
+            b')' | b']' | b'}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Split the arguments of a macro call on its top-level commas
+fn top_level_arguments(arguments: &str) -> Vec<&str> {
+    let bytes = arguments.as_bytes();
+    let (mut depth, mut in_string, mut start, mut i) = (0, false, 0, 0);
+    let mut split = Vec::new();
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'"' => in_string = !in_string,
+            b'(' | b'[' | b'{' if !in_string => depth += 1,
+            b')' | b']' | b'}' if !in_string => depth -= 1,
+            b',' if !in_string && depth == 0 => {
+                split.push(&arguments[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    split.push(&arguments[start..]);
+    split
+}
+
+/// Every `t!(…)` call of a source file
+fn translations(path: &str, source: &str) -> Vec<Translation> {
+    let mut found = Vec::new();
+    for (position, _) in source.match_indices("t!(") {
+        let preceding = source[..position].chars().next_back();
+        if preceding.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let start = position + "t!(".len();
+        let end = closing_bracket(source, start);
+        let mut arguments = top_level_arguments(&source[start..end - 1]).into_iter();
+        let key = arguments.next().unwrap_or_default().trim();
+        let literal = key
+            .strip_prefix("\\\"")
+            .and_then(|key| key.strip_suffix("\\\""))
+            .or_else(|| key.strip_prefix('"').and_then(|key| key.strip_suffix('"')));
+        let line = source[..position].lines().count();
+        found.push(Translation {
+            location: format!("{path}:{line}"),
+            key: literal.map(str::to_string),
+            arguments: arguments
+                .filter_map(|argument| {
+                    let (name, _) = argument.split_once(':')?;
+                    let name = name.trim();
+                    (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+                        .then(|| name.to_string())
+                })
+                .collect(),
+        });
+    }
+    found
+}
+
+/// Keys returned by the `*_key` / `*_keys` helpers that map values to messages
+fn helper_keys(source: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    for (position, _) in source.match_indices("fn ") {
+        let name: String = source[position + 3..]
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if !(name.ends_with("_key") || name.ends_with("_keys")) {
+            continue;
+        }
+        let Some(body) = source[position..]
+            .find('{')
+            .map(|offset| position + offset + 1)
+        else {
+            continue;
+        };
+        let end = closing_bracket(source, body);
+        keys.extend(quoted_ids(&source[body..end]).filter(|key| key.contains('-')));
+    }
+    keys
+}
+
+#[test]
+fn test_locales_define_the_same_messages() {
+    let locales = parse_locales();
+    let all: BTreeSet<&str> = locales
+        .iter()
+        .flat_map(|locale| locale.messages().into_keys())
+        .collect();
+    for locale in &locales {
+        let messages = locale.messages();
+        let missing: Vec<_> = all
+            .iter()
+            .filter(|key| !messages.contains_key(*key))
+            .collect();
+        assert!(missing.is_empty(), "{}.ftl lacks {missing:?}", locale.id);
+    }
+}
+
+#[test]
+fn test_used_keys_exist() {
+    let locales = parse_locales();
+    let mut missing = Vec::new();
+    for (path, source) in sources() {
+        let used = translations(&path, &source)
+            .into_iter()
+            .filter_map(|translation| Some((translation.key?, translation.location)))
+            .chain(
+                helper_keys(&source)
+                    .into_iter()
+                    .map(|key| (key.to_string(), path.clone())),
+            );
+        for (key, location) in used {
+            for locale in &locales {
+                if !locale.messages().contains_key(key.as_str()) {
+                    missing.push(format!(
+                        "{location}: `{key}` is missing from {}.ftl",
+                        locale.id
+                    ));
+                }
+            }
+        }
+    }
+    assert!(missing.is_empty(), "{missing:#?}");
+}
+
+#[test]
+fn test_no_unused_messages() {
+    let locales = parse_locales();
+    let sources = sources();
+    let mut used: BTreeSet<&str> = sources
+        .iter()
+        .flat_map(|(_, source)| quoted_ids(source))
+        .collect();
+    // Messages only reached through other messages
+    for locale in &locales {
+        for pattern in locale.messages().values() {
+            used.extend(
+                inline_expressions(pattern)
+                    .into_iter()
+                    .filter_map(|expression| match expression {
+                        InlineExpression::MessageReference { id, .. } => Some(id.name),
+                        _ => None,
+                    }),
+            );
+        }
+    }
+    for locale in &locales {
+        let unused: Vec<_> = locale
+            .messages()
+            .into_keys()
+            .filter(|key| !used.contains(key))
+            .collect();
+        assert!(
+            unused.is_empty(),
+            "{}.ftl has unused messages: {unused:?}",
+            locale.id
+        );
+    }
+}
+
+#[test]
+fn test_variables_match() {
+    let locales = parse_locales();
+    let (reference, others) = locales.split_first().expect("at least one locale");
+    for key in reference.messages().into_keys() {
+        let expected = reference.variables(key);
+        for locale in others {
+            assert_eq!(
+                locale.variables(key),
+                expected,
+                "`{key}` uses different variables in {}.ftl and {}.ftl",
+                locale.id,
+                reference.id
+            );
+        }
+    }
+
+    let mut mismatched = Vec::new();
+    for (path, source) in sources() {
+        for translation in translations(&path, &source) {
+            let Some(key) = &translation.key else {
+                continue;
+            };
+            let expected: BTreeSet<String> = reference
+                .variables(key)
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            if translation.arguments != expected {
+                mismatched.push(format!(
+                    "{}: `{key}` takes {expected:?}, given {:?}",
+                    translation.location, translation.arguments
+                ));
+            }
+        }
+    }
+    assert!(mismatched.is_empty(), "{mismatched:#?}");
+}
+
+#[test]
+fn test_plural_forms() {
+    for locale in parse_locales() {
+        let categories = whole_number_categories(&locale.id);
+        for (key, pattern) in locale.messages() {
+            let plurals: Vec<_> = selects(pattern)
+                .into_iter()
+                .filter_map(plural_variant_keys)
+                .collect();
+            assert!(
+                !COUNTED_MESSAGES.contains(&key) || !plurals.is_empty(),
+                "`{key}` in {}.ftl must select on the plural form of its count",
+                locale.id
+            );
+            for keys in plurals {
+                let missing: Vec<_> = categories.difference(&keys).collect();
+                assert!(
+                    missing.is_empty(),
+                    "`{key}` in {}.ftl lacks plural variants {missing:?}",
+                    locale.id
+                );
+            }
+            // "model(s)" is a sign of a missing plural select
+            let text: String = pattern
+                .elements
+                .iter()
+                .filter_map(|element| match element {
+                    PatternElement::TextElement { value } => Some(*value),
+                    _ => None,
+                })
+                .collect();
+            assert!(
+                !text.contains("(s)"),
+                "`{key}` in {}.ftl fakes a plural with \"(s)\"",
+                locale.id
+            );
+        }
+    }
+}
+
+#[test]
+fn test_source_scanner() {
+    let source = r#"
+        rsx! { "{t!(\"preset-save\")}" }
+        t!("playground-usage", prompt: f(a, b), cost: money.format(x), seconds: seconds)
+        assert!(html.contains("not-a-call"));
+        t!(label.label_key(), unit: unit)
+    "#;
+    let found = translations("lib.rs", source);
+    assert_eq!(found.len(), 3, "{found:?}");
+    assert_eq!(found[0].key.as_deref(), Some("preset-save"));
+    assert!(found[0].arguments.is_empty());
+    assert_eq!(found[1].key.as_deref(), Some("playground-usage"));
+    assert_eq!(
+        found[1].arguments,
+        BTreeSet::from(["prompt", "cost", "seconds"].map(String::from))
+    );
+    assert_eq!(found[2].key, None);
+    assert_eq!(found[2].arguments, BTreeSet::from(["unit".to_string()]));
+
+    let helpers = r#"
+        pub fn label_key(&self) -> &'static str {
+            match self { A::B => "sort-name", A::C => "sort-price" }
+        }
+        pub fn keys(&self) -> &'static [&'static str] { &["j", "enter-key"] }
+    "#;
+    assert_eq!(helper_keys(helpers), ["sort-name", "sort-price"]);
+}
@@ -1,106 +1,151 @@
-use dioxus::prelude::*;
 use dioxus_i18n::prelude::*;
-use unic_langid::langid;
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use unic_langid::{langid, LanguageIdentifier};
+
+use crate::storage;
+
+#[cfg(test)]
+mod consistency;
+
+// Registry of the `.ftl` files in `locales/`, generated by `build.rs`
+include!(concat!(env!("OUT_DIR"), "/locales.rs"));
 
 /// Storage key for persisting language preference
-#[cfg(target_arch = "wasm32")]
 const LANGUAGE_STORAGE_KEY: &str = "polza-models-language";
 
-/// Initialize i18n with browser detection and LocalStorage persistence
+/// Language used when none of the preferred languages is bundled
+pub const DEFAULT_LANGUAGE: LanguageIdentifier = langid!("en-US");
+
+/// A bundled language: identifier and the language's own name (e.g. "Русский")
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageOption {
+    pub id: LanguageIdentifier,
+    pub name: &'static str,
+}
+
+/// Every bundled language, ordered by identifier
+pub fn available_languages() -> Vec<LanguageOption> {
+    LOCALES
+        .iter()
+        .filter_map(|(id, name, _)| {
+            Some(LanguageOption {
+                id: id.parse().ok()?,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Initialize i18n with browser detection and persisted preference
 pub fn init_i18n() -> I18nConfig {
-    // Detect browser language or load from LocalStorage
-    let initial_language = detect_language();
-
-    I18nConfig::new(initial_language)
-        .with_locale(Locale::new_static(
-            langid!("en-US"),
-            include_str!("../../locales/en-US.ftl"),
-        ))
-        .with_locale(Locale::new_static(
-            langid!("ru-RU"),
-            include_str!("../../locales/ru-RU.ftl"),
-        ))
+    // Detect browser language or load the stored preference
+    i18n_config(detect_language())
 }
 
-/// Detect the initial language from LocalStorage or browser settings
-fn detect_language() -> unic_langid::LanguageIdentifier {
-    // Try to load from LocalStorage first (WASM only)
-    #[cfg(target_arch = "wasm32")]
-    {
-        use gloo_storage::{LocalStorage, Storage};
+/// Configuration with every bundled locale, starting in `language`.
+///
+/// Messages missing from a translation fall back to [`DEFAULT_LANGUAGE`].
+pub fn i18n_config(language: LanguageIdentifier) -> I18nConfig {
+    LOCALES
+        .iter()
+        .filter_map(|(id, _, source)| Some((id.parse::<LanguageIdentifier>().ok()?, *source)))
+        .fold(
+            I18nConfig::new(language).with_fallback(DEFAULT_LANGUAGE),
+            |config, (id, source)| config.with_locale(Locale::new_static(id, source)),
+        )
+}
+
+/// Best bundled language for the user's preferred languages, most preferred first.
+///
+/// Matches on language and region with fallbacks, so "ru" and "ru-UA" both pick ru-RU;
+/// falls back to [`DEFAULT_LANGUAGE`].
+pub fn negotiate_language<S: AsRef<str>>(requested: &[S]) -> LanguageIdentifier {
+    let requested: Vec<LanguageIdentifier> = requested
+        .iter()
+        .filter_map(|language| language.as_ref().parse().ok())
+        .collect();
+    let available: Vec<LanguageIdentifier> = available_languages()
+        .into_iter()
+        .map(|option| option.id)
+        .collect();
+    negotiate_languages(
+        &requested,
+        &available,
+        Some(&DEFAULT_LANGUAGE),
+        NegotiationStrategy::Lookup,
+    )
+    .first()
+    .map(|language| (*language).clone())
+    .unwrap_or(DEFAULT_LANGUAGE)
+}
 
-        if let Ok(stored_lang) = LocalStorage::get::<String>(LANGUAGE_STORAGE_KEY) {
-            #[cfg(target_arch = "wasm32")]
-            gloo_console::log!("[i18n] Loaded language from LocalStorage:", &stored_lang);
+/// Detect the visitor's language from storage or browser settings
+pub fn detect_language() -> LanguageIdentifier {
+    // Try to load the stored preference first
+    if let Some(stored_lang) = storage::load::<String>(LANGUAGE_STORAGE_KEY) {
+        #[cfg(target_arch = "wasm32")]
+        gloo_console::log!("[i18n] Loaded stored language:", &stored_lang);
 
-            // Parse stored language
-            return match stored_lang.as_str() {
-                "ru-RU" | "ru" => langid!("ru-RU"),
-                _ => langid!("en-US"),
-            };
-        }
+        return negotiate_language(&[stored_lang]);
+    }
 
-        // If not in storage, try to detect from browser
+    // If not in storage, negotiate against the browser's preferred languages (WASM only)
+    #[cfg(target_arch = "wasm32")]
+    {
         if let Some(window) = web_sys::window() {
-            if let Some(navigator) = window.navigator().language() {
-                #[cfg(target_arch = "wasm32")]
-                gloo_console::log!("[i18n] Browser language:", &navigator);
-
-                // Check if Russian is preferred
-                if navigator.starts_with("ru") {
-                    return langid!("ru-RU");
-                }
-            }
+            let preferred: Vec<String> = window
+                .navigator()
+                .languages()
+                .iter()
+                .filter_map(|language| language.as_string())
+                .collect();
+            gloo_console::log!("[i18n] Browser languages:", preferred.join(", "));
+            return negotiate_language(&preferred);
         }
     }
 
-    // Default to English
-    langid!("en-US")
+    DEFAULT_LANGUAGE
 }
 
-/// Save language preference to LocalStorage (WASM only)
-#[cfg(target_arch = "wasm32")]
-pub fn save_language_preference(lang_id: &unic_langid::LanguageIdentifier) {
-    use gloo_storage::{LocalStorage, Storage};
-
+/// Save language preference to storage
+pub fn save_language_preference(lang_id: &LanguageIdentifier) {
     let lang_str = lang_id.to_string();
-    let _ = LocalStorage::set(LANGUAGE_STORAGE_KEY, &lang_str);
+    storage::save(LANGUAGE_STORAGE_KEY, &lang_str);
 
     #[cfg(target_arch = "wasm32")]
-    gloo_console::log!("[i18n] Saved language to LocalStorage:", &lang_str);
+    gloo_console::log!("[i18n] Saved language preference:", &lang_str);
 }
 
-/// Save language preference to LocalStorage (non-WASM stub)
-#[cfg(not(target_arch = "wasm32"))]
-pub fn save_language_preference(_lang_id: &unic_langid::LanguageIdentifier) {
-    // No-op on non-WASM platforms
-}
-
-/// Toggle between English and Russian
-pub fn toggle_language(i18n: &mut Signal<I18n>) {
-    // Get current language from i18n context
-    let current_lang = {
-        let i18n_read = i18n.read();
-        i18n_read.language().clone()
-    };
-
-    let new_lang = if current_lang == langid!("en-US") {
-        langid!("ru-RU")
-    } else {
-        langid!("en-US")
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lists_locale_files() {
+        let languages = available_languages();
+        assert!(languages.len() >= 2);
+        assert!(languages.contains(&LanguageOption {
+            id: langid!("en-US"),
+            name: "English",
+        }));
+        assert!(languages.contains(&LanguageOption {
+            id: langid!("ru-RU"),
+            name: "Русский",
+        }));
+    }
 
-    #[cfg(target_arch = "wasm32")]
-    gloo_console::log!(
-        "[i18n] Switching language from",
-        current_lang.to_string(),
-        "to",
-        new_lang.to_string()
-    );
-
-    // Save to LocalStorage
-    save_language_preference(&new_lang);
-
-    // Update i18n
-    i18n.write().set_language(new_lang);
+    #[test]
+    fn test_negotiation() {
+        assert_eq!(negotiate_language(&["ru-RU"]), langid!("ru-RU"));
+        assert_eq!(negotiate_language(&["ru"]), langid!("ru-RU"));
+        assert_eq!(negotiate_language(&["ru-UA", "en-US"]), langid!("ru-RU"));
+        // The first bundled language in the preference list wins
+        assert_eq!(
+            negotiate_language(&["ja-JP", "en-GB", "ru"]),
+            langid!("en-US")
+        );
+        assert_eq!(negotiate_language(&["ja-JP"]), DEFAULT_LANGUAGE);
+        assert_eq!(negotiate_language::<&str>(&[]), DEFAULT_LANGUAGE);
+        assert_eq!(negotiate_language(&["not a language"]), DEFAULT_LANGUAGE);
+    }
 }
@@ -2,9 +2,13 @@
 
 pub mod api;
 pub mod cache;
+pub mod clipboard;
 pub mod components;
+pub mod history;
 pub mod i18n;
 pub mod models;
+pub mod prerender;
+pub mod storage;
 pub mod utils;
 
 // Re-export the main App component for convenience
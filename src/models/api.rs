@@ -16,7 +16,8 @@ pub struct Model {
     pub canonical_slug: String,
     pub pricing: Pricing,
     pub architecture: Architecture,
-    #[serde(default)]
+    /// Derived from `name` after fetching; left out of stored snapshots when cleared
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub name_tokens: Vec<String>,
 
     pub top_provider: TopProvider,
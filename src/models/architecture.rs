@@ -4,6 +4,18 @@ use serde::{Deserialize, Serialize};
 pub struct Architecture {
     pub input_modalities: Vec<Modality>,
     pub output_modalities: Vec<Modality>,
+    /// Tokenizer name as reported by the API ("GPT", "Llama3", "cl100k_base", ...)
+    #[serde(default)]
+    pub tokenizer: Option<String>,
+}
+
+impl Architecture {
+    /// Tokenizer family used to count the tokens of a prompt for this model
+    pub fn tokenizer_family(&self) -> TokenizerFamily {
+        self.tokenizer
+            .as_deref()
+            .map_or(TokenizerFamily::Other, TokenizerFamily::from_name)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -16,3 +28,103 @@ pub enum Modality {
     Embeddings,
     Video,
 }
+
+impl Modality {
+    /// Fluent key of the modality name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Modality::Text => "modality-text",
+            Modality::Image => "modality-image",
+            Modality::File => "modality-file",
+            Modality::Audio => "modality-audio",
+            Modality::Embeddings => "modality-embeddings",
+            Modality::Video => "modality-video",
+        }
+    }
+}
+
+/// Group of models sharing a vocabulary, and thus roughly the same token counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenizerFamily {
+    /// Current OpenAI models (`o200k_base`)
+    Gpt,
+    /// Older OpenAI chat and embedding models (`cl100k_base`)
+    Cl100k,
+    Claude,
+    Gemini,
+    Llama,
+    Mistral,
+    Qwen,
+    DeepSeek,
+    Grok,
+    Other,
+}
+
+impl TokenizerFamily {
+    /// Family of a tokenizer name reported by the API; unknown names map to [`TokenizerFamily::Other`]
+    pub fn from_name(name: &str) -> Self {
+        let name = name.trim().to_lowercase();
+        match name.as_str() {
+            "gpt" | "o200k_base" => TokenizerFamily::Gpt,
+            "cl100k_base" => TokenizerFamily::Cl100k,
+            "claude" => TokenizerFamily::Claude,
+            "gemini" => TokenizerFamily::Gemini,
+            "mistral" => TokenizerFamily::Mistral,
+            "deepseek" => TokenizerFamily::DeepSeek,
+            "grok" => TokenizerFamily::Grok,
+            _ if name.starts_with("llama") => TokenizerFamily::Llama,
+            _ if name.starts_with("qwen") => TokenizerFamily::Qwen,
+            _ => TokenizerFamily::Other,
+        }
+    }
+
+    /// Fluent key of the family name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            TokenizerFamily::Gpt => "tokenizer-gpt",
+            TokenizerFamily::Cl100k => "tokenizer-cl100k",
+            TokenizerFamily::Claude => "tokenizer-claude",
+            TokenizerFamily::Gemini => "tokenizer-gemini",
+            TokenizerFamily::Llama => "tokenizer-llama",
+            TokenizerFamily::Mistral => "tokenizer-mistral",
+            TokenizerFamily::Qwen => "tokenizer-qwen",
+            TokenizerFamily::DeepSeek => "tokenizer-deepseek",
+            TokenizerFamily::Grok => "tokenizer-grok",
+            TokenizerFamily::Other => "tokenizer-other",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenizer_family_from_name() {
+        assert_eq!(TokenizerFamily::from_name("GPT"), TokenizerFamily::Gpt);
+        assert_eq!(
+            TokenizerFamily::from_name("cl100k_base"),
+            TokenizerFamily::Cl100k
+        );
+        assert_eq!(TokenizerFamily::from_name("Llama3"), TokenizerFamily::Llama);
+        assert_eq!(TokenizerFamily::from_name("Qwen3"), TokenizerFamily::Qwen);
+        assert_eq!(TokenizerFamily::from_name("Router"), TokenizerFamily::Other);
+    }
+
+    #[test]
+    fn test_tokenizer_is_kept() {
+        let architecture: Architecture = serde_json::from_str(
+            r#"{"input_modalities": ["text"], "output_modalities": ["text"], "tokenizer": "Gemini"}"#,
+        )
+        .unwrap();
+        assert_eq!(architecture.tokenizer.as_deref(), Some("Gemini"));
+        assert_eq!(architecture.tokenizer_family(), TokenizerFamily::Gemini);
+
+        // Cached catalogs saved before the field existed still load
+        let architecture: Architecture = serde_json::from_str(
+            r#"{"input_modalities": ["text"], "output_modalities": ["text"]}"#,
+        )
+        .unwrap();
+        assert_eq!(architecture.tokenizer_family(), TokenizerFamily::Other);
+    }
+}
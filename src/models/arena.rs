@@ -0,0 +1,179 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Models picked for the arena, by id, in the order they were added
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArenaSelection(Vec<String>);
+
+impl ArenaSelection {
+    /// Fewest models an arena run compares
+    pub const MIN_MODELS: usize = 2;
+    /// Most models shown side by side
+    pub const MAX_MODELS: usize = 4;
+
+    pub fn ids(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.iter().any(|selected| selected == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.0.len() >= Self::MAX_MODELS
+    }
+
+    /// Whether enough models are selected to start a run
+    pub fn can_run(&self) -> bool {
+        self.0.len() >= Self::MIN_MODELS
+    }
+
+    /// Remove the model if selected, add it otherwise.
+    ///
+    /// Returns `false` (and changes nothing) when adding would exceed [`Self::MAX_MODELS`].
+    pub fn toggle(&mut self, id: &str) -> bool {
+        if let Some(position) = self.0.iter().position(|selected| selected == id) {
+            self.0.remove(position);
+        } else if self.is_full() {
+            return false;
+        } else {
+            self.0.push(id.to_owned());
+        }
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Progress of a single model's answer in an arena run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArenaStatus {
+    /// Request sent, nothing received yet
+    Waiting,
+    /// Answer is being streamed
+    Streaming,
+    Finished,
+    Failed,
+    Cancelled,
+}
+
+impl ArenaStatus {
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ArenaStatus::Waiting | ArenaStatus::Streaming)
+    }
+}
+
+/// One model's answer in an arena run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArenaResponse {
+    pub model_id: String,
+    pub model_name: String,
+    pub status: ArenaStatus,
+    pub text: String,
+    /// Time until the first streamed token, in milliseconds
+    pub first_token_ms: Option<u64>,
+    /// Time until the answer was complete, in milliseconds
+    pub latency_ms: Option<u64>,
+    pub prompt_tokens: Option<u64>,
+    pub completion_tokens: Option<u64>,
+    /// Cost computed from the reported usage and the model's pricing
+    pub cost: Option<Decimal>,
+    pub error: Option<String>,
+}
+
+impl ArenaResponse {
+    /// Empty answer for a request that has just been sent
+    pub fn waiting(model_id: String, model_name: String) -> Self {
+        ArenaResponse {
+            model_id,
+            model_name,
+            status: ArenaStatus::Waiting,
+            text: String::new(),
+            first_token_ms: None,
+            latency_ms: None,
+            prompt_tokens: None,
+            completion_tokens: None,
+            cost: None,
+            error: None,
+        }
+    }
+}
+
+/// A prompt and the answers of all compared models, exportable for later review
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArenaSession {
+    pub prompt: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub started_at: OffsetDateTime,
+    pub responses: Vec<ArenaResponse>,
+}
+
+impl ArenaSession {
+    /// Export the session as pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_selection_limits() {
+        let mut selection = ArenaSelection::default();
+        assert!(selection.toggle("a"));
+        assert!(!selection.can_run());
+        for id in ["b", "c", "d"] {
+            assert!(selection.toggle(id));
+        }
+        assert!(selection.can_run());
+        assert!(selection.is_full());
+
+        // A fifth model is refused, removing one frees a slot
+        assert!(!selection.toggle("e"));
+        assert!(!selection.contains("e"));
+        assert!(selection.toggle("b"));
+        assert!(selection.toggle("e"));
+        assert_eq!(selection.ids(), ["a", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn test_session_json() {
+        let mut response = ArenaResponse::waiting("openai/gpt-4o".into(), "GPT-4o".into());
+        response.status = ArenaStatus::Finished;
+        response.text = "Hi!".into();
+        response.latency_ms = Some(1250);
+        response.prompt_tokens = Some(8);
+        response.completion_tokens = Some(3);
+        response.cost = Some(Decimal::new(42, 6));
+
+        let session = ArenaSession {
+            prompt: "Hello!".into(),
+            started_at: datetime!(2025-10-01 12:00 UTC),
+            responses: vec![response],
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&session.to_json()).unwrap();
+        assert_eq!(json["started_at"], "2025-10-01T12:00:00Z");
+        assert_eq!(json["responses"][0]["status"], "finished");
+        assert_eq!(json["responses"][0]["latency_ms"], 1250);
+        assert_eq!(json["responses"][0]["cost"], "0.000042");
+
+        let restored: ArenaResponse = serde_json::from_value(json["responses"][0].clone()).unwrap();
+        assert_eq!(restored, session.responses[0]);
+    }
+}
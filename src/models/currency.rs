@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::macros::date;
+use time::Date;
+
+/// Currency prices are displayed in; the catalog itself is priced in rubles
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    #[default]
+    Rub,
+    Usd,
+    Eur,
+    Cny,
+}
+
+impl Currency {
+    /// All currencies in the order they are offered to the user
+    pub const ALL: [Currency; 4] = [Currency::Rub, Currency::Usd, Currency::Eur, Currency::Cny];
+
+    /// ISO 4217 code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Rub => "RUB",
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Cny => "CNY",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Rub => "₽",
+            Currency::Usd => "$",
+            Currency::Eur => "€",
+            Currency::Cny => "¥",
+        }
+    }
+
+    /// Fluent key of the currency name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Currency::Rub => "currency-rub",
+            Currency::Usd => "currency-usd",
+            Currency::Eur => "currency-eur",
+            Currency::Cny => "currency-cny",
+        }
+    }
+}
+
+/// Price of one unit of a currency in rubles, and the day it was taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    pub rub_per_unit: Decimal,
+    pub as_of: Date,
+}
+
+impl ExchangeRate {
+    /// Day the bundled reference rates were taken
+    pub const BUNDLED_AS_OF: Date = date!(2025 - 10 - 01);
+
+    /// Reference rate shipped with the app; `None` for the ruble itself
+    pub fn bundled(currency: Currency) -> Option<Self> {
+        let rub_per_unit = match currency {
+            Currency::Rub => return None,
+            Currency::Usd => Decimal::new(8287, 2),
+            Currency::Eur => Decimal::new(9725, 2),
+            Currency::Cny => Decimal::new(1162, 2),
+        };
+        Some(ExchangeRate {
+            rub_per_unit,
+            as_of: Self::BUNDLED_AS_OF,
+        })
+    }
+}
+
+/// Display currency and the exchange rates entered by the user (persisted)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrencySettings {
+    pub currency: Currency,
+    /// Rates overriding the bundled ones
+    pub custom_rates: BTreeMap<Currency, ExchangeRate>,
+}
+
+impl CurrencySettings {
+    /// Rate used to convert into `currency`: the user's own if set, the bundled one otherwise
+    pub fn rate(&self, currency: Currency) -> Option<ExchangeRate> {
+        self.custom_rates
+            .get(&currency)
+            .copied()
+            .or_else(|| ExchangeRate::bundled(currency))
+    }
+
+    /// Whether the rate for `currency` was entered by the user
+    pub fn has_custom_rate(&self, currency: Currency) -> bool {
+        self.custom_rates.contains_key(&currency)
+    }
+
+    /// Use the user's rate for `currency`; non-positive rates are ignored
+    pub fn set_custom_rate(&mut self, currency: Currency, rub_per_unit: Decimal, as_of: Date) {
+        if currency != Currency::Rub && rub_per_unit > Decimal::ZERO {
+            self.custom_rates.insert(
+                currency,
+                ExchangeRate {
+                    rub_per_unit,
+                    as_of,
+                },
+            );
+        }
+    }
+
+    /// Go back to the bundled rate for `currency`
+    pub fn reset_rate(&mut self, currency: Currency) {
+        self.custom_rates.remove(&currency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_rates() {
+        assert_eq!(ExchangeRate::bundled(Currency::Rub), None);
+        for currency in Currency::ALL.into_iter().skip(1) {
+            let rate = ExchangeRate::bundled(currency).unwrap();
+            assert!(rate.rub_per_unit > Decimal::ZERO);
+            assert_eq!(rate.as_of, ExchangeRate::BUNDLED_AS_OF);
+        }
+    }
+
+    #[test]
+    fn test_custom_rate_overrides_bundled() {
+        let mut settings = CurrencySettings::default();
+        settings.set_custom_rate(Currency::Usd, Decimal::new(90, 0), date!(2025 - 11 - 02));
+        assert!(settings.has_custom_rate(Currency::Usd));
+        assert_eq!(
+            settings.rate(Currency::Usd),
+            Some(ExchangeRate {
+                rub_per_unit: Decimal::new(90, 0),
+                as_of: date!(2025 - 11 - 02),
+            })
+        );
+
+        // Nonsensical rates are rejected
+        settings.set_custom_rate(Currency::Eur, Decimal::ZERO, date!(2025 - 11 - 02));
+        assert!(!settings.has_custom_rate(Currency::Eur));
+
+        settings.reset_rate(Currency::Usd);
+        assert_eq!(
+            settings.rate(Currency::Usd),
+            ExchangeRate::bundled(Currency::Usd)
+        );
+    }
+
+    #[test]
+    fn test_settings_roundtrip() {
+        let mut settings = CurrencySettings {
+            currency: Currency::Eur,
+            ..Default::default()
+        };
+        settings.set_custom_rate(Currency::Eur, Decimal::new(1005, 1), date!(2025 - 11 - 02));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert!(json.contains(r#""currency":"EUR""#), "{json}");
+        let restored: CurrencySettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, settings);
+    }
+}
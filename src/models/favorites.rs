@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+/// Favorite models, keyed by `canonical_slug` so they survive renames
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Favorites(BTreeSet<String>);
+
+impl Favorites {
+    pub fn contains(&self, slug: &str) -> bool {
+        self.0.contains(slug)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add the slug if missing, remove it otherwise
+    pub fn toggle(&mut self, slug: &str) {
+        if !self.0.remove(slug) {
+            self.0.insert(slug.to_owned());
+        }
+    }
+
+    /// Export favorites as a pretty-printed JSON array of slugs
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.0).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Merge favorites from a JSON array of slugs, returning how many were new
+    pub fn import_json(&mut self, json: &str) -> Result<usize, serde_json::Error> {
+        let imported: Favorites = serde_json::from_str(json)?;
+        let before = self.0.len();
+        self.0.extend(
+            imported
+                .0
+                .into_iter()
+                .map(|slug| slug.trim().to_owned())
+                .filter(|slug| !slug.is_empty()),
+        );
+        Ok(self.0.len() - before)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let mut favorites = Favorites::default();
+        favorites.toggle("openai/gpt-4o");
+        assert!(favorites.contains("openai/gpt-4o"));
+        assert_eq!(favorites.len(), 1);
+
+        favorites.toggle("openai/gpt-4o");
+        assert!(!favorites.contains("openai/gpt-4o"));
+        assert!(favorites.is_empty());
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let mut favorites = Favorites::default();
+        favorites.toggle("b/model");
+        favorites.toggle("a/model");
+
+        let json = favorites.to_json();
+        let mut restored = Favorites::default();
+        assert_eq!(restored.import_json(&json).unwrap(), 2);
+        assert_eq!(restored, favorites);
+    }
+
+    #[test]
+    fn test_import_merges_and_counts_new() {
+        let mut favorites = Favorites::default();
+        favorites.toggle("a/model");
+
+        let added = favorites
+            .import_json(r#"["a/model", "b/model", "  ", " c/model "]"#)
+            .unwrap();
+        assert_eq!(added, 2);
+        assert!(favorites.contains("b/model"));
+        assert!(favorites.contains("c/model"));
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_json() {
+        let mut favorites = Favorites::default();
+        assert!(favorites.import_json("{not json").is_err());
+        assert!(favorites.import_json(r#"{"a": 1}"#).is_err());
+        assert!(favorites.is_empty());
+    }
+}
@@ -1,9 +1,21 @@
 mod api;
 mod architecture;
+mod arena;
+mod currency;
+mod favorites;
+mod parameter;
+mod preset;
 mod pricing;
+mod recency;
 mod ui;
 
 pub use api::{ApiResponse, Model, TopProvider};
-pub use architecture::{Architecture, Modality};
-pub use pricing::Pricing;
-pub use ui::{SortDirection, SortField};
+pub use architecture::{Architecture, Modality, TokenizerFamily};
+pub use arena::{ArenaResponse, ArenaSelection, ArenaSession, ArenaStatus};
+pub use currency::{Currency, CurrencySettings, ExchangeRate};
+pub use favorites::Favorites;
+pub use parameter::{parameter_label_keys, ParameterError, ParameterKind};
+pub use preset::{name_taken, FilterPreset};
+pub use pricing::{BilledTokens, PriceUnit, Pricing, PricingField};
+pub use recency::RecencyWindow;
+pub use ui::{ChartAxis, Page, SortDirection, SortField, SortKey, TableColumn, Theme, ViewMode};
@@ -0,0 +1,186 @@
+use serde_json::Value;
+
+/// Input control for a request parameter listed in `supported_parameters`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterKind {
+    /// Number within `min..=max`, edited in `step` increments
+    Float { min: f64, max: f64, step: f64 },
+    /// Whole number of at least `min`
+    Integer { min: i64 },
+    /// Free text (e.g. a stop sequence)
+    Text,
+}
+
+impl ParameterKind {
+    /// Control for a known parameter name; `None` for parameters that cannot be
+    /// edited as a single value (tools, response_format, logit_bias, ...)
+    pub fn for_parameter(name: &str) -> Option<Self> {
+        Some(match name {
+            "temperature" => ParameterKind::Float {
+                min: 0.0,
+                max: 2.0,
+                step: 0.1,
+            },
+            "top_p" | "min_p" | "top_a" => ParameterKind::Float {
+                min: 0.0,
+                max: 1.0,
+                step: 0.05,
+            },
+            "frequency_penalty" | "presence_penalty" => ParameterKind::Float {
+                min: -2.0,
+                max: 2.0,
+                step: 0.1,
+            },
+            "repetition_penalty" => ParameterKind::Float {
+                min: 0.0,
+                max: 2.0,
+                step: 0.05,
+            },
+            "max_tokens" => ParameterKind::Integer { min: 1 },
+            "top_k" | "seed" => ParameterKind::Integer { min: 0 },
+            "stop" => ParameterKind::Text,
+            _ => return None,
+        })
+    }
+
+    /// Convert the text typed into the control to a JSON request value.
+    ///
+    /// Blank input means "use the provider default" and yields `Ok(None)`; numbers
+    /// outside the allowed range are rejected rather than silently clamped.
+    pub fn parse(&self, input: &str) -> Result<Option<Value>, ParameterError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+        match *self {
+            ParameterKind::Float { min, max, .. } => {
+                let value: f64 = input.parse().map_err(|_| ParameterError::NotANumber)?;
+                if !(min..=max).contains(&value) {
+                    return Err(ParameterError::OutOfRange);
+                }
+                Ok(Some(Value::from(value)))
+            }
+            ParameterKind::Integer { min } => {
+                let value: i64 = input.parse().map_err(|_| ParameterError::NotANumber)?;
+                if value < min {
+                    return Err(ParameterError::OutOfRange);
+                }
+                Ok(Some(Value::from(value)))
+            }
+            ParameterKind::Text => Ok(Some(Value::from(input))),
+        }
+    }
+}
+
+/// Fluent keys of the display name and the description of a request parameter.
+///
+/// `None` for parameters without a translation, which are shown by their API identifier.
+pub fn parameter_label_keys(name: &str) -> Option<(&'static str, &'static str)> {
+    Some(match name {
+        "temperature" => ("parameter-temperature", "parameter-temperature-description"),
+        "top_p" => ("parameter-top-p", "parameter-top-p-description"),
+        "top_k" => ("parameter-top-k", "parameter-top-k-description"),
+        "min_p" => ("parameter-min-p", "parameter-min-p-description"),
+        "top_a" => ("parameter-top-a", "parameter-top-a-description"),
+        "frequency_penalty" => (
+            "parameter-frequency-penalty",
+            "parameter-frequency-penalty-description",
+        ),
+        "presence_penalty" => (
+            "parameter-presence-penalty",
+            "parameter-presence-penalty-description",
+        ),
+        "repetition_penalty" => (
+            "parameter-repetition-penalty",
+            "parameter-repetition-penalty-description",
+        ),
+        "max_tokens" => ("parameter-max-tokens", "parameter-max-tokens-description"),
+        "seed" => ("parameter-seed", "parameter-seed-description"),
+        "stop" => ("parameter-stop", "parameter-stop-description"),
+        "tools" => ("parameter-tools", "parameter-tools-description"),
+        "tool_choice" => ("parameter-tool-choice", "parameter-tool-choice-description"),
+        "response_format" => (
+            "parameter-response-format",
+            "parameter-response-format-description",
+        ),
+        "structured_outputs" => (
+            "parameter-structured-outputs",
+            "parameter-structured-outputs-description",
+        ),
+        "logit_bias" => ("parameter-logit-bias", "parameter-logit-bias-description"),
+        "logprobs" => ("parameter-logprobs", "parameter-logprobs-description"),
+        "top_logprobs" => (
+            "parameter-top-logprobs",
+            "parameter-top-logprobs-description",
+        ),
+        "reasoning" => ("parameter-reasoning", "parameter-reasoning-description"),
+        "include_reasoning" => (
+            "parameter-include-reasoning",
+            "parameter-include-reasoning-description",
+        ),
+        "dimensions" => ("parameter-dimensions", "parameter-dimensions-description"),
+        "encoding_format" => (
+            "parameter-encoding-format",
+            "parameter-encoding-format-description",
+        ),
+        _ => return None,
+    })
+}
+
+/// Why a parameter input was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterError {
+    NotANumber,
+    OutOfRange,
+}
+
+impl ParameterError {
+    /// Fluent key of the error message
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ParameterError::NotANumber => "parameter-error-not-a-number",
+            ParameterError::OutOfRange => "parameter-error-out-of-range",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_parameters() {
+        assert!(matches!(
+            ParameterKind::for_parameter("temperature"),
+            Some(ParameterKind::Float { .. })
+        ));
+        assert_eq!(
+            ParameterKind::for_parameter("max_tokens"),
+            Some(ParameterKind::Integer { min: 1 })
+        );
+        assert_eq!(
+            ParameterKind::for_parameter("stop"),
+            Some(ParameterKind::Text)
+        );
+        assert_eq!(ParameterKind::for_parameter("tools"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let temperature = ParameterKind::for_parameter("temperature").unwrap();
+        assert_eq!(temperature.parse(""), Ok(None));
+        assert_eq!(temperature.parse(" 0.7 "), Ok(Some(Value::from(0.7))));
+        assert_eq!(temperature.parse("2.5"), Err(ParameterError::OutOfRange));
+        assert_eq!(temperature.parse("warm"), Err(ParameterError::NotANumber));
+
+        let max_tokens = ParameterKind::for_parameter("max_tokens").unwrap();
+        assert_eq!(max_tokens.parse("256"), Ok(Some(Value::from(256))));
+        assert_eq!(max_tokens.parse("0"), Err(ParameterError::OutOfRange));
+        assert_eq!(max_tokens.parse("1.5"), Err(ParameterError::NotANumber));
+
+        assert_eq!(
+            ParameterKind::Text.parse("###"),
+            Ok(Some(Value::from("###")))
+        );
+    }
+}
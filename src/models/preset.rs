@@ -0,0 +1,136 @@
+use std::collections::{BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Modality, SortKey};
+
+/// A named snapshot of the filter text, modality selections and sort order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    pub name: String,
+    pub filter_text: String,
+    pub input_modalities: BTreeSet<Modality>,
+    pub output_modalities: BTreeSet<Modality>,
+    pub sort_keys: Vec<SortKey>,
+}
+
+impl FilterPreset {
+    /// Capture the current filter state under the given name
+    pub fn capture(
+        name: String,
+        filter_text: &str,
+        input_modalities: &HashSet<Modality>,
+        output_modalities: &HashSet<Modality>,
+        sort_keys: &[SortKey],
+    ) -> Self {
+        Self {
+            name,
+            filter_text: filter_text.to_owned(),
+            input_modalities: input_modalities.iter().copied().collect(),
+            output_modalities: output_modalities.iter().copied().collect(),
+            sort_keys: sort_keys.to_vec(),
+        }
+    }
+
+    /// Check whether the preset describes exactly the given filter state
+    pub fn matches(
+        &self,
+        filter_text: &str,
+        input_modalities: &HashSet<Modality>,
+        output_modalities: &HashSet<Modality>,
+        sort_keys: &[SortKey],
+    ) -> bool {
+        self.filter_text == filter_text
+            && self.input_modalities.len() == input_modalities.len()
+            && self
+                .input_modalities
+                .iter()
+                .all(|m| input_modalities.contains(m))
+            && self.output_modalities.len() == output_modalities.len()
+            && self
+                .output_modalities
+                .iter()
+                .all(|m| output_modalities.contains(m))
+            && self.sort_keys == sort_keys
+    }
+}
+
+/// Whether a preset other than the one at `index` is already called `name`.
+///
+/// Presets are told apart by name (saving under an existing name replaces it), so a rename must
+/// not produce a duplicate.
+pub fn name_taken(presets: &[FilterPreset], index: usize, name: &str) -> bool {
+    presets
+        .iter()
+        .enumerate()
+        .any(|(other, preset)| other != index && preset.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SortDirection, SortField};
+
+    fn cheap_tool_models() -> FilterPreset {
+        FilterPreset::capture(
+            "cheap tool-capable text models".to_string(),
+            "gpt",
+            &HashSet::from([Modality::Text]),
+            &HashSet::from([Modality::Text]),
+            &[SortKey::new(
+                SortField::PromptPrice,
+                SortDirection::Ascending,
+            )],
+        )
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let preset = cheap_tool_models();
+        let json = serde_json::to_string(&preset).unwrap();
+        let restored: FilterPreset = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, preset);
+    }
+
+    #[test]
+    fn test_matches_current_state() {
+        let preset = cheap_tool_models();
+        let sort = [SortKey::new(
+            SortField::PromptPrice,
+            SortDirection::Ascending,
+        )];
+        let text = HashSet::from([Modality::Text]);
+
+        assert!(preset.matches("gpt", &text, &text, &sort));
+
+        // Any difference in text, modalities or sort breaks the match
+        assert!(!preset.matches("gp", &text, &text, &sort));
+        assert!(!preset.matches(
+            "gpt",
+            &HashSet::from([Modality::Text, Modality::Image]),
+            &text,
+            &sort
+        ));
+        assert!(!preset.matches("gpt", &text, &HashSet::new(), &sort));
+        assert!(!preset.matches(
+            "gpt",
+            &text,
+            &text,
+            &[SortKey::new(
+                SortField::PromptPrice,
+                SortDirection::Descending
+            )]
+        ));
+    }
+
+    #[test]
+    fn test_rename_cannot_duplicate_a_name() {
+        let mut other = cheap_tool_models();
+        other.name = "images".to_string();
+        let presets = [cheap_tool_models(), other];
+
+        assert!(name_taken(&presets, 0, "images"));
+        assert!(!name_taken(&presets, 1, "images"), "keeping its own name");
+        assert!(!name_taken(&presets, 0, "vision"));
+    }
+}
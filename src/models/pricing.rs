@@ -25,3 +25,303 @@ impl Pricing {
             && self.input_cache_write.is_zero()
     }
 }
+
+/// A single component of [`Pricing`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PricingField {
+    Prompt,
+    Completion,
+    InternalReasoning,
+    InputCacheRead,
+    InputCacheWrite,
+    Image,
+    Request,
+    WebSearch,
+}
+
+impl PricingField {
+    /// All pricing components in display order
+    pub const ALL: [PricingField; 8] = [
+        PricingField::Prompt,
+        PricingField::Completion,
+        PricingField::InternalReasoning,
+        PricingField::InputCacheRead,
+        PricingField::InputCacheWrite,
+        PricingField::Image,
+        PricingField::Request,
+        PricingField::WebSearch,
+    ];
+
+    /// Whether the price is charged per token (as opposed to per invocation)
+    pub fn is_per_token(&self) -> bool {
+        matches!(
+            self,
+            PricingField::Prompt
+                | PricingField::Completion
+                | PricingField::InternalReasoning
+                | PricingField::InputCacheRead
+                | PricingField::InputCacheWrite
+        )
+    }
+
+    /// Fluent key of the component name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            PricingField::Prompt => "price-field-prompt",
+            PricingField::Completion => "price-field-completion",
+            PricingField::InternalReasoning => "price-field-internal-reasoning",
+            PricingField::InputCacheRead => "price-field-input-cache-read",
+            PricingField::InputCacheWrite => "price-field-input-cache-write",
+            PricingField::Image => "price-field-image",
+            PricingField::Request => "price-field-request",
+            PricingField::WebSearch => "price-field-web-search",
+        }
+    }
+}
+
+/// Number of tokens per-token prices are quoted for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceUnit {
+    Token,
+    Thousand,
+    #[default]
+    Million,
+    Billion,
+}
+
+impl PriceUnit {
+    pub const ALL: [PriceUnit; 4] = [
+        PriceUnit::Token,
+        PriceUnit::Thousand,
+        PriceUnit::Million,
+        PriceUnit::Billion,
+    ];
+
+    pub fn tokens(&self) -> Decimal {
+        match self {
+            PriceUnit::Token => Decimal::ONE,
+            PriceUnit::Thousand => Decimal::from(1_000),
+            PriceUnit::Million => Decimal::from(1_000_000),
+            PriceUnit::Billion => Decimal::from(1_000_000_000),
+        }
+    }
+
+    /// Price of one unit, given the price of a single token.
+    ///
+    /// The multiplier is a power of ten, so the result is exact.
+    pub fn scale(&self, price_per_token: Decimal) -> Decimal {
+        price_per_token
+            .checked_mul(self.tokens())
+            .unwrap_or(Decimal::MAX)
+    }
+
+    /// Value of the `$unit` Fluent variable used by the price labels
+    pub fn fluent_arg(&self) -> &'static str {
+        match self {
+            PriceUnit::Token => "token",
+            PriceUnit::Thousand => "thousand",
+            PriceUnit::Million => "million",
+            PriceUnit::Billion => "billion",
+        }
+    }
+}
+
+impl Pricing {
+    /// Price of a single component
+    pub fn get(&self, field: PricingField) -> Decimal {
+        match field {
+            PricingField::Prompt => self.prompt,
+            PricingField::Completion => self.completion,
+            PricingField::InternalReasoning => self.internal_reasoning,
+            PricingField::InputCacheRead => self.input_cache_read,
+            PricingField::InputCacheWrite => self.input_cache_write,
+            PricingField::Image => self.image,
+            PricingField::Request => self.request,
+            PricingField::WebSearch => self.web_search,
+        }
+    }
+}
+
+impl Pricing {
+    /// Export the prices as pretty-printed JSON, per-token prices quoted per `unit`.
+    ///
+    /// Prices stay decimal strings, so no precision is lost on the way.
+    pub fn to_json(&self, unit: PriceUnit) -> String {
+        let mut prices = serde_json::Map::new();
+        prices.insert("unit".to_string(), serde_json::json!(unit));
+        for field in PricingField::ALL {
+            let price = if field.is_per_token() {
+                unit.scale(self.get(field))
+            } else {
+                self.get(field)
+            };
+            if let Ok(serde_json::Value::String(key)) = serde_json::to_value(field) {
+                prices.insert(key, serde_json::json!(price.normalize()));
+            }
+        }
+        serde_json::to_string_pretty(&prices).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+/// Tokens of one request, split the way they are billed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BilledTokens {
+    /// Input tokens, cached ones included
+    pub prompt: u64,
+    /// Output tokens, reasoning included
+    pub completion: u64,
+    /// Part of the output spent on internal reasoning
+    pub reasoning: u64,
+    /// Part of the input read from the prompt cache
+    pub cache_read: u64,
+    /// Part of the input written to the prompt cache
+    pub cache_write: u64,
+}
+
+impl Pricing {
+    /// Cost of one request.
+    ///
+    /// Reasoning and cached tokens are billed at their own prices; where the model lists no such
+    /// price, they cost as much as ordinary completion or prompt tokens.
+    pub fn usage_cost(&self, tokens: BilledTokens) -> Decimal {
+        let or = |price: Decimal, fallback: Decimal| {
+            if price.is_zero() {
+                fallback
+            } else {
+                price
+            }
+        };
+        let cache_read = tokens.cache_read.min(tokens.prompt);
+        let cache_write = tokens.cache_write.min(tokens.prompt - cache_read);
+        let reasoning = tokens.reasoning.min(tokens.completion);
+
+        self.prompt * Decimal::from(tokens.prompt - cache_read - cache_write)
+            + or(self.input_cache_read, self.prompt) * Decimal::from(cache_read)
+            + or(self.input_cache_write, self.prompt) * Decimal::from(cache_write)
+            + self.completion * Decimal::from(tokens.completion - reasoning)
+            + or(self.internal_reasoning, self.completion) * Decimal::from(reasoning)
+            + self.request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_usage_cost() {
+        let dec = |value: &str| Decimal::from_str(value).unwrap();
+        let pricing = Pricing {
+            prompt: dec("0.00001771"),
+            completion: dec("0.00005313"),
+            image: Decimal::ZERO,
+            request: dec("0.5"),
+            web_search: Decimal::ZERO,
+            internal_reasoning: Decimal::ZERO,
+            input_cache_read: Decimal::ZERO,
+            input_cache_write: Decimal::ZERO,
+        };
+
+        let tokens = |prompt, completion| BilledTokens {
+            prompt,
+            completion,
+            ..BilledTokens::default()
+        };
+        // 1000 × 0.00001771 + 200 × 0.00005313 + 0.5
+        assert_eq!(pricing.usage_cost(tokens(1000, 200)), dec("0.528336"));
+        assert_eq!(pricing.usage_cost(tokens(0, 0)), dec("0.5"));
+
+        // Without their own prices, reasoning and cached tokens cost as much as the others
+        let detailed = BilledTokens {
+            reasoning: 150,
+            cache_read: 600,
+            cache_write: 100,
+            ..tokens(1000, 200)
+        };
+        assert_eq!(pricing.usage_cost(detailed), dec("0.528336"));
+    }
+
+    #[test]
+    fn test_usage_cost_of_reasoning_and_cached_tokens() {
+        let dec = |value: &str| Decimal::from_str(value).unwrap();
+        let pricing = Pricing {
+            prompt: dec("0.000002"),
+            completion: dec("0.00001"),
+            image: Decimal::ZERO,
+            request: Decimal::ZERO,
+            web_search: Decimal::ZERO,
+            internal_reasoning: dec("0.00002"),
+            input_cache_read: dec("0.0000005"),
+            input_cache_write: dec("0.0000025"),
+        };
+        let tokens = BilledTokens {
+            prompt: 1000,
+            completion: 200,
+            reasoning: 150,
+            cache_read: 600,
+            cache_write: 100,
+        };
+
+        // 300 × 0.000002 + 600 × 0.0000005 + 100 × 0.0000025 + 50 × 0.00001 + 150 × 0.00002
+        assert_eq!(pricing.usage_cost(tokens), dec("0.00465"));
+
+        // Inconsistent counts never go below zero
+        let overreported = BilledTokens {
+            reasoning: 500,
+            cache_read: 2000,
+            ..tokens
+        };
+        assert_eq!(pricing.usage_cost(overreported), dec("0.0045"));
+    }
+
+    #[test]
+    fn test_json_export_uses_the_price_unit() {
+        let dec = |value: &str| Decimal::from_str(value).unwrap();
+        let pricing = Pricing {
+            prompt: dec("0.00000012"),
+            completion: dec("0.0000005"),
+            image: Decimal::ZERO,
+            request: dec("0.5"),
+            web_search: Decimal::ZERO,
+            internal_reasoning: Decimal::ZERO,
+            input_cache_read: dec("0.00000003"),
+            input_cache_write: Decimal::ZERO,
+        };
+
+        let json: serde_json::Value =
+            serde_json::from_str(&pricing.to_json(PriceUnit::Million)).unwrap();
+        assert_eq!(json["unit"], "million");
+        assert_eq!(json["prompt"], "0.12");
+        assert_eq!(json["completion"], "0.5");
+        assert_eq!(json["input_cache_read"], "0.03");
+        // Per-request prices are not rescaled
+        assert_eq!(json["request"], "0.5");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&pricing.to_json(PriceUnit::Thousand)).unwrap();
+        assert_eq!(json["unit"], "thousand");
+        assert_eq!(json["prompt"], "0.00012");
+    }
+
+    #[test]
+    fn test_price_unit_scale_is_exact() {
+        let price = Decimal::from_str("0.00000012").unwrap();
+        assert_eq!(PriceUnit::Token.scale(price), price);
+        assert_eq!(
+            PriceUnit::Thousand.scale(price).normalize().to_string(),
+            "0.00012"
+        );
+        assert_eq!(
+            PriceUnit::Million.scale(price).normalize().to_string(),
+            "0.12"
+        );
+        assert_eq!(
+            PriceUnit::Billion.scale(price).normalize().to_string(),
+            "120"
+        );
+        assert_eq!(PriceUnit::default(), PriceUnit::Million);
+    }
+}
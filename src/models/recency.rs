@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+/// How far back a model's creation date counts as recent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecencyWindow {
+    /// The last N days
+    Days(u16),
+    /// Everything created after the previous visit
+    SinceLastVisit,
+}
+
+impl Default for RecencyWindow {
+    fn default() -> Self {
+        RecencyWindow::Days(14)
+    }
+}
+
+impl RecencyWindow {
+    /// Presets offered by the created-date filter
+    pub const FILTER_PRESETS: [RecencyWindow; 4] = [
+        RecencyWindow::Days(7),
+        RecencyWindow::Days(30),
+        RecencyWindow::Days(90),
+        RecencyWindow::SinceLastVisit,
+    ];
+
+    /// Windows offered for the "NEW" badge
+    pub const BADGE_CHOICES: [RecencyWindow; 5] = [
+        RecencyWindow::Days(7),
+        RecencyWindow::Days(14),
+        RecencyWindow::Days(30),
+        RecencyWindow::Days(90),
+        RecencyWindow::SinceLastVisit,
+    ];
+
+    /// Earliest creation time inside the window (None if there is no previous visit)
+    pub fn cutoff(
+        self,
+        now: OffsetDateTime,
+        last_visit: Option<OffsetDateTime>,
+    ) -> Option<OffsetDateTime> {
+        match self {
+            RecencyWindow::Days(days) => Some(now - Duration::days(days.into())),
+            RecencyWindow::SinceLastVisit => last_visit,
+        }
+    }
+
+    /// Check whether a creation time falls inside the window
+    pub fn contains(
+        self,
+        created: OffsetDateTime,
+        now: OffsetDateTime,
+        last_visit: Option<OffsetDateTime>,
+    ) -> bool {
+        self.cutoff(now, last_visit)
+            .is_some_and(|cutoff| created >= cutoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_days_window() {
+        let now = datetime!(2025-10-31 12:00 UTC);
+        let window = RecencyWindow::Days(7);
+
+        assert_eq!(
+            window.cutoff(now, None),
+            Some(datetime!(2025-10-24 12:00 UTC))
+        );
+        assert!(window.contains(datetime!(2025-10-24 12:00 UTC), now, None));
+        assert!(window.contains(datetime!(2025-10-30 00:00 UTC), now, None));
+        assert!(!window.contains(datetime!(2025-10-24 11:59 UTC), now, None));
+    }
+
+    #[test]
+    fn test_since_last_visit() {
+        let now = datetime!(2025-10-31 12:00 UTC);
+        let last_visit = Some(datetime!(2025-10-20 08:00 UTC));
+        let window = RecencyWindow::SinceLastVisit;
+
+        assert!(window.contains(datetime!(2025-10-21 00:00 UTC), now, last_visit));
+        assert!(!window.contains(datetime!(2025-10-19 00:00 UTC), now, last_visit));
+
+        // Without a previous visit nothing counts as new
+        assert_eq!(window.cutoff(now, None), None);
+        assert!(!window.contains(now, now, None));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        for window in RecencyWindow::BADGE_CHOICES {
+            let json = serde_json::to_string(&window).unwrap();
+            let restored: RecencyWindow = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, window);
+        }
+        assert_eq!(
+            serde_json::to_string(&RecencyWindow::SinceLastVisit).unwrap(),
+            r#""since_last_visit""#
+        );
+    }
+}
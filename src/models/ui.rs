@@ -1,13 +1,280 @@
-#[derive(Debug, Clone, Copy, PartialEq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortField {
     Name,
+    Provider,
     Created,
     PromptPrice,
     CompletionPrice,
+    InternalReasoningPrice,
+    InputCacheReadPrice,
+    InputCacheWritePrice,
+    ImagePrice,
+    RequestPrice,
+    WebSearchPrice,
+    ContextLength,
+    MaxCompletionTokens,
+}
+
+impl SortField {
+    /// All sort fields in the order they are offered to the user
+    pub const ALL: [SortField; 13] = [
+        SortField::Name,
+        SortField::Provider,
+        SortField::Created,
+        SortField::PromptPrice,
+        SortField::CompletionPrice,
+        SortField::InternalReasoningPrice,
+        SortField::InputCacheReadPrice,
+        SortField::InputCacheWritePrice,
+        SortField::ImagePrice,
+        SortField::RequestPrice,
+        SortField::WebSearchPrice,
+        SortField::ContextLength,
+        SortField::MaxCompletionTokens,
+    ];
+
+    /// Fluent key of the sort field name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SortField::Name => "sort-name",
+            SortField::Provider => "sort-provider",
+            SortField::Created => "sort-created",
+            SortField::PromptPrice => "sort-prompt-price",
+            SortField::CompletionPrice => "sort-completion-price",
+            SortField::InternalReasoningPrice => "sort-internal-reasoning-price",
+            SortField::InputCacheReadPrice => "sort-input-cache-read-price",
+            SortField::InputCacheWritePrice => "sort-input-cache-write-price",
+            SortField::ImagePrice => "sort-image-price",
+            SortField::RequestPrice => "sort-request-price",
+            SortField::WebSearchPrice => "sort-web-search-price",
+            SortField::ContextLength => "sort-context-length",
+            SortField::MaxCompletionTokens => "sort-max-completion-tokens",
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortDirection {
     Ascending,
     Descending,
 }
+
+impl SortDirection {
+    /// The opposite direction
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// One level of a multi-key sort (primary, secondary, ...)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SortKey {
+    pub field: SortField,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    /// Maximum number of sort levels offered in the UI
+    pub const MAX_KEYS: usize = 3;
+
+    pub fn new(field: SortField, direction: SortDirection) -> Self {
+        Self { field, direction }
+    }
+}
+
+/// How the filtered models are presented
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewMode {
+    #[default]
+    Cards,
+    Table,
+    Chart,
+}
+
+/// Color theme of the interface
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    /// Follow the system `prefers-color-scheme` setting
+    #[default]
+    Auto,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    /// All themes in the order they are offered to the user
+    pub const ALL: [Theme; 4] = [Theme::Auto, Theme::Light, Theme::Dark, Theme::HighContrast];
+
+    /// Value of the `data-theme` attribute selecting the CSS variables
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            Theme::Auto => "auto",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    /// Fluent key of the theme name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Theme::Auto => "theme-auto",
+            Theme::Light => "theme-light",
+            Theme::Dark => "theme-dark",
+            Theme::HighContrast => "theme-high-contrast",
+        }
+    }
+}
+
+/// Top-level page of the application
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Page {
+    #[default]
+    Models,
+    Providers,
+    /// Side-by-side comparison of the models picked in the list
+    Arena,
+}
+
+/// A quantity plotted on an axis of the scatter chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartAxis {
+    PromptPrice,
+    CompletionPrice,
+    ContextLength,
+    Created,
+}
+
+impl ChartAxis {
+    /// All axes in the order they are offered to the user
+    pub const ALL: [ChartAxis; 4] = [
+        ChartAxis::PromptPrice,
+        ChartAxis::CompletionPrice,
+        ChartAxis::ContextLength,
+        ChartAxis::Created,
+    ];
+
+    /// Fluent key of the axis name
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ChartAxis::PromptPrice => "chart-axis-prompt-price",
+            ChartAxis::CompletionPrice => "chart-axis-completion-price",
+            ChartAxis::ContextLength => "chart-axis-context-length",
+            ChartAxis::Created => "chart-axis-created",
+        }
+    }
+
+    /// Prices span several orders of magnitude, so they are plotted on a log scale
+    pub fn is_logarithmic(&self) -> bool {
+        matches!(self, ChartAxis::PromptPrice | ChartAxis::CompletionPrice)
+    }
+}
+
+/// A column of the compact table view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableColumn {
+    Name,
+    Provider,
+    PromptPrice,
+    CompletionPrice,
+    InternalReasoningPrice,
+    InputCacheReadPrice,
+    InputCacheWritePrice,
+    ImagePrice,
+    RequestPrice,
+    WebSearchPrice,
+    ContextLength,
+    MaxCompletionTokens,
+    Modalities,
+    Created,
+}
+
+impl TableColumn {
+    /// All columns in display order
+    pub const ALL: [TableColumn; 14] = [
+        TableColumn::Name,
+        TableColumn::Provider,
+        TableColumn::PromptPrice,
+        TableColumn::CompletionPrice,
+        TableColumn::InternalReasoningPrice,
+        TableColumn::InputCacheReadPrice,
+        TableColumn::InputCacheWritePrice,
+        TableColumn::ImagePrice,
+        TableColumn::RequestPrice,
+        TableColumn::WebSearchPrice,
+        TableColumn::ContextLength,
+        TableColumn::MaxCompletionTokens,
+        TableColumn::Modalities,
+        TableColumn::Created,
+    ];
+
+    /// Columns shown before the user has picked any
+    pub fn defaults() -> Vec<TableColumn> {
+        vec![
+            TableColumn::Name,
+            TableColumn::Provider,
+            TableColumn::PromptPrice,
+            TableColumn::CompletionPrice,
+            TableColumn::ContextLength,
+            TableColumn::Modalities,
+            TableColumn::Created,
+        ]
+    }
+
+    /// Fluent key of the column header
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            TableColumn::Name => "column-name",
+            TableColumn::Provider => "column-provider",
+            TableColumn::PromptPrice => "column-prompt-price",
+            TableColumn::CompletionPrice => "column-completion-price",
+            TableColumn::InternalReasoningPrice => "column-internal-reasoning-price",
+            TableColumn::InputCacheReadPrice => "column-input-cache-read-price",
+            TableColumn::InputCacheWritePrice => "column-input-cache-write-price",
+            TableColumn::ImagePrice => "column-image-price",
+            TableColumn::RequestPrice => "column-request-price",
+            TableColumn::WebSearchPrice => "column-web-search-price",
+            TableColumn::ContextLength => "column-context-length",
+            TableColumn::MaxCompletionTokens => "column-max-completion-tokens",
+            TableColumn::Modalities => "column-modalities",
+            TableColumn::Created => "column-created",
+        }
+    }
+
+    /// Sort field applied when the column header is clicked (if the column is sortable)
+    pub fn sort_field(&self) -> Option<SortField> {
+        match self {
+            TableColumn::Name => Some(SortField::Name),
+            TableColumn::Provider => Some(SortField::Provider),
+            TableColumn::PromptPrice => Some(SortField::PromptPrice),
+            TableColumn::CompletionPrice => Some(SortField::CompletionPrice),
+            TableColumn::InternalReasoningPrice => Some(SortField::InternalReasoningPrice),
+            TableColumn::InputCacheReadPrice => Some(SortField::InputCacheReadPrice),
+            TableColumn::InputCacheWritePrice => Some(SortField::InputCacheWritePrice),
+            TableColumn::ImagePrice => Some(SortField::ImagePrice),
+            TableColumn::RequestPrice => Some(SortField::RequestPrice),
+            TableColumn::WebSearchPrice => Some(SortField::WebSearchPrice),
+            TableColumn::ContextLength => Some(SortField::ContextLength),
+            TableColumn::MaxCompletionTokens => Some(SortField::MaxCompletionTokens),
+            TableColumn::Modalities => None,
+            TableColumn::Created => Some(SortField::Created),
+        }
+    }
+
+    /// Whether the column can be hidden via the column picker
+    pub fn is_optional(&self) -> bool {
+        *self != TableColumn::Name
+    }
+}
@@ -0,0 +1,342 @@
+//! Pages of the app pre-rendered into the web bundle at build time, and hydrated by the app.
+//!
+//! The `prerender` binary renders the app into `index.html` with the catalog, into
+//! `models/<id>/index.html` with the details of each model open, and into `404.html` (served for
+//! every other URL) still loading, so the first paint needs neither the wasm bundle nor the API and
+//! search engines can index each model.
+//!
+//! The app built with the `hydrate` feature adopts that DOM instead of rendering its own. Its first
+//! render has to match the build's, so while [`is_hydrating`] it renders the [`PrerenderedPage`]
+//! the build embedded, in the default language and settings; the visitor's language, settings and
+//! the live catalog are applied once the page is live.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::models::{ApiResponse, Model};
+
+/// Directory of the model pages, relative to the app root
+const MODELS_DIR: &str = "models";
+
+/// What a pre-rendered page shows, embedded into the page for the app to hydrate with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrerenderedPage {
+    /// Catalog as of the build, shown until the app has loaded its own; `None` shows it loading
+    pub catalog: Option<ApiResponse>,
+    /// Model whose details dialog is open
+    pub model: Option<Model>,
+    /// Time of the build, which relative dates are rendered against
+    #[serde(with = "time::serde::timestamp")]
+    pub rendered_at: OffsetDateTime,
+}
+
+/// Whether the app is rendering a pre-rendered page: at build time, and in the browser while it
+/// hydrates that page. Nothing rendered then may depend on the visitor (storage, language, clock).
+pub fn is_hydrating() -> bool {
+    #[cfg(all(feature = "hydrate", target_arch = "wasm32"))]
+    let hydrating = dioxus_fullstack_core::is_hydrating();
+    #[cfg(not(all(feature = "hydrate", target_arch = "wasm32")))]
+    let hydrating = false;
+
+    hydrating || try_consume_context::<PrerenderedPage>().is_some()
+}
+
+/// The page the app is hydrating, provided by the `prerender` binary at build time and read from
+/// the page in the browser.
+///
+/// The page is the first entry of the hydration data, so this must be called before any other
+/// component reads from it (e.g. `document::Link`).
+pub fn use_prerendered_page() -> Option<Rc<PrerenderedPage>> {
+    use_hook(|| {
+        if let Some(page) = try_consume_context::<PrerenderedPage>() {
+            return Some(Rc::new(page));
+        }
+
+        #[cfg(all(feature = "hydrate", target_arch = "wasm32"))]
+        if dioxus_fullstack_core::is_hydrating() {
+            return dioxus_fullstack_core::serialize_context()
+                .create_entry::<PrerenderedPage>()
+                .get()
+                .ok()
+                .map(Rc::new);
+        }
+
+        None
+    })
+}
+
+/// Hydration data of a page, in the format the app reads it from (base64 of the entries, the first
+/// one being reserved for an error)
+#[cfg(feature = "prerender")]
+pub fn hydration_data(page: &PrerenderedPage) -> String {
+    let context = dioxus_fullstack_core::HydrationContext::default();
+    context.error_entry();
+    context
+        .create_entry::<PrerenderedPage>()
+        .insert(page, std::panic::Location::caller());
+    context.serialized().data
+}
+
+/// A pre-rendered page, as HTML fragments to put into the app shell
+#[derive(Debug, Clone, Copy)]
+pub struct StaticPage<'a> {
+    /// `<title>` and meta tags, replacing the shell's `<title>`
+    pub head: &'a str,
+    /// The app as rendered for the page, with the markers it hydrates from
+    pub body: &'a str,
+    /// What the page shows, encoded by [`hydration_data`]
+    pub data: &'a str,
+    /// Relative URL of the app root, for pages below it (see [`root_prefix`])
+    pub base: Option<&'a str>,
+}
+
+/// Why a page could not be put into the app shell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellError {
+    /// The shell has no `<head>` element
+    MissingHead,
+    /// The shell has no `<title>` element
+    MissingTitle,
+    /// The shell has no empty `<div id="main">` for the app to mount into
+    MissingRoot,
+}
+
+impl fmt::Display for ShellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShellError::MissingHead => write!(f, "index.html has no <head>"),
+            ShellError::MissingTitle => write!(f, "index.html has no <title>"),
+            ShellError::MissingRoot => write!(f, r#"index.html has no empty <div id="main">"#),
+        }
+    }
+}
+
+impl std::error::Error for ShellError {}
+
+/// Put a pre-rendered page into the app shell (the `index.html` built by `dx bundle`)
+pub fn inject(shell: &str, page: StaticPage) -> Result<String, ShellError> {
+    const TITLE_START: &str = "<title>";
+    const TITLE_END: &str = "</title>";
+    const ROOT: &str = r#"<div id="main">"#;
+    const ROOT_END: &str = "</div>";
+
+    let head_start = shell.find("<head").ok_or(ShellError::MissingHead)?;
+    let head_end = head_start
+        + shell[head_start..]
+            .find('>')
+            .ok_or(ShellError::MissingHead)?
+        + 1;
+    let title_start = shell.find(TITLE_START).ok_or(ShellError::MissingTitle)?;
+    let title_end = title_start
+        + shell[title_start..]
+            .find(TITLE_END)
+            .ok_or(ShellError::MissingTitle)?
+        + TITLE_END.len();
+    let root_end = shell.find(ROOT).ok_or(ShellError::MissingRoot)? + ROOT.len();
+    if !shell[root_end..].starts_with(ROOT_END) {
+        return Err(ShellError::MissingRoot);
+    }
+    if !(head_end <= title_start && title_end <= root_end) {
+        return Err(ShellError::MissingTitle);
+    }
+    let after_root = root_end + ROOT_END.len();
+
+    // The base comes first so it applies to every relative URL of the shell
+    let base = page
+        .base
+        .map(|base| format!(r#"<base href="{base}">"#))
+        .unwrap_or_default();
+    // Read by the app when it starts hydrating, which the module script defers until after parsing
+    let hydration = format!(
+        r#"<script>{}window.initial_dioxus_hydration_data="{}";</script>"#,
+        dioxus_interpreter_js::INITIALIZE_STREAMING_JS,
+        page.data,
+    );
+    Ok(format!(
+        "{}{base}{}{}{}{}{ROOT_END}{hydration}{}",
+        &shell[..head_end],
+        &shell[head_end..title_start],
+        page.head,
+        &shell[title_end..root_end],
+        page.body,
+        &shell[after_root..],
+    ))
+}
+
+/// Link to the page of a model, relative to the app root
+pub fn model_page_href(model_id: &str) -> String {
+    format!("{MODELS_DIR}/{model_id}/")
+}
+
+/// File of the page of a model, relative to the app root.
+///
+/// `None` for ids that do not make a path below the models directory (e.g. with `..` segments).
+pub fn model_page_path(model_id: &str) -> Option<PathBuf> {
+    let segments: Vec<&str> = model_id.split('/').collect();
+    let usable = segments
+        .iter()
+        .all(|segment| !matches!(*segment, "" | "." | "..") && !segment.contains('\\'));
+    usable.then(|| {
+        let mut path: PathBuf = [MODELS_DIR].into_iter().chain(segments).collect();
+        path.push("index.html");
+        path
+    })
+}
+
+/// Relative URL of the app root from the page of a model, e.g. `../../../` for `openai/gpt-4o`
+pub fn root_prefix(model_id: &str) -> String {
+    "../".repeat(model_id.split('/').count() + 1)
+}
+
+/// Id of the model whose page a URL path points at, e.g. `/Polza-AI-models-viewer/models/openai/gpt-4o/`
+pub fn model_id_from_path(path: &str) -> Option<&str> {
+    let (_, page) = path.split_once(&format!("/{MODELS_DIR}/"))?;
+    let id = page.trim_end_matches("index.html").trim_end_matches('/');
+    (!id.is_empty()).then_some(id)
+}
+
+/// Id of the model whose page the app was opened on (`404.html` serves pages the build did not render)
+#[cfg(target_arch = "wasm32")]
+pub fn linked_model_id() -> Option<String> {
+    let path = web_sys::window()?.location().pathname().ok()?;
+    let path = String::from(js_sys::decode_uri_component(&path).ok()?);
+    model_id_from_path(&path).map(str::to_owned)
+}
+
+/// No-op implementation for non-web platforms
+#[cfg(not(target_arch = "wasm32"))]
+pub fn linked_model_id() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const SHELL: &str = r#"<!DOCTYPE html>
+<html>
+    <head>
+        <title>polza-models</title>
+        <link rel="stylesheet" href="./assets/main.css">
+    </head>
+    <body>
+        <div id="main"></div>
+        <script type="module" src="./wasm/polza-models.js"></script>
+    </body>
+</html>"#;
+
+    #[test]
+    fn test_inject_fills_head_and_root() {
+        let page = StaticPage {
+            head: "<title>GPT-4o</title>",
+            body: "<p>catalog</p>",
+            data: "oA==",
+            base: None,
+        };
+        let html = inject(SHELL, page).unwrap();
+        assert!(html.contains("<title>GPT-4o</title>"), "{html}");
+        assert!(!html.contains("polza-models</title>"), "{html}");
+        assert!(
+            html.contains(r#"<div id="main"><p>catalog</p></div><script>"#),
+            "{html}"
+        );
+        assert!(!html.contains("<base"), "{html}");
+        assert!(html.ends_with("</html>"), "{html}");
+    }
+
+    #[test]
+    fn test_inject_sets_the_hydration_data_before_the_app_starts() {
+        let page = StaticPage {
+            head: "",
+            body: "",
+            data: "oA==",
+            base: None,
+        };
+        let html = inject(SHELL, page).unwrap();
+        let data = html
+            .find(r#"window.initial_dioxus_hydration_data="oA==";"#)
+            .unwrap();
+        assert!(html.find("window.hydrate_queue").unwrap() < data, "{html}");
+        assert!(
+            data < html.find("./wasm/polza-models.js").unwrap(),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn test_inject_puts_the_base_before_relative_urls() {
+        let page = StaticPage {
+            head: "",
+            body: "",
+            data: "",
+            base: Some("../../../"),
+        };
+        let html = inject(SHELL, page).unwrap();
+        let base = html.find(r#"<base href="../../../">"#).unwrap();
+        assert!(html.find("<head>").unwrap() < base, "{html}");
+        assert!(base < html.find("./assets/main.css").unwrap(), "{html}");
+    }
+
+    #[test]
+    fn test_inject_rejects_unknown_shells() {
+        let page = StaticPage {
+            head: "",
+            body: "",
+            data: "",
+            base: None,
+        };
+        assert_eq!(
+            inject("<html><body></body></html>", page),
+            Err(ShellError::MissingHead)
+        );
+        assert_eq!(
+            inject("<head></head><div id=\"main\"></div>", page),
+            Err(ShellError::MissingTitle)
+        );
+        assert_eq!(
+            inject("<head><title>x</title></head><div id=\"app\"></div>", page),
+            Err(ShellError::MissingRoot)
+        );
+        // The app would hydrate the shell's own content too
+        assert_eq!(
+            inject(
+                "<head><title>x</title></head><div id=\"main\">x</div>",
+                page
+            ),
+            Err(ShellError::MissingRoot)
+        );
+    }
+
+    #[test]
+    fn test_model_pages_mirror_the_id() {
+        assert_eq!(model_page_href("openai/gpt-4o"), "models/openai/gpt-4o/");
+        assert_eq!(
+            model_page_path("openai/gpt-4o").as_deref(),
+            Some(Path::new("models/openai/gpt-4o/index.html"))
+        );
+        assert_eq!(root_prefix("openai/gpt-4o"), "../../../");
+        assert_eq!(model_page_path("../index"), None);
+        assert_eq!(model_page_path("openai//gpt-4o"), None);
+        assert_eq!(model_page_path(""), None);
+    }
+
+    #[test]
+    fn test_model_id_from_path() {
+        assert_eq!(
+            model_id_from_path("/Polza-AI-models-viewer/models/openai/gpt-4o/"),
+            Some("openai/gpt-4o")
+        );
+        assert_eq!(
+            model_id_from_path("/models/openai/gpt-4o/index.html"),
+            Some("openai/gpt-4o")
+        );
+        assert_eq!(model_id_from_path("/Polza-AI-models-viewer/"), None);
+        assert_eq!(model_id_from_path("/models/"), None);
+    }
+}
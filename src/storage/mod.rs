@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::prerender::is_hydrating;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+#[cfg(target_arch = "wasm32")]
+pub use wasm::{load, remove, save, try_save};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native;
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::{load, remove, save, try_save};
+
+/// Create a signal that is initialized from storage and written back on every change.
+///
+/// While hydrating a pre-rendered page it starts with `init()`, as the page was rendered with, and
+/// the stored value is loaded once the page is live.
+pub fn use_persistent<T>(key: &'static str, init: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let hydrating = use_hook(is_hydrating);
+    let mut signal = use_signal(|| {
+        if hydrating {
+            init()
+        } else {
+            load(key).unwrap_or_else(init)
+        }
+    });
+    let loaded = use_hook(|| Rc::new(Cell::new(!hydrating)));
+
+    use_effect(move || {
+        // The default must not overwrite the stored value before it is loaded
+        if !loaded.replace(true) {
+            if let Some(stored) = load(key) {
+                signal.set(stored);
+            }
+        }
+        save(key, &*signal.read());
+    });
+
+    signal
+}
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Directory name used inside the platform data directory
+const APP_DIR: &str = "polza-models";
+
+/// Path of the JSON file backing a storage key
+fn storage_path(key: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_DIR).join(format!("{key}.json")))
+}
+
+/// Load a JSON value from the platform data directory
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let contents = std::fs::read_to_string(storage_path(key)?).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Save a value as JSON to the platform data directory
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    let _ = try_save(key, value);
+}
+
+/// Save a value as JSON to the platform data directory, returning why it could not be written
+pub fn try_save<T: Serialize>(key: &str, value: &T) -> Result<(), String> {
+    let Some(path) = storage_path(key) else {
+        return Ok(());
+    };
+    let json = serde_json::to_string(value).map_err(|err| err.to_string())?;
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    std::fs::write(path, json).map_err(|err| err.to_string())
+}
+
+/// Remove a value from the platform data directory
+pub fn remove(key: &str) {
+    if let Some(path) = storage_path(key) {
+        let _ = std::fs::remove_file(path);
+    }
+}
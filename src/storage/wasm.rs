@@ -0,0 +1,25 @@
+use gloo_console::log;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Load a JSON value from LocalStorage
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    LocalStorage::get(key).ok()
+}
+
+/// Save a value to LocalStorage as JSON
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    if let Err(err) = try_save(key, value) {
+        log!(format!("[Storage] ✗ Failed to save \"{}\": {}", key, err));
+    }
+}
+
+/// Save a value to LocalStorage as JSON, returning why it could not be written
+pub fn try_save<T: Serialize>(key: &str, value: &T) -> Result<(), String> {
+    LocalStorage::set(key, value).map_err(|err| err.to_string())
+}
+
+/// Remove a value from LocalStorage
+pub fn remove(key: &str) {
+    LocalStorage::delete(key);
+}
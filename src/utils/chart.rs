@@ -0,0 +1,265 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::models::{ChartAxis, Model, PriceUnit};
+
+/// Maps a data interval linearly onto a pixel interval
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearScale {
+    domain: (f64, f64),
+    range: (f64, f64),
+}
+
+impl LinearScale {
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self { domain, range }
+    }
+
+    /// Build a scale whose domain covers all values (`None` when there are no values)
+    pub fn fit(values: impl IntoIterator<Item = f64>, range: (f64, f64)) -> Option<Self> {
+        let (min, max) = values.into_iter().filter(|v| v.is_finite()).fold(
+            None,
+            |acc: Option<(f64, f64)>, v| match acc {
+                None => Some((v, v)),
+                Some((min, max)) => Some((min.min(v), max.max(v))),
+            },
+        )?;
+        Some(Self::new((min, max), range))
+    }
+
+    pub fn domain(&self) -> (f64, f64) {
+        self.domain
+    }
+
+    /// Map a data value to a pixel position.
+    ///
+    /// A degenerate domain (a single distinct value) maps to the middle of the range.
+    pub fn map(&self, value: f64) -> f64 {
+        let (d0, d1) = self.domain;
+        let (r0, r1) = self.range;
+        if d1 == d0 {
+            return (r0 + r1) / 2.0;
+        }
+        r0 + (value - d0) / (d1 - d0) * (r1 - r0)
+    }
+}
+
+/// Maps positive values onto a pixel interval on a base-10 logarithmic scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogScale {
+    exponents: LinearScale,
+}
+
+impl LogScale {
+    /// Build a scale covering all positive values (non-positive ones cannot be placed)
+    pub fn fit(values: impl IntoIterator<Item = f64>, range: (f64, f64)) -> Option<Self> {
+        let exponents = LinearScale::fit(
+            values.into_iter().filter(|v| *v > 0.0).map(f64::log10),
+            range,
+        )?;
+        Some(Self { exponents })
+    }
+
+    /// Smallest and largest value covered by the scale
+    pub fn domain(&self) -> (f64, f64) {
+        let (min, max) = self.exponents.domain();
+        (10f64.powf(min), 10f64.powf(max))
+    }
+
+    pub fn map(&self, value: f64) -> f64 {
+        self.exponents.map(value.log10())
+    }
+
+    /// Powers of ten inside the domain, used as tick positions
+    pub fn decades(&self) -> Vec<f64> {
+        let (min, max) = self.exponents.domain();
+        let (first, last) = (min.ceil() as i32, max.floor() as i32);
+        (first..=last)
+            .map(|exponent| 10f64.powi(exponent))
+            .collect()
+    }
+}
+
+/// Linear or logarithmic scale, depending on the plotted axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AxisScale {
+    Linear(LinearScale),
+    Log(LogScale),
+}
+
+impl AxisScale {
+    /// Build the scale appropriate for the axis
+    pub fn fit(
+        axis: ChartAxis,
+        values: impl IntoIterator<Item = f64>,
+        range: (f64, f64),
+    ) -> Option<Self> {
+        if axis.is_logarithmic() {
+            LogScale::fit(values, range).map(AxisScale::Log)
+        } else {
+            LinearScale::fit(values, range).map(AxisScale::Linear)
+        }
+    }
+
+    pub fn map(&self, value: f64) -> f64 {
+        match self {
+            AxisScale::Linear(scale) => scale.map(value),
+            AxisScale::Log(scale) => scale.map(value),
+        }
+    }
+
+    /// Values to label along the axis: the domain ends, plus the decades on a log scale
+    pub fn ticks(&self) -> Vec<f64> {
+        let (min, max) = match self {
+            AxisScale::Linear(scale) => scale.domain(),
+            AxisScale::Log(scale) => scale.domain(),
+        };
+        let mut ticks = vec![min];
+        if let AxisScale::Log(scale) = self {
+            ticks.extend(scale.decades());
+        }
+        ticks.push(max);
+
+        // Drop ticks that would overlap a neighbour (within 1% of the axis length). Compared by
+        // position, so the decades at the low end of a log axis are kept.
+        let tolerance = (self.map(max) - self.map(min)).abs() / 100.0;
+        ticks.sort_by(f64::total_cmp);
+        ticks.dedup_by(|a, b| (self.map(*a) - self.map(*b)).abs() <= tolerance);
+        ticks
+    }
+}
+
+/// Position of a model along a chart axis.
+///
+/// Prices are per `unit`. Returns `None` when the model cannot be placed,
+/// i.e. a zero price on a logarithmic axis.
+pub fn axis_value(model: &Model, axis: ChartAxis, unit: PriceUnit) -> Option<f64> {
+    let per_unit = |price: Decimal| unit.scale(price).to_f64();
+    let value = match axis {
+        ChartAxis::PromptPrice => per_unit(model.pricing.prompt)?,
+        ChartAxis::CompletionPrice => per_unit(model.pricing.completion)?,
+        ChartAxis::ContextLength => model.top_provider.context_length as f64,
+        ChartAxis::Created => model.created.unix_timestamp() as f64,
+    };
+    (!axis.is_logarithmic() || value > 0.0).then_some(value)
+}
+
+/// Stable color for a provider, derived from a hash of its name
+pub fn provider_color(provider: &str) -> String {
+    // FNV-1a keeps the color identical across runs and platforms
+    let hash = provider.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+    });
+    format!("hsl({}, 65%, 45%)", hash % 360)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_scale_maps_endpoints_and_midpoint() {
+        let scale = LinearScale::new((10.0, 20.0), (0.0, 100.0));
+        assert_eq!(scale.map(10.0), 0.0);
+        assert_eq!(scale.map(20.0), 100.0);
+        assert_eq!(scale.map(15.0), 50.0);
+    }
+
+    #[test]
+    fn test_linear_scale_inverted_range() {
+        // SVG y grows downwards, so charts map the domain onto (bottom, top)
+        let scale = LinearScale::new((0.0, 1.0), (150.0, 10.0));
+        assert_eq!(scale.map(0.0), 150.0);
+        assert_eq!(scale.map(1.0), 10.0);
+    }
+
+    #[test]
+    fn test_linear_scale_single_value_is_centered() {
+        let scale = LinearScale::fit([3.0], (0.0, 100.0)).unwrap();
+        assert_eq!(scale.domain(), (3.0, 3.0));
+        assert_eq!(scale.map(3.0), 50.0);
+    }
+
+    #[test]
+    fn test_linear_scale_fit() {
+        let scale = LinearScale::fit([5.0, -1.0, 3.0, f64::NAN], (0.0, 60.0)).unwrap();
+        assert_eq!(scale.domain(), (-1.0, 5.0));
+        assert_eq!(scale.map(2.0), 30.0);
+
+        assert!(LinearScale::fit(std::iter::empty(), (0.0, 1.0)).is_none());
+    }
+
+    #[test]
+    fn test_log_scale_maps_decades_evenly() {
+        let scale = LogScale::fit([0.1, 10.0, 0.0, -5.0], (0.0, 100.0)).unwrap();
+        assert!((scale.map(0.1) - 0.0).abs() < 1e-9);
+        assert!((scale.map(1.0) - 50.0).abs() < 1e-9);
+        assert!((scale.map(10.0) - 100.0).abs() < 1e-9);
+        assert_eq!(scale.decades(), vec![0.1, 1.0, 10.0]);
+
+        // Only non-positive values: nothing to place
+        assert!(LogScale::fit([0.0, -1.0], (0.0, 1.0)).is_none());
+    }
+
+    #[test]
+    fn test_axis_scale_ticks() {
+        let linear =
+            AxisScale::fit(ChartAxis::ContextLength, [4096.0, 8192.0], (0.0, 1.0)).unwrap();
+        assert_eq!(linear.ticks(), vec![4096.0, 8192.0]);
+
+        let log = AxisScale::fit(ChartAxis::PromptPrice, [0.5, 20.0], (0.0, 1.0)).unwrap();
+        let ticks = log.ticks();
+        assert_eq!(ticks.len(), 4);
+        assert!((ticks[0] - 0.5).abs() < 1e-9);
+        assert!((ticks[1] - 1.0).abs() < 1e-9);
+        assert!((ticks[2] - 10.0).abs() < 1e-9);
+        assert!((ticks[3] - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_log_ticks_over_several_decades() {
+        let log = AxisScale::fit(ChartAxis::PromptPrice, [0.01, 1000.0], (0.0, 500.0)).unwrap();
+        let expected = [0.01, 0.1, 1.0, 10.0, 100.0, 1000.0];
+        let ticks = log.ticks();
+        assert_eq!(ticks.len(), expected.len(), "{ticks:?}");
+        for (tick, expected) in ticks.iter().zip(expected) {
+            assert!((tick / expected - 1.0).abs() < 1e-9, "{ticks:?}");
+        }
+    }
+
+    #[test]
+    fn test_axis_value_from_models_json() {
+        let response: crate::models::ApiResponse =
+            serde_json::from_str(include_str!("../models.json")).unwrap();
+        let model = &response.data[0];
+
+        assert_eq!(
+            axis_value(model, ChartAxis::ContextLength, PriceUnit::Million),
+            Some(model.top_provider.context_length as f64)
+        );
+        assert_eq!(
+            axis_value(model, ChartAxis::Created, PriceUnit::Million),
+            Some(model.created.unix_timestamp() as f64)
+        );
+
+        // Prices follow the chosen unit
+        let per_token = axis_value(model, ChartAxis::PromptPrice, PriceUnit::Token).unwrap();
+        let per_thousand = axis_value(model, ChartAxis::PromptPrice, PriceUnit::Thousand).unwrap();
+        assert!((per_thousand / per_token - 1000.0).abs() < 1e-6);
+
+        // Free models cannot be placed on a logarithmic price axis
+        let mut free = model.clone();
+        free.pricing.prompt = Decimal::ZERO;
+        assert_eq!(
+            axis_value(&free, ChartAxis::PromptPrice, PriceUnit::Million),
+            None
+        );
+    }
+
+    #[test]
+    fn test_provider_color_is_stable() {
+        assert_eq!(provider_color("openai"), provider_color("openai"));
+        assert_ne!(provider_color("openai"), provider_color("anthropic"));
+        assert!(provider_color("google").starts_with("hsl("));
+    }
+}
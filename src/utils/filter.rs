@@ -26,7 +26,10 @@ pub fn matches_any_token_sequence(filter_token: &str, model_tokens: &[String]) -
 }
 
 /// Check if model has all required modalities
-pub fn has_all_modalities(model_modalities: &[Modality], required: &std::collections::HashSet<Modality>) -> bool {
+pub fn has_all_modalities(
+    model_modalities: &[Modality],
+    required: &std::collections::HashSet<Modality>,
+) -> bool {
     // If no modalities are required, any model passes
     if required.is_empty() {
         return true;
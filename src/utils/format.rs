@@ -1,51 +1,311 @@
 use rust_decimal::Decimal;
+use time::{Date, OffsetDateTime, UtcOffset};
+use unic_langid::LanguageIdentifier;
 
-/// Format price per million tokens with auto decimal places
-pub fn format_price_per_million(price: Decimal) -> String {
-    let price_per_million = price * Decimal::from(1_000_000);
-    let normalized = price_per_million.normalize();
-    format!("₽{}", normalized)
+/// Order of the day, month and year in a numeric date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// `10/18/2026`
+    MonthDayYear,
+    /// `18.10.2026`
+    DayMonthYear,
+    /// `2026-10-18`
+    YearMonthDay,
 }
 
-/// Format price per invocation with auto decimal places
-pub fn format_price_per_invocation(price: Decimal) -> String {
-    let normalized = price.normalize();
-    format!("₽{}", normalized)
+/// Number and date conventions of an interface language.
+///
+/// Formatting is done in Rust rather than through the browser's `Intl`, and points in time are
+/// shown in UTC, so web and native builds, and the pre-rendered pages, print exactly the same text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocaleFormat {
+    /// Digit group separator, e.g. `,` in `1,234`
+    pub thousands: char,
+    /// Decimal separator, e.g. `.` in `0.5`
+    pub decimal: char,
+    pub date_order: DateOrder,
+    pub date_separator: char,
+    /// 12-hour clock with AM/PM instead of 24-hour
+    pub twelve_hour: bool,
+    /// Space between a number and the percent sign, e.g. the no-break space in `50 %`
+    pub percent_separator: Option<char>,
 }
 
-/// Format number with thousands separator
-pub fn format_with_commas(n: usize) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
-    let chars: Vec<char> = s.chars().collect();
-    for (i, ch) in chars.iter().enumerate() {
-        if i > 0 && (chars.len() - i).is_multiple_of(3) {
-            result.push(',');
+impl Default for LocaleFormat {
+    /// US English conventions
+    fn default() -> Self {
+        LocaleFormat {
+            thousands: ',',
+            decimal: '.',
+            date_order: DateOrder::MonthDayYear,
+            date_separator: '/',
+            twelve_hour: true,
+            percent_separator: None,
         }
-        result.push(*ch);
     }
-    result
 }
 
-/// Format timestamp to local time
-#[cfg(target_arch = "wasm32")]
-pub fn format_timestamp(dt: &time::OffsetDateTime) -> String {
-    use js_sys::Date;
+impl LocaleFormat {
+    /// Conventions customary for the language (and region, for English)
+    pub fn for_language(language: &LanguageIdentifier) -> Self {
+        let european = |thousands, date_separator| LocaleFormat {
+            thousands,
+            decimal: ',',
+            date_order: DateOrder::DayMonthYear,
+            date_separator,
+            twelve_hour: false,
+            percent_separator: Some('\u{a0}'),
+        };
+        match language.language.as_str() {
+            "en" => match language.region.as_ref().map(|region| region.as_str()) {
+                None | Some("US") => LocaleFormat::default(),
+                Some(_) => LocaleFormat {
+                    date_order: DateOrder::DayMonthYear,
+                    twelve_hour: false,
+                    ..LocaleFormat::default()
+                },
+            },
+            "ru" | "uk" | "be" | "kk" | "pl" => european('\u{a0}', '.'),
+            "de" => european('.', '.'),
+            "fr" => LocaleFormat {
+                percent_separator: Some('\u{202f}'),
+                ..european('\u{202f}', '/')
+            },
+            "es" => european('.', '/'),
+            "it" | "pt" => LocaleFormat {
+                percent_separator: None,
+                ..european('.', '/')
+            },
+            _ => LocaleFormat {
+                date_order: DateOrder::YearMonthDay,
+                date_separator: '-',
+                twelve_hour: false,
+                ..LocaleFormat::default()
+            },
+        }
+    }
+
+    /// Format a whole number with digit grouping, e.g. `1,234,567`
+    pub fn integer(&self, n: u64) -> String {
+        self.group_digits(&n.to_string())
+    }
+
+    /// Format a decimal with digit grouping and the local decimal separator, keeping every digit
+    pub fn decimal(&self, value: Decimal) -> String {
+        let text = value.to_string();
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", text.as_str()),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let mut result = format!("{sign}{}", self.group_digits(whole));
+        if !fraction.is_empty() {
+            result.push(self.decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    /// Format a share between 0 and 1 as a whole percentage, e.g. `50%` or `50 %`
+    pub fn percent(&self, share: f64) -> String {
+        let number = self.integer((share * 100.0).round() as u64);
+        match self.percent_separator {
+            Some(separator) => format!("{number}{separator}%"),
+            None => format!("{number}%"),
+        }
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        let mut result = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                result.push(self.thousands);
+            }
+            result.push(ch);
+        }
+        result
+    }
+
+    /// Format a calendar date, e.g. `10/18/2026` or `18.10.2026`
+    pub fn date(&self, date: Date) -> String {
+        let (year, month, day) = (date.year(), u8::from(date.month()), date.day());
+        let separator = self.date_separator;
+        match self.date_order {
+            DateOrder::MonthDayYear => format!("{month:02}{separator}{day:02}{separator}{year}"),
+            DateOrder::DayMonthYear => format!("{day:02}{separator}{month:02}{separator}{year}"),
+            DateOrder::YearMonthDay => format!("{year}{separator}{month:02}{separator}{day:02}"),
+        }
+    }
+
+    /// Format a point in time as a date and a time of day, in the given offset
+    pub fn date_time(&self, at: &OffsetDateTime, offset: UtcOffset) -> String {
+        let at = at.to_offset(offset);
+        let (hour, minute) = (at.hour(), at.minute());
+        let time = if self.twelve_hour {
+            let suffix = if hour < 12 { "AM" } else { "PM" };
+            let hour = match hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            format!("{hour}:{minute:02}\u{a0}{suffix}")
+        } else {
+            format!("{hour:02}:{minute:02}")
+        };
+        format!("{}, {time}", self.date(at.date()))
+    }
 
-    // Convert to milliseconds for JavaScript Date
-    let timestamp_ms = dt.unix_timestamp() as f64 * 1000.0;
-    let date = Date::new(&timestamp_ms.into());
+    /// Format a point in time in UTC, marked as such
+    pub fn timestamp(&self, at: &OffsetDateTime) -> String {
+        format!("{} UTC", self.date_time(at, UtcOffset::UTC))
+    }
+
+    /// Calendar date of a point in time in UTC
+    pub fn utc_date(&self, at: &OffsetDateTime) -> String {
+        self.date(at.to_offset(UtcOffset::UTC).date())
+    }
+}
 
-    // Format using browser's locale
-    date.to_locale_string("ru-RU", &js_sys::Object::new())
-        .as_string()
-        .unwrap_or_else(|| "Unknown date".to_string())
+/// How long ago something happened, in the largest whole unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeTime {
+    JustNow,
+    Minutes(u64),
+    Hours(u64),
+    Days(u64),
+    Months(u64),
+    Years(u64),
 }
 
-/// Format timestamp to RFC2822 format
-#[cfg(not(target_arch = "wasm32"))]
-pub fn format_timestamp(dt: &time::OffsetDateTime) -> String {
-    use time::format_description::well_known::Rfc2822;
-    dt.format(&Rfc2822)
-        .unwrap_or_else(|_| "Unknown date".to_string())
+impl RelativeTime {
+    /// Time elapsed from `then` to `now`; moments in the future count as "just now"
+    pub fn between(then: OffsetDateTime, now: OffsetDateTime) -> Self {
+        let seconds = (now - then).whole_seconds().max(0) as u64;
+        let (minutes, hours, days) = (seconds / 60, seconds / 3600, seconds / 86_400);
+        match days {
+            0 if hours > 0 => RelativeTime::Hours(hours),
+            0 if minutes > 0 => RelativeTime::Minutes(minutes),
+            0 => RelativeTime::JustNow,
+            1..30 => RelativeTime::Days(days),
+            30..365 => RelativeTime::Months(days / 30),
+            _ => RelativeTime::Years(days / 365),
+        }
+    }
+
+    /// Fluent key of the phrase; it takes the number of units as `$count`
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            RelativeTime::JustNow => "relative-just-now",
+            RelativeTime::Minutes(_) => "relative-minutes-ago",
+            RelativeTime::Hours(_) => "relative-hours-ago",
+            RelativeTime::Days(_) => "relative-days-ago",
+            RelativeTime::Months(_) => "relative-months-ago",
+            RelativeTime::Years(_) => "relative-years-ago",
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        match *self {
+            RelativeTime::JustNow => 0,
+            RelativeTime::Minutes(count)
+            | RelativeTime::Hours(count)
+            | RelativeTime::Days(count)
+            | RelativeTime::Months(count)
+            | RelativeTime::Years(count) => count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use time::macros::{date, datetime, offset};
+    use unic_langid::langid;
+
+    fn locale(language: LanguageIdentifier) -> LocaleFormat {
+        LocaleFormat::for_language(&language)
+    }
+
+    #[test]
+    fn test_en_us() {
+        let en = locale(langid!("en-US"));
+        assert_eq!(en.integer(0), "0");
+        assert_eq!(en.integer(999), "999");
+        assert_eq!(en.integer(1_234_567), "1,234,567");
+        assert_eq!(
+            en.decimal(Decimal::from_str("-1234.05").unwrap()),
+            "-1,234.05"
+        );
+        assert_eq!(en.date(date!(2026 - 03 - 07)), "03/07/2026");
+        let at = datetime!(2026-03-07 00:05 UTC);
+        assert_eq!(
+            en.date_time(&at, UtcOffset::UTC),
+            "03/07/2026, 12:05\u{a0}AM"
+        );
+        assert_eq!(en.date_time(&at, offset!(+14)), "03/07/2026, 2:05\u{a0}PM");
+        assert_eq!(en.timestamp(&at), "03/07/2026, 12:05\u{a0}AM UTC");
+        assert_eq!(en.percent(0.5), "50%");
+        assert_eq!(en.percent(0.0), "0%");
+    }
+
+    #[test]
+    fn test_en_gb() {
+        let gb = locale(langid!("en-GB"));
+        assert_eq!(gb.integer(1_234), "1,234");
+        assert_eq!(gb.date(date!(2026 - 03 - 07)), "07/03/2026");
+    }
+
+    #[test]
+    fn test_ru_ru() {
+        let ru = locale(langid!("ru-RU"));
+        assert_eq!(ru.integer(1_234_567), "1\u{a0}234\u{a0}567");
+        assert_eq!(
+            ru.decimal(Decimal::from_str("1234.5").unwrap()),
+            "1\u{a0}234,5"
+        );
+        assert_eq!(ru.date(date!(2026 - 03 - 07)), "07.03.2026");
+        let at = datetime!(2026-03-07 21:30 UTC);
+        assert_eq!(ru.date_time(&at, offset!(+3)), "08.03.2026, 00:30");
+        assert_eq!(ru.percent(0.5), "50\u{a0}%");
+        assert_eq!(ru.percent(2.0 / 3.0), "67\u{a0}%");
+    }
+
+    #[test]
+    fn test_other_languages() {
+        let de = locale(langid!("de-DE"));
+        assert_eq!(de.decimal(Decimal::from_str("1234.5").unwrap()), "1.234,5");
+        assert_eq!(de.date(date!(2026 - 03 - 07)), "07.03.2026");
+        assert_eq!(de.percent(0.25), "25\u{a0}%");
+        assert_eq!(locale(langid!("fr-FR")).percent(0.25), "25\u{202f}%");
+        assert_eq!(locale(langid!("it-IT")).percent(0.25), "25%");
+
+        // Unknown languages get ISO dates
+        let ja = locale(langid!("ja-JP"));
+        assert_eq!(ja.date(date!(2026 - 03 - 07)), "2026-03-07");
+    }
+
+    #[test]
+    fn test_times_are_shown_in_utc() {
+        // Late evening in New York is already the next day in UTC
+        let at = datetime!(2026-03-07 21:30 -05:00);
+        let ru = locale(langid!("ru-RU"));
+        assert_eq!(ru.timestamp(&at), "08.03.2026, 02:30 UTC");
+        assert_eq!(ru.utc_date(&at), "08.03.2026");
+        assert_eq!(locale(langid!("en-US")).utc_date(&at), "03/08/2026");
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = datetime!(2026-10-18 12:00 UTC);
+        let ago = |duration: time::Duration| RelativeTime::between(now - duration, now);
+        assert_eq!(ago(time::Duration::seconds(30)), RelativeTime::JustNow);
+        assert_eq!(ago(time::Duration::minutes(5)), RelativeTime::Minutes(5));
+        assert_eq!(ago(time::Duration::hours(23)), RelativeTime::Hours(23));
+        assert_eq!(ago(time::Duration::days(3)), RelativeTime::Days(3));
+        assert_eq!(ago(time::Duration::days(65)), RelativeTime::Months(2));
+        assert_eq!(ago(time::Duration::days(800)), RelativeTime::Years(2));
+        // Clock skew: a moment slightly in the future
+        assert_eq!(ago(time::Duration::minutes(-2)), RelativeTime::JustNow);
+        assert_eq!(RelativeTime::Days(3).count(), 3);
+    }
 }
@@ -0,0 +1,135 @@
+/// Actions reachable from the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shortcut {
+    FocusFilter,
+    SelectNext,
+    SelectPrevious,
+    OpenSelected,
+    CopySlug,
+    ToggleFavorite,
+    PreviousModel,
+    NextModel,
+    ShowHelp,
+    Close,
+}
+
+impl Shortcut {
+    /// All shortcuts in the order they are listed in the cheat sheet
+    pub const ALL: [Shortcut; 10] = [
+        Shortcut::FocusFilter,
+        Shortcut::SelectNext,
+        Shortcut::SelectPrevious,
+        Shortcut::OpenSelected,
+        Shortcut::CopySlug,
+        Shortcut::ToggleFavorite,
+        Shortcut::PreviousModel,
+        Shortcut::NextModel,
+        Shortcut::ShowHelp,
+        Shortcut::Close,
+    ];
+
+    /// Map a `KeyboardEvent.key` value to its shortcut
+    pub fn from_key(key: &str) -> Option<Self> {
+        Some(match key {
+            "/" => Shortcut::FocusFilter,
+            "ArrowDown" | "j" => Shortcut::SelectNext,
+            "ArrowUp" | "k" => Shortcut::SelectPrevious,
+            "Enter" => Shortcut::OpenSelected,
+            "c" => Shortcut::CopySlug,
+            "f" => Shortcut::ToggleFavorite,
+            "[" => Shortcut::PreviousModel,
+            "]" => Shortcut::NextModel,
+            "?" => Shortcut::ShowHelp,
+            "Escape" => Shortcut::Close,
+            _ => return None,
+        })
+    }
+
+    /// Keys shown for the shortcut in the cheat sheet
+    pub fn keys(&self) -> &'static [&'static str] {
+        match self {
+            Shortcut::FocusFilter => &["/"],
+            Shortcut::SelectNext => &["↓", "j"],
+            Shortcut::SelectPrevious => &["↑", "k"],
+            Shortcut::OpenSelected => &["Enter"],
+            Shortcut::CopySlug => &["c"],
+            Shortcut::ToggleFavorite => &["f"],
+            Shortcut::PreviousModel => &["["],
+            Shortcut::NextModel => &["]"],
+            Shortcut::ShowHelp => &["?"],
+            Shortcut::Close => &["Esc"],
+        }
+    }
+
+    /// Fluent key of the shortcut description
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Shortcut::FocusFilter => "shortcut-focus-filter",
+            Shortcut::SelectNext => "shortcut-select-next",
+            Shortcut::SelectPrevious => "shortcut-select-previous",
+            Shortcut::OpenSelected => "shortcut-open-selected",
+            Shortcut::CopySlug => "shortcut-copy-slug",
+            Shortcut::ToggleFavorite => "shortcut-toggle-favorite",
+            Shortcut::PreviousModel => "shortcut-previous-model",
+            Shortcut::NextModel => "shortcut-next-model",
+            Shortcut::ShowHelp => "shortcut-show-help",
+            Shortcut::Close => "shortcut-close",
+        }
+    }
+}
+
+/// Move a selection `delta` steps through `len` items, stopping at both ends.
+///
+/// Without a current selection, moving forward selects the first item and moving
+/// backward the last one.
+pub fn step_selection(current: Option<usize>, len: usize, delta: isize) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let last = len - 1;
+    Some(match current {
+        None if delta >= 0 => 0,
+        None => last,
+        Some(index) => index.min(last).saturating_add_signed(delta).min(last),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_key() {
+        assert_eq!(Shortcut::from_key("j"), Some(Shortcut::SelectNext));
+        assert_eq!(Shortcut::from_key("ArrowDown"), Some(Shortcut::SelectNext));
+        assert_eq!(Shortcut::from_key("k"), Some(Shortcut::SelectPrevious));
+        assert_eq!(Shortcut::from_key("]"), Some(Shortcut::NextModel));
+        assert_eq!(Shortcut::from_key("x"), None);
+        assert_eq!(Shortcut::from_key("J"), None);
+    }
+
+    #[test]
+    fn test_every_shortcut_has_keys() {
+        for shortcut in Shortcut::ALL {
+            assert!(!shortcut.keys().is_empty(), "{shortcut:?}");
+        }
+    }
+
+    #[test]
+    fn test_step_selection() {
+        // Starting without a selection
+        assert_eq!(step_selection(None, 3, 1), Some(0));
+        assert_eq!(step_selection(None, 3, -1), Some(2));
+
+        // Moving and clamping at both ends
+        assert_eq!(step_selection(Some(0), 3, 1), Some(1));
+        assert_eq!(step_selection(Some(2), 3, 1), Some(2));
+        assert_eq!(step_selection(Some(0), 3, -1), Some(0));
+
+        // A stale index past the end (the list shrank) is clamped first
+        assert_eq!(step_selection(Some(10), 3, -1), Some(1));
+
+        // Nothing to select
+        assert_eq!(step_selection(Some(1), 0, 1), None);
+    }
+}
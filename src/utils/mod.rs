@@ -1,7 +1,21 @@
+pub mod chart;
 pub mod filter;
 pub mod format;
+pub mod keyboard;
+pub mod money;
+pub mod providers;
+pub mod snippets;
+pub mod sort;
+pub mod token_count;
 pub mod tokenize;
 
+pub use chart::{axis_value, provider_color, AxisScale, LinearScale, LogScale};
 pub use filter::{has_all_modalities, matches_any_token_sequence};
-pub use format::{format_price_per_invocation, format_price_per_million, format_timestamp, format_with_commas};
+pub use format::{DateOrder, LocaleFormat, RelativeTime};
+pub use keyboard::{step_selection, Shortcut};
+pub use money::{Money, SymbolPosition};
+pub use providers::{summarize_providers, PriceStats, ProviderSummary};
+pub use snippets::SnippetFormat;
+pub use sort::{compare_models, sort_models};
+pub use token_count::{count_tokens, estimate_tokens, TokenCount, TokenCounts};
 pub use tokenize::tokenize;
@@ -0,0 +1,250 @@
+use rust_decimal::Decimal;
+use unic_langid::LanguageIdentifier;
+
+use crate::models::{Currency, CurrencySettings, PriceUnit, PricingField};
+
+use super::format::LocaleFormat;
+
+/// Significant digits kept after converting a price to another currency
+const CONVERTED_SIGNIFICANT_DIGITS: u32 = 4;
+
+/// Where the currency symbol goes relative to the amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// `$1.5`
+    Prefix,
+    /// `1.5 $`
+    Suffix,
+}
+
+impl SymbolPosition {
+    /// Symbol placement customary for the language
+    pub fn for_language(language: &LanguageIdentifier) -> Self {
+        match language.language.as_str() {
+            "ru" | "de" | "fr" | "es" | "it" | "pl" | "uk" | "kk" => SymbolPosition::Suffix,
+            _ => SymbolPosition::Prefix,
+        }
+    }
+}
+
+/// Formats catalog prices (in rubles) in the display currency and price unit.
+///
+/// Every price shown in the interface goes through this formatter so the currency, the
+/// exchange rate, the price unit and the symbol placement are applied in one place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Money {
+    currency: Currency,
+    /// Rubles per unit of `currency` (one for rubles)
+    rub_per_unit: Decimal,
+    /// Number of tokens per-token prices are shown for
+    unit: PriceUnit,
+    position: SymbolPosition,
+    /// Digit grouping and decimal separator of the interface language
+    numbers: LocaleFormat,
+}
+
+impl Default for Money {
+    /// Rubles per 1M tokens with a leading symbol, as the catalog is priced
+    fn default() -> Self {
+        Money {
+            currency: Currency::Rub,
+            rub_per_unit: Decimal::ONE,
+            unit: PriceUnit::default(),
+            position: SymbolPosition::Prefix,
+            numbers: LocaleFormat::default(),
+        }
+    }
+}
+
+impl Money {
+    pub fn new(
+        settings: &CurrencySettings,
+        unit: PriceUnit,
+        language: &LanguageIdentifier,
+    ) -> Self {
+        let rate = settings.rate(settings.currency);
+        Money {
+            currency: settings.currency,
+            rub_per_unit: rate.map_or(Decimal::ONE, |rate| rate.rub_per_unit),
+            unit,
+            position: SymbolPosition::for_language(language),
+            numbers: LocaleFormat::for_language(language),
+        }
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn unit(&self) -> PriceUnit {
+        self.unit
+    }
+
+    pub fn locale(&self) -> LocaleFormat {
+        self.numbers
+    }
+
+    /// Convert an amount in rubles to the display currency.
+    ///
+    /// Converted amounts are rounded to a few significant digits, since the rates themselves are
+    /// approximate; ruble amounts are kept exact.
+    pub fn convert(&self, rub: Decimal) -> Decimal {
+        if self.rub_per_unit == Decimal::ONE {
+            return rub;
+        }
+        rub.checked_div(self.rub_per_unit)
+            .and_then(|amount| amount.round_sf(CONVERTED_SIGNIFICANT_DIGITS))
+            .unwrap_or_default()
+    }
+
+    /// Format an amount in rubles, e.g. `$0.0015` or `0,0015 $`
+    pub fn format(&self, rub: Decimal) -> String {
+        let amount = self.numbers.decimal(self.convert(rub).normalize());
+        let symbol = self.currency.symbol();
+        match self.position {
+            SymbolPosition::Prefix => format!("{symbol}{amount}"),
+            // No-break space so the symbol never wraps away from the amount
+            SymbolPosition::Suffix => format!("{amount}\u{a0}{symbol}"),
+        }
+    }
+
+    /// Format a per-token price for the chosen price unit, e.g. `₽0.12` per 1M tokens
+    pub fn per_unit(&self, price: Decimal) -> String {
+        self.format(self.unit.scale(price))
+    }
+
+    /// Format a pricing component per price unit or per invocation, as appropriate for the field
+    pub fn pricing_field(&self, field: PricingField, price: Decimal) -> String {
+        if field.is_per_token() {
+            self.per_unit(price)
+        } else {
+            self.format(price)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExchangeRate;
+    use time::macros::date;
+    use unic_langid::langid;
+
+    fn usd_at(rub_per_unit: i64) -> CurrencySettings {
+        let mut settings = CurrencySettings {
+            currency: Currency::Usd,
+            ..Default::default()
+        };
+        settings.set_custom_rate(
+            Currency::Usd,
+            Decimal::from(rub_per_unit),
+            date!(2025 - 11 - 02),
+        );
+        settings
+    }
+
+    #[test]
+    fn test_default_is_rubles() {
+        let money = Money::default();
+        assert_eq!(money.format(Decimal::new(15, 4)), "₽0.0015");
+        assert_eq!(money.per_unit(Decimal::new(12, 8)), "₽0.12");
+        assert_eq!(
+            Money::new(
+                &CurrencySettings::default(),
+                PriceUnit::default(),
+                &langid!("en-US")
+            ),
+            money
+        );
+    }
+
+    #[test]
+    fn test_conversion() {
+        let money = Money::new(&usd_at(80), PriceUnit::default(), &langid!("en-US"));
+        assert_eq!(money.convert(Decimal::from(160)), Decimal::from(2));
+        assert_eq!(money.format(Decimal::from(160)), "$2");
+        // 1 / 80 = 0.0125; a third: 4 significant digits
+        assert_eq!(money.format(Decimal::ONE), "$0.0125");
+        assert_eq!(
+            money.format(Decimal::from(100) / Decimal::from(3)),
+            "$0.4167"
+        );
+    }
+
+    #[test]
+    fn test_bundled_rate_is_used() {
+        let settings = CurrencySettings {
+            currency: Currency::Eur,
+            ..Default::default()
+        };
+        let money = Money::new(&settings, PriceUnit::default(), &langid!("en-US"));
+        let rate = ExchangeRate::bundled(Currency::Eur).unwrap().rub_per_unit;
+        assert_eq!(money.format(rate), "€1");
+    }
+
+    #[test]
+    fn test_symbol_placement_follows_language() {
+        let amount = Decimal::from(160);
+        assert_eq!(
+            Money::new(&usd_at(80), PriceUnit::default(), &langid!("en-US")).format(amount),
+            "$2"
+        );
+        assert_eq!(
+            Money::new(&usd_at(80), PriceUnit::default(), &langid!("ru-RU")).format(amount),
+            "2\u{a0}$"
+        );
+        assert_eq!(
+            Money::new(
+                &CurrencySettings::default(),
+                PriceUnit::default(),
+                &langid!("ru-RU")
+            )
+            .format(amount),
+            "160\u{a0}₽"
+        );
+    }
+
+    #[test]
+    fn test_separators_follow_language() {
+        let amount = Decimal::new(12_345, 1);
+        let rubles = |language| {
+            Money::new(
+                &CurrencySettings::default(),
+                PriceUnit::default(),
+                &language,
+            )
+            .format(amount)
+        };
+        assert_eq!(rubles(langid!("en-US")), "₽1,234.5");
+        assert_eq!(rubles(langid!("ru-RU")), "1\u{a0}234,5\u{a0}₽");
+    }
+
+    #[test]
+    fn test_pricing_field_units() {
+        let money = Money::default();
+        assert_eq!(
+            money.pricing_field(PricingField::Prompt, Decimal::new(12, 8)),
+            "₽0.12"
+        );
+        assert_eq!(
+            money.pricing_field(PricingField::Request, Decimal::new(5, 1)),
+            "₽0.5"
+        );
+    }
+
+    #[test]
+    fn test_price_units() {
+        let price = Decimal::new(12, 8);
+        let per = |unit| {
+            Money::new(&CurrencySettings::default(), unit, &langid!("en-US")).per_unit(price)
+        };
+        assert_eq!(per(PriceUnit::Token), "₽0.00000012");
+        assert_eq!(per(PriceUnit::Thousand), "₽0.00012");
+        assert_eq!(per(PriceUnit::Million), "₽0.12");
+        assert_eq!(per(PriceUnit::Billion), "₽120");
+
+        // Conversion happens after scaling, so no digits are lost on tiny per-token prices
+        let money = Money::new(&usd_at(80), PriceUnit::Token, &langid!("en-US"));
+        assert_eq!(money.per_unit(Decimal::new(1, 8)), "$0.000000000125");
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rust_decimal::Decimal;
+
+use crate::models::{Modality, Model};
+
+/// Minimum, median and maximum of a set of prices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceStats {
+    pub min: Decimal,
+    pub median: Decimal,
+    pub max: Decimal,
+}
+
+impl PriceStats {
+    /// Compute the statistics (`None` for an empty set).
+    ///
+    /// For an even number of prices the median is the mean of the two middle ones.
+    pub fn from_prices(mut prices: Vec<Decimal>) -> Option<Self> {
+        prices.sort();
+        let (min, max) = (*prices.first()?, *prices.last()?);
+        let middle = prices.len() / 2;
+        let median = if prices.len().is_multiple_of(2) {
+            (prices[middle - 1] + prices[middle]) / Decimal::TWO
+        } else {
+            prices[middle]
+        };
+        Some(Self { min, median, max })
+    }
+}
+
+/// Aggregated figures for all models of one provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderSummary {
+    pub name: String,
+    pub model_count: usize,
+    pub prompt: PriceStats,
+    pub completion: PriceStats,
+    pub input_modalities: BTreeSet<Modality>,
+    pub output_modalities: BTreeSet<Modality>,
+    /// Most recently created model
+    pub newest: Model,
+    /// Fraction of models that are moderated, from 0.0 to 1.0
+    pub moderated_share: f64,
+}
+
+/// Summarize the models per provider, largest providers first (ties by name)
+pub fn summarize_providers(models: &[Model]) -> Vec<ProviderSummary> {
+    let mut by_provider: BTreeMap<&str, Vec<&Model>> = BTreeMap::new();
+    for model in models {
+        if let Some(provider) = model.provider() {
+            by_provider.entry(provider).or_default().push(model);
+        }
+    }
+
+    let mut summaries: Vec<ProviderSummary> = by_provider
+        .into_iter()
+        .filter_map(|(name, models)| {
+            let newest = models.iter().max_by_key(|model| model.created)?;
+            let moderated = models
+                .iter()
+                .filter(|model| model.top_provider.is_moderated)
+                .count();
+
+            Some(ProviderSummary {
+                name: name.to_owned(),
+                model_count: models.len(),
+                prompt: PriceStats::from_prices(models.iter().map(|m| m.pricing.prompt).collect())?,
+                completion: PriceStats::from_prices(
+                    models.iter().map(|m| m.pricing.completion).collect(),
+                )?,
+                input_modalities: models
+                    .iter()
+                    .flat_map(|m| m.architecture.input_modalities.iter().copied())
+                    .collect(),
+                output_modalities: models
+                    .iter()
+                    .flat_map(|m| m.architecture.output_modalities.iter().copied())
+                    .collect(),
+                newest: (*newest).clone(),
+                moderated_share: moderated as f64 / models.len() as f64,
+            })
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| {
+        b.model_count
+            .cmp(&a.model_count)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiResponse;
+    use std::str::FromStr;
+
+    fn models() -> Vec<Model> {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        response.data
+    }
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_price_stats() {
+        assert_eq!(PriceStats::from_prices(vec![]), None);
+
+        let odd = PriceStats::from_prices(vec![dec("3"), dec("1"), dec("2")]).unwrap();
+        assert_eq!(
+            (odd.min, odd.median, odd.max),
+            (dec("1"), dec("2"), dec("3"))
+        );
+
+        let even = PriceStats::from_prices(vec![dec("4"), dec("1"), dec("2"), dec("10")]).unwrap();
+        assert_eq!(
+            (even.min, even.median, even.max),
+            (dec("1"), dec("3"), dec("10"))
+        );
+    }
+
+    #[test]
+    fn test_summaries_cover_every_model() {
+        let models = models();
+        let summaries = summarize_providers(&models);
+
+        let total: usize = summaries.iter().map(|s| s.model_count).sum();
+        assert_eq!(total, models.len());
+
+        // Largest provider first, then alphabetical
+        let names: Vec<&str> = summaries.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["openai", "deepcogito", "google", "nvidia"]);
+    }
+
+    #[test]
+    fn test_openai_summary() {
+        let summaries = summarize_providers(&models());
+        let openai = summaries.iter().find(|s| s.name == "openai").unwrap();
+
+        assert_eq!(openai.model_count, 2);
+        assert_eq!(openai.prompt.min, dec("0.0000093"));
+        assert_eq!(openai.prompt.median, dec("0.000119865"));
+        assert_eq!(openai.prompt.max, dec("0.00023043"));
+        assert_eq!(openai.completion.min, Decimal::ZERO);
+        assert_eq!(openai.completion.median, dec("0.00009217"));
+        assert_eq!(openai.completion.max, dec("0.00018434"));
+
+        assert_eq!(
+            openai.input_modalities,
+            BTreeSet::from([Modality::Text, Modality::Image, Modality::File])
+        );
+        assert_eq!(
+            openai.output_modalities,
+            BTreeSet::from([Modality::Text, Modality::Image, Modality::Embeddings])
+        );
+        assert_eq!(openai.newest.id, "openai/text-embedding-ada-002");
+        assert_eq!(openai.moderated_share, 1.0);
+    }
+
+    #[test]
+    fn test_single_model_provider() {
+        let summaries = summarize_providers(&models());
+        let nvidia = summaries.iter().find(|s| s.name == "nvidia").unwrap();
+
+        assert_eq!(nvidia.model_count, 1);
+        assert_eq!(nvidia.prompt.min, nvidia.prompt.max);
+        assert_eq!(nvidia.prompt.median, dec("0.00001771"));
+        assert_eq!(nvidia.moderated_share, 0.0);
+    }
+}
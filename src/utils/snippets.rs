@@ -0,0 +1,245 @@
+use crate::api::API_BASE_URL;
+use crate::models::{Modality, Model, PriceUnit};
+
+/// Placeholder prompt used in the generated requests
+const SAMPLE_INPUT: &str = "Hello!";
+
+/// Environment variable the generated snippets read the API key from
+const API_KEY_VARIABLE: &str = "POLZA_API_KEY";
+
+/// What the copy menu can put on the clipboard for a model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnippetFormat {
+    Slug,
+    RequestJson,
+    Curl,
+    Python,
+    TypeScript,
+    PricingJson,
+}
+
+impl SnippetFormat {
+    /// All formats in the order they are listed in the copy menu
+    pub const ALL: [SnippetFormat; 6] = [
+        SnippetFormat::Slug,
+        SnippetFormat::RequestJson,
+        SnippetFormat::Curl,
+        SnippetFormat::Python,
+        SnippetFormat::TypeScript,
+        SnippetFormat::PricingJson,
+    ];
+
+    /// Fluent key of the menu entry
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SnippetFormat::Slug => "copy-format-slug",
+            SnippetFormat::RequestJson => "copy-format-request-json",
+            SnippetFormat::Curl => "copy-format-curl",
+            SnippetFormat::Python => "copy-format-python",
+            SnippetFormat::TypeScript => "copy-format-typescript",
+            SnippetFormat::PricingJson => "copy-format-pricing-json",
+        }
+    }
+
+    /// Text copied for the model, with per-token prices quoted per `unit`.
+    ///
+    /// Embedding models get an `/embeddings` request, everything else a chat completion.
+    pub fn render(&self, model: &Model, unit: PriceUnit) -> String {
+        let endpoint = Endpoint::for_model(model);
+        // A JSON string literal is also a valid Python and TypeScript one
+        let id = serde_json::to_string(&model.id).unwrap_or_default();
+
+        match self {
+            SnippetFormat::Slug => model.canonical_slug.clone(),
+            SnippetFormat::PricingJson => model.pricing.to_json(unit),
+            SnippetFormat::RequestJson => request_json(model, endpoint),
+            SnippetFormat::Curl => format!(
+                "curl {API_BASE_URL}{path} \\\n  \
+                 -H \"Content-Type: application/json\" \\\n  \
+                 -H \"Authorization: Bearer ${API_KEY_VARIABLE}\" \\\n  \
+                 -d '{body}'",
+                path = endpoint.path(),
+                body = request_json(model, endpoint).replace('\'', r"'\''"),
+            ),
+            SnippetFormat::Python => {
+                let call = match endpoint {
+                    Endpoint::ChatCompletions => format!(
+                        "completion = client.chat.completions.create(\n    \
+                         model={id},\n    \
+                         messages=[{{\"role\": \"user\", \"content\": \"{SAMPLE_INPUT}\"}}],\n\
+                         )\n\
+                         print(completion.choices[0].message.content)"
+                    ),
+                    Endpoint::Embeddings => format!(
+                        "response = client.embeddings.create(\n    \
+                         model={id},\n    \
+                         input=\"{SAMPLE_INPUT}\",\n\
+                         )\n\
+                         print(response.data[0].embedding)"
+                    ),
+                };
+                format!(
+                    "import os\n\n\
+                     from openai import OpenAI\n\n\
+                     client = OpenAI(\n    \
+                     base_url=\"{API_BASE_URL}\",\n    \
+                     api_key=os.environ[\"{API_KEY_VARIABLE}\"],\n\
+                     )\n\n\
+                     {call}\n"
+                )
+            }
+            SnippetFormat::TypeScript => {
+                let call = match endpoint {
+                    Endpoint::ChatCompletions => format!(
+                        "const completion = await client.chat.completions.create({{\n  \
+                         model: {id},\n  \
+                         messages: [{{ role: \"user\", content: \"{SAMPLE_INPUT}\" }}],\n\
+                         }});\n\
+                         console.log(completion.choices[0].message.content);"
+                    ),
+                    Endpoint::Embeddings => format!(
+                        "const response = await client.embeddings.create({{\n  \
+                         model: {id},\n  \
+                         input: \"{SAMPLE_INPUT}\",\n\
+                         }});\n\
+                         console.log(response.data[0].embedding);"
+                    ),
+                };
+                format!(
+                    "import OpenAI from \"openai\";\n\n\
+                     const client = new OpenAI({{\n  \
+                     baseURL: \"{API_BASE_URL}\",\n  \
+                     apiKey: process.env.{API_KEY_VARIABLE},\n\
+                     }});\n\n\
+                     {call}\n"
+                )
+            }
+        }
+    }
+}
+
+/// OpenAI-compatible endpoint a model is called through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Endpoint {
+    ChatCompletions,
+    Embeddings,
+}
+
+impl Endpoint {
+    fn for_model(model: &Model) -> Self {
+        if model
+            .architecture
+            .output_modalities
+            .contains(&Modality::Embeddings)
+        {
+            Endpoint::Embeddings
+        } else {
+            Endpoint::ChatCompletions
+        }
+    }
+
+    fn path(&self) -> &'static str {
+        match self {
+            Endpoint::ChatCompletions => "/chat/completions",
+            Endpoint::Embeddings => "/embeddings",
+        }
+    }
+}
+
+/// Pretty-printed request body for the endpoint
+fn request_json(model: &Model, endpoint: Endpoint) -> String {
+    let body = match endpoint {
+        Endpoint::ChatCompletions => serde_json::json!({
+            "model": model.id,
+            "messages": [{ "role": "user", "content": SAMPLE_INPUT }],
+        }),
+        Endpoint::Embeddings => serde_json::json!({
+            "model": model.id,
+            "input": SAMPLE_INPUT,
+        }),
+    };
+    serde_json::to_string_pretty(&body).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiResponse;
+
+    fn model(id: &str) -> Model {
+        let response: ApiResponse = serde_json::from_str(include_str!("../models.json")).unwrap();
+        response.data.into_iter().find(|m| m.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_request_json() {
+        let chat = model("openai/gpt-5-image-mini");
+        let body: serde_json::Value =
+            serde_json::from_str(&SnippetFormat::RequestJson.render(&chat, PriceUnit::Million))
+                .unwrap();
+        assert_eq!(body["model"], "openai/gpt-5-image-mini");
+        assert_eq!(body["messages"][0]["role"], "user");
+
+        let embeddings = model("openai/text-embedding-ada-002");
+        let body: serde_json::Value = serde_json::from_str(
+            &SnippetFormat::RequestJson.render(&embeddings, PriceUnit::Million),
+        )
+        .unwrap();
+        assert_eq!(body["model"], "openai/text-embedding-ada-002");
+        assert_eq!(body["input"], SAMPLE_INPUT);
+        assert!(body.get("messages").is_none());
+    }
+
+    #[test]
+    fn test_curl() {
+        let curl =
+            SnippetFormat::Curl.render(&model("openai/gpt-5-image-mini"), PriceUnit::Million);
+        assert!(curl.starts_with("curl https://api.polza.ai/api/v1/chat/completions \\\n"));
+        assert!(curl.contains("-H \"Authorization: Bearer $POLZA_API_KEY\""));
+        assert!(curl.contains("\"model\": \"openai/gpt-5-image-mini\""));
+
+        let curl =
+            SnippetFormat::Curl.render(&model("openai/text-embedding-ada-002"), PriceUnit::Million);
+        assert!(curl.starts_with("curl https://api.polza.ai/api/v1/embeddings \\\n"));
+    }
+
+    #[test]
+    fn test_sdk_snippets_use_model_id() {
+        let chat = model("google/gemini-2.5-flash-lite-preview-09-2025");
+
+        let python = SnippetFormat::Python.render(&chat, PriceUnit::Million);
+        assert!(python.contains("base_url=\"https://api.polza.ai/api/v1\""));
+        assert!(python.contains("model=\"google/gemini-2.5-flash-lite-preview-09-2025\",\n"));
+        assert!(python.contains("client.chat.completions.create("));
+
+        let typescript = SnippetFormat::TypeScript.render(&chat, PriceUnit::Million);
+        assert!(typescript.contains("baseURL: \"https://api.polza.ai/api/v1\""));
+        assert!(typescript.contains("model: \"google/gemini-2.5-flash-lite-preview-09-2025\",\n"));
+
+        let embeddings = SnippetFormat::Python
+            .render(&model("openai/text-embedding-ada-002"), PriceUnit::Million);
+        assert!(embeddings.contains("client.embeddings.create("));
+    }
+
+    #[test]
+    fn test_pricing_json_uses_the_unit() {
+        let chat = model("openai/gpt-5-image-mini");
+        let json: serde_json::Value =
+            serde_json::from_str(&SnippetFormat::PricingJson.render(&chat, PriceUnit::Thousand))
+                .unwrap();
+        assert_eq!(json["unit"], "thousand");
+        assert_eq!(
+            json["prompt"],
+            serde_json::json!(PriceUnit::Thousand.scale(chat.pricing.prompt).normalize())
+        );
+    }
+
+    #[test]
+    fn test_slug() {
+        let chat = model("openai/gpt-5-image-mini");
+        assert_eq!(
+            SnippetFormat::Slug.render(&chat, PriceUnit::Million),
+            chat.canonical_slug
+        );
+    }
+}
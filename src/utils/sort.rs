@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+
+use crate::models::{Model, SortDirection, SortField, SortKey};
+
+/// Compare two models by a single field in ascending order
+fn compare_by_field(a: &Model, b: &Model, field: SortField) -> Ordering {
+    match field {
+        SortField::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortField::Provider => a.provider().cmp(&b.provider()),
+        SortField::Created => a.created.cmp(&b.created),
+        SortField::PromptPrice => a.pricing.prompt.cmp(&b.pricing.prompt),
+        SortField::CompletionPrice => a.pricing.completion.cmp(&b.pricing.completion),
+        SortField::InternalReasoningPrice => a
+            .pricing
+            .internal_reasoning
+            .cmp(&b.pricing.internal_reasoning),
+        SortField::InputCacheReadPrice => {
+            a.pricing.input_cache_read.cmp(&b.pricing.input_cache_read)
+        }
+        SortField::InputCacheWritePrice => a
+            .pricing
+            .input_cache_write
+            .cmp(&b.pricing.input_cache_write),
+        SortField::ImagePrice => a.pricing.image.cmp(&b.pricing.image),
+        SortField::RequestPrice => a.pricing.request.cmp(&b.pricing.request),
+        SortField::WebSearchPrice => a.pricing.web_search.cmp(&b.pricing.web_search),
+        SortField::ContextLength => a
+            .top_provider
+            .context_length
+            .cmp(&b.top_provider.context_length),
+        SortField::MaxCompletionTokens => max_completion_rank(a).cmp(&max_completion_rank(b)),
+    }
+}
+
+/// Max completion tokens for ordering purposes (zero means "no limit", so it ranks highest)
+fn max_completion_rank(model: &Model) -> usize {
+    match model.top_provider.max_completion_tokens {
+        0 => usize::MAX,
+        tokens => tokens,
+    }
+}
+
+/// Compare two models by the given sort keys, in order of priority.
+///
+/// Models that are equal on every key are ordered by `id`, so the result is deterministic.
+pub fn compare_models(a: &Model, b: &Model, keys: &[SortKey]) -> Ordering {
+    keys.iter()
+        .map(|key| {
+            let comparison = compare_by_field(a, b, key.field);
+            match key.direction {
+                SortDirection::Ascending => comparison,
+                SortDirection::Descending => comparison.reverse(),
+            }
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.id.cmp(&b.id))
+}
+
+/// Sort models in place by the given sort keys
+pub fn sort_models(models: &mut [&Model], keys: &[SortKey]) {
+    models.sort_by(|a, b| compare_models(a, b, keys));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Architecture, Modality, Pricing, TopProvider};
+    use rust_decimal::Decimal;
+
+    fn model(id: &str, prompt: i64, completion: i64, created: i64) -> Model {
+        Model {
+            name: id.to_string(),
+            id: id.to_string(),
+            created: time::OffsetDateTime::from_unix_timestamp(created).unwrap(),
+            canonical_slug: id.to_string(),
+            pricing: Pricing {
+                prompt: Decimal::new(prompt, 6),
+                completion: Decimal::new(completion, 6),
+                image: Decimal::ZERO,
+                request: Decimal::ZERO,
+                web_search: Decimal::ZERO,
+                internal_reasoning: Decimal::ZERO,
+                input_cache_read: Decimal::ZERO,
+                input_cache_write: Decimal::ZERO,
+            },
+            architecture: Architecture {
+                input_modalities: vec![Modality::Text],
+                output_modalities: vec![Modality::Text],
+                tokenizer: None,
+            },
+            name_tokens: vec![],
+            top_provider: TopProvider {
+                context_length: 8192,
+                max_completion_tokens: 0,
+                is_moderated: false,
+            },
+            supported_parameters: vec![],
+        }
+    }
+
+    fn ids(models: &[&Model]) -> Vec<String> {
+        models.iter().map(|m| m.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_single_key_both_directions() {
+        let cheap = model("a/cheap", 1, 5, 100);
+        let pricey = model("b/pricey", 9, 1, 200);
+        let mut models = vec![&pricey, &cheap];
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(
+                SortField::PromptPrice,
+                SortDirection::Ascending,
+            )],
+        );
+        assert_eq!(ids(&models), vec!["a/cheap", "b/pricey"]);
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(
+                SortField::PromptPrice,
+                SortDirection::Descending,
+            )],
+        );
+        assert_eq!(ids(&models), vec!["b/pricey", "a/cheap"]);
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(
+                SortField::CompletionPrice,
+                SortDirection::Ascending,
+            )],
+        );
+        assert_eq!(ids(&models), vec!["b/pricey", "a/cheap"]);
+    }
+
+    #[test]
+    fn test_secondary_and_tertiary_keys() {
+        let a1 = model("anthropic/one", 3, 1, 100);
+        let a2 = model("anthropic/two", 1, 1, 100);
+        let g1 = model("google/one", 2, 2, 300);
+        let g2 = model("google/two", 2, 1, 200);
+        let mut models = vec![&g1, &a1, &g2, &a2];
+
+        // Provider ascending, then prompt price ascending, then completion price ascending
+        let keys = [
+            SortKey::new(SortField::Provider, SortDirection::Ascending),
+            SortKey::new(SortField::PromptPrice, SortDirection::Ascending),
+            SortKey::new(SortField::CompletionPrice, SortDirection::Ascending),
+        ];
+        sort_models(&mut models, &keys);
+        assert_eq!(
+            ids(&models),
+            vec!["anthropic/two", "anthropic/one", "google/two", "google/one"]
+        );
+    }
+
+    #[test]
+    fn test_ties_break_by_id() {
+        let b = model("x/b", 1, 1, 100);
+        let a = model("x/a", 1, 1, 100);
+        let c = model("x/c", 1, 1, 100);
+
+        let mut models = vec![&b, &c, &a];
+        sort_models(
+            &mut models,
+            &[SortKey::new(
+                SortField::PromptPrice,
+                SortDirection::Descending,
+            )],
+        );
+        assert_eq!(ids(&models), vec!["x/a", "x/b", "x/c"]);
+
+        // No keys at all still yields a deterministic order
+        let mut models = vec![&c, &a, &b];
+        sort_models(&mut models, &[]);
+        assert_eq!(ids(&models), vec!["x/a", "x/b", "x/c"]);
+    }
+
+    #[test]
+    fn test_provider_ignores_openrouter_prefix() {
+        let routed = model("openrouter/anthropic/claude", 1, 1, 100);
+        let direct = model("google/gemini", 1, 1, 100);
+        let mut models = vec![&direct, &routed];
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(SortField::Provider, SortDirection::Ascending)],
+        );
+        assert_eq!(
+            ids(&models),
+            vec!["openrouter/anthropic/claude", "google/gemini"]
+        );
+    }
+
+    #[test]
+    fn test_unlimited_completion_tokens_rank_highest() {
+        let mut limited = model("x/limited", 1, 1, 100);
+        limited.top_provider.max_completion_tokens = 4096;
+        let unlimited = model("x/unlimited", 1, 1, 100);
+        let mut models = vec![&unlimited, &limited];
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(
+                SortField::MaxCompletionTokens,
+                SortDirection::Ascending,
+            )],
+        );
+        assert_eq!(ids(&models), vec!["x/limited", "x/unlimited"]);
+    }
+
+    #[test]
+    fn test_name_is_case_insensitive() {
+        let mut upper = model("x/1", 1, 1, 100);
+        upper.name = "Beta".to_string();
+        let mut lower = model("x/2", 1, 1, 100);
+        lower.name = "alpha".to_string();
+        let mut models = vec![&upper, &lower];
+
+        sort_models(
+            &mut models,
+            &[SortKey::new(SortField::Name, SortDirection::Ascending)],
+        );
+        assert_eq!(ids(&models), vec!["x/2", "x/1"]);
+    }
+}
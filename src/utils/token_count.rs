@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+
+use crate::models::TokenizerFamily;
+
+/// Number of tokens a text takes for one tokenizer family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenCount {
+    pub tokens: u64,
+    /// `true` when counted with the family's own vocabulary, `false` for an estimate
+    pub exact: bool,
+}
+
+/// Token counts of one text for several tokenizer families
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenCounts(BTreeMap<TokenizerFamily, TokenCount>);
+
+impl TokenCounts {
+    /// Count the tokens of `text` once per distinct family
+    pub fn for_families(text: &str, families: impl IntoIterator<Item = TokenizerFamily>) -> Self {
+        let mut counts = BTreeMap::new();
+        for family in families {
+            counts
+                .entry(family)
+                .or_insert_with(|| count_tokens(text, family));
+        }
+        TokenCounts(counts)
+    }
+
+    pub fn get(&self, family: TokenizerFamily) -> Option<TokenCount> {
+        self.0.get(&family).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (TokenizerFamily, TokenCount)> + '_ {
+        self.0.iter().map(|(family, count)| (*family, *count))
+    }
+}
+
+/// Count the tokens of `text` for a tokenizer family.
+///
+/// OpenAI vocabularies are bundled (with the `exact-tokenizers` feature) and give exact counts;
+/// every other family falls back to [`estimate_tokens`].
+pub fn count_tokens(text: &str, family: TokenizerFamily) -> TokenCount {
+    #[cfg(feature = "exact-tokenizers")]
+    {
+        let bpe = match family {
+            TokenizerFamily::Gpt => Some(tiktoken_rs::o200k_base_singleton()),
+            TokenizerFamily::Cl100k => Some(tiktoken_rs::cl100k_base_singleton()),
+            _ => None,
+        };
+        if let Some(bpe) = bpe {
+            return TokenCount {
+                tokens: bpe.encode_ordinary(text).len() as u64,
+                exact: true,
+            };
+        }
+    }
+
+    TokenCount {
+        tokens: estimate_tokens(text, family),
+        exact: false,
+    }
+}
+
+/// Characters per token of punctuation and symbol runs, shared by all families
+const SYMBOL_CHARS_PER_TOKEN: f64 = 3.0;
+
+/// Average characters per token of a family, measured separately for the scripts that
+/// tokenize very differently
+struct Profile {
+    /// Letters and digits of Latin-script words
+    latin: f64,
+    /// Letters of other alphabets (Cyrillic, Greek, Arabic, ...)
+    other: f64,
+    /// Chinese, Japanese and Korean characters
+    cjk: f64,
+}
+
+impl Profile {
+    fn for_family(family: TokenizerFamily) -> Self {
+        let (latin, other, cjk) = match family {
+            TokenizerFamily::Gpt => (8.0, 5.0, 1.5),
+            TokenizerFamily::Cl100k => (8.0, 2.4, 1.0),
+            TokenizerFamily::Gemini => (8.0, 4.5, 1.5),
+            TokenizerFamily::Llama | TokenizerFamily::Grok => (8.0, 3.5, 1.2),
+            TokenizerFamily::Qwen | TokenizerFamily::DeepSeek => (8.0, 3.5, 1.5),
+            TokenizerFamily::Mistral => (7.0, 3.0, 1.0),
+            TokenizerFamily::Claude => (6.5, 2.4, 0.9),
+            // Unknown vocabularies err on the side of more tokens
+            TokenizerFamily::Other => (6.0, 2.4, 0.9),
+        };
+        Profile { latin, other, cjk }
+    }
+}
+
+/// Script class of a character, as far as token boundaries are concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Latin,
+    Other,
+    Cjk,
+    Space,
+    Newline,
+    Symbol,
+}
+
+impl CharClass {
+    fn of(ch: char) -> Self {
+        if ch == '\n' {
+            CharClass::Newline
+        } else if ch.is_whitespace() {
+            CharClass::Space
+        } else if ch.is_ascii_alphanumeric() || ('\u{C0}'..='\u{24F}').contains(&ch) {
+            CharClass::Latin
+        } else if is_cjk(ch) {
+            CharClass::Cjk
+        } else if ch.is_alphanumeric() {
+            CharClass::Other
+        } else {
+            CharClass::Symbol
+        }
+    }
+}
+
+fn is_cjk(ch: char) -> bool {
+    matches!(ch,
+        '\u{1100}'..='\u{11FF}'     // Hangul Jamo
+        | '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}'   // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}'   // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}'   // CJK Compatibility Ideographs
+        | '\u{20000}'..='\u{2FA1F}' // CJK Extensions B-F
+    )
+}
+
+/// Estimate the token count of `text` without a vocabulary.
+///
+/// The text is split into runs of same-script characters; each word costs at least one token
+/// and long words one more per the family's average characters per token. Runs of punctuation
+/// and symbols take a token per three characters, line breaks one each. The OpenAI averages
+/// were calibrated on English, Russian and Chinese text against the bundled vocabularies; the
+/// other families are scaled from them by their vocabulary sizes.
+pub fn estimate_tokens(text: &str, family: TokenizerFamily) -> u64 {
+    let profile = Profile::for_family(family);
+    let mut tokens = 0.0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let class = CharClass::of(ch);
+        let mut length = 1usize;
+        while chars
+            .peek()
+            .is_some_and(|next| CharClass::of(*next) == class)
+        {
+            chars.next();
+            length += 1;
+        }
+
+        tokens += match class {
+            CharClass::Latin => (length as f64 / profile.latin).ceil(),
+            CharClass::Other => (length as f64 / profile.other).ceil(),
+            CharClass::Cjk => (length as f64 / profile.cjk).ceil(),
+            CharClass::Symbol => (length as f64 / SYMBOL_CHARS_PER_TOKEN).ceil(),
+            CharClass::Newline => 1.0,
+            // A single space is merged into the following word
+            CharClass::Space if length == 1 => 0.0,
+            CharClass::Space => 1.0,
+        };
+    }
+
+    tokens as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENGLISH: &str = "Prices are per token, but users think in documents. \
+        This estimate helps to compare what a typical prompt would cost with every model \
+        in the filtered list, including the fixed fee charged per request.";
+
+    #[test]
+    fn test_empty_text() {
+        assert_eq!(estimate_tokens("", TokenizerFamily::Claude), 0);
+        assert_eq!(count_tokens("", TokenizerFamily::Gpt).tokens, 0);
+    }
+
+    #[test]
+    fn test_counts_per_family() {
+        let counts = TokenCounts::for_families(
+            ENGLISH,
+            [
+                TokenizerFamily::Claude,
+                TokenizerFamily::Gemini,
+                TokenizerFamily::Claude,
+            ],
+        );
+        assert_eq!(counts.iter().count(), 2);
+        let claude = counts.get(TokenizerFamily::Claude).unwrap();
+        assert!(!claude.exact);
+        assert!(claude.tokens > 0);
+        assert_eq!(counts.get(TokenizerFamily::Llama), None);
+    }
+
+    #[test]
+    fn test_scripts_differ() {
+        // Cyrillic and CJK text takes more tokens per character than English
+        let english = estimate_tokens("hello world", TokenizerFamily::Other);
+        let russian = estimate_tokens("привет мир", TokenizerFamily::Other);
+        let chinese = estimate_tokens("你好世界", TokenizerFamily::Other);
+        assert_eq!(english, 2);
+        assert!(russian > english);
+        assert!(chinese >= 4);
+    }
+
+    #[cfg(feature = "exact-tokenizers")]
+    #[test]
+    fn test_exact_openai_counts() {
+        let gpt = count_tokens("Hello world!", TokenizerFamily::Gpt);
+        assert_eq!(
+            gpt,
+            TokenCount {
+                tokens: 3,
+                exact: true
+            }
+        );
+        assert!(count_tokens(ENGLISH, TokenizerFamily::Cl100k).exact);
+    }
+
+    #[cfg(feature = "exact-tokenizers")]
+    #[test]
+    fn test_heuristic_is_calibrated() {
+        // On longer English and Russian texts the estimate stays within 15% of the exact count
+        let texts = [
+            include_str!("../../README.md"),
+            include_str!("../../locales/ru-RU.ftl"),
+        ];
+        for family in [TokenizerFamily::Gpt, TokenizerFamily::Cl100k] {
+            for text in texts {
+                let exact = count_tokens(text, family).tokens as f64;
+                let estimate = estimate_tokens(text, family) as f64;
+                assert!(
+                    (estimate - exact).abs() / exact <= 0.15,
+                    "{family:?}: estimated {estimate}, exact {exact}"
+                );
+            }
+        }
+    }
+}
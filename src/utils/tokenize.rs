@@ -8,9 +8,20 @@ pub fn tokenize(input: &str) -> Vec<String> {
         let ch = chars[i];
 
         // Check if this is a delimiter
-        if ch.is_whitespace() || ch == '-' || ch == '_' || ch == '.' || ch == '/'
-            || ch == '(' || ch == ')' || ch == '[' || ch == ']'
-            || ch == '{' || ch == '}' || ch == ':' || ch == ',' || ch == ';'
+        if ch.is_whitespace()
+            || ch == '-'
+            || ch == '_'
+            || ch == '.'
+            || ch == '/'
+            || ch == '('
+            || ch == ')'
+            || ch == '['
+            || ch == ']'
+            || ch == '{'
+            || ch == '}'
+            || ch == ':'
+            || ch == ','
+            || ch == ';'
         {
             if !current_token.is_empty() {
                 result.push(current_token.to_lowercase());
@@ -110,9 +121,7 @@ mod tests {
         let tokens = tokenize("Google: Gemini 2.5 Flash Image (Nano Banana)");
         assert_eq!(
             tokens,
-            vec![
-                "google", "gemini", "2", "5", "flash", "image", "nano", "banana"
-            ]
+            vec!["google", "gemini", "2", "5", "flash", "image", "nano", "banana"]
         );
         // Verify "nano" is a standalone token
         assert!(tokens.contains(&"nano".to_string()));
@@ -129,7 +138,10 @@ mod tests {
         assert_eq!(tokenize("foo---bar"), vec!["foo", "bar"]);
         assert_eq!(tokenize("a  b"), vec!["a", "b"]);
         // Mixed delimiters
-        assert_eq!(tokenize("foo-bar_baz.qux"), vec!["foo", "bar", "baz", "qux"]);
+        assert_eq!(
+            tokenize("foo-bar_baz.qux"),
+            vec!["foo", "bar", "baz", "qux"]
+        );
     }
 
     #[test]